@@ -0,0 +1,78 @@
+//! Times `App::assign_columns` on a synthetic 10k-commit history.
+//! Run with `cargo bench --bench assign_columns`.
+
+use git_manager::app::{App, Config};
+use git_manager::graph::{Commit, CommitGraph};
+use std::time::{Duration, Instant};
+
+const COMMIT_COUNT: usize = 10_000;
+const ITERATIONS: u32 = 5;
+
+/// A mainline where every 20th commit merges a 5-commit feature branch forked a little further back
+fn fixture() -> (CommitGraph, Vec<String>) {
+    let mut graph = CommitGraph::new();
+    let mut oldest_first = Vec::with_capacity(COMMIT_COUNT);
+    let id = |n: usize| format!("{:040x}", n);
+
+    let mut add = |graph: &mut CommitGraph, n: usize, parents: Vec<String>| {
+        graph.add_commit(Commit {
+            id: id(n),
+            short_id: id(n)[..7].to_string(),
+            parents,
+            children: Vec::new(),
+            message: format!("commit {}", n),
+            author: "bench".to_string(),
+            timestamp: n as i64,
+            author_timestamp: n as i64,
+        });
+        oldest_first.push(id(n));
+    };
+
+    let mut n = 0;
+    let mut mainline: Option<String> = None;
+    while n < COMMIT_COUNT {
+        let is_merge = n % 20 == 19 && n + 6 < COMMIT_COUNT;
+        if is_merge {
+            // Feature branch off the current mainline tip, then merge it back
+            let fork = mainline.clone().unwrap();
+            let mut tip = fork;
+            for _ in 0..5 {
+                add(&mut graph, n, vec![tip]);
+                tip = id(n);
+                n += 1;
+            }
+            add(&mut graph, n, vec![mainline.clone().unwrap(), tip]);
+        } else {
+            add(&mut graph, n, mainline.iter().cloned().collect());
+        }
+        mainline = Some(id(n));
+        n += 1;
+    }
+
+    graph.build_graph();
+    oldest_first.reverse();
+    (graph, oldest_first)
+}
+
+fn main() {
+    let mut total = Duration::ZERO;
+    let mut commit_count = 0;
+    for _ in 0..ITERATIONS {
+        // Fresh fixture each round since assign_columns takes the graph mutably
+        let (mut graph, newest_first) = fixture();
+        let head = newest_first.first().cloned();
+        let mut app = App::new(Config::default());
+        let start = Instant::now();
+        let nodes = app.assign_columns(&mut graph, &newest_first, head.clone());
+        total += start.elapsed();
+        assert_eq!(nodes.len(), newest_first.len());
+        commit_count = nodes.len();
+    }
+
+    println!(
+        "assign_columns: {} commits, {} iterations, {:?} per iteration",
+        commit_count,
+        ITERATIONS,
+        total / ITERATIONS
+    );
+}
@@ -0,0 +1,134 @@
+use super::App;
+use super::types::{AppMode, BlameLine, FileStatus, FocusedPane};
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Blame hash for lines that only exist in the working tree
+const UNCOMMITTED_HASH: &str = "0000000000000000000000000000000000000000";
+
+/// Parse `git blame --porcelain`. Author fields only follow a commit's first line, so they are remembered per hash.
+fn parse_blame_porcelain(output: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<String, (String, i64)> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut current_hash = String::new();
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let (author, time) = commits.get(&current_hash).cloned().unwrap_or_default();
+            let date = chrono::DateTime::from_timestamp(time, 0)
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            lines.push(BlameLine {
+                commit_hash: current_hash.clone(),
+                author,
+                date,
+                content: content.to_string(),
+            });
+        } else if let Some(author) = line.strip_prefix("author ") {
+            commits.entry(current_hash.clone()).or_default().0 = author.to_string();
+        } else if let Some(time) = line.strip_prefix("author-time ") {
+            commits.entry(current_hash.clone()).or_default().1 = time.parse().unwrap_or(0);
+        } else if let Some(hash) = line.split(' ').next().filter(|hash| hash.len() == 40 && hash.chars().all(|c| c.is_ascii_hexdigit())) {
+            current_hash = hash.to_string();
+        }
+    }
+
+    lines
+}
+
+impl App {
+    /// `b` on git status: show `git blame` for the highlighted file
+    pub fn open_blame_view(&mut self) {
+        let Some(file) = self.selected_file_idx.and_then(|idx| self.git_status_files.get(idx)) else {
+            return;
+        };
+
+        if matches!(file.status, FileStatus::Untracked | FileStatus::Deleted) {
+            self.set_status_message("✗ Blame needs a tracked file that still exists".to_string());
+            return;
+        }
+
+        // Status paths are relative to the work tree root, not to where the app was started
+        let path = file.path.clone();
+        let output = Command::new("git")
+            .current_dir(super::conflict::work_tree_root())
+            .args(&["blame", "--porcelain", "--", &path])
+            .output();
+
+        let output = match output {
+            Ok(output) if output.status.success() => output,
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+                return;
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+                return;
+            }
+        };
+
+        self.blame_lines = parse_blame_porcelain(&String::from_utf8_lossy(&output.stdout));
+        self.blame_path = path;
+        self.selected_blame_idx = 0;
+        self.mode = AppMode::BlameView;
+    }
+
+    pub fn blame_up(&mut self) {
+        if self.selected_blame_idx > 0 {
+            self.selected_blame_idx -= 1;
+        }
+    }
+
+    pub fn blame_down(&mut self) {
+        if self.selected_blame_idx + 1 < self.blame_lines.len() {
+            self.selected_blame_idx += 1;
+        }
+    }
+
+    pub fn blame_page(&mut self, down: bool, page: usize) {
+        self.selected_blame_idx = if down {
+            (self.selected_blame_idx + page).min(self.blame_lines.len().saturating_sub(1))
+        } else {
+            self.selected_blame_idx.saturating_sub(page)
+        };
+    }
+
+    pub fn close_blame_view(&mut self) {
+        self.blame_lines.clear();
+        self.blame_path.clear();
+        self.selected_blame_idx = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Enter: select the line's commit in the graph, or summarize it when it isn't loaded
+    pub fn blame_jump(&mut self) {
+        let Some(hash) = self.blame_lines.get(self.selected_blame_idx).map(|line| line.commit_hash.clone()) else {
+            return;
+        };
+
+        if hash == UNCOMMITTED_HASH {
+            self.set_status_message("✗ This line is not committed yet".to_string());
+            return;
+        }
+
+        if let Some(idx) = self.graph_nodes.iter().position(|node| node.commit.id == hash) {
+            self.close_blame_view();
+            self.selected_commit_idx = Some(idx);
+            self.update_selection();
+            self.focused_pane = FocusedPane::CommitGraph;
+            return;
+        }
+
+        let summary = Command::new("git")
+            .args(&["show", "-s", "--format=%h %an, %ar: %s", &hash])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+        match summary {
+            Some(summary) => self.set_status_message(format!("{} (not loaded in the graph)", summary)),
+            None => self.set_status_message(format!("✗ Commit {} not found", &hash[..7])),
+        }
+    }
+}
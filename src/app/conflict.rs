@@ -0,0 +1,175 @@
+use super::App;
+use super::types::{AppMode, ConflictFile, ConflictOperation, FocusedPane};
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// git reports conflicts as "CONFLICT (content): ..." lines, on stdout for merge/cherry-pick/rebase
+pub(super) fn stopped_on_conflict(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("CONFLICT")
+        || String::from_utf8_lossy(&output.stderr).contains("CONFLICT")
+}
+
+/// Files git still lists as unmerged, for operations whose output wasn't captured
+pub(super) fn has_unmerged_files() -> bool {
+    Command::new("git")
+        .args(&["diff", "--name-only", "--diff-filter=U"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty())
+}
+
+/// Conflict paths are relative to the top of the work tree, not the current directory
+pub(super) fn work_tree_root() -> PathBuf {
+    Command::new("git")
+        .args(&["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+impl App {
+    /// Switch to the resolution assistant after `operation` stopped on conflicts.
+    /// Returns the error shown in the status bar, since the operation itself did not finish.
+    pub(super) fn enter_conflict_resolution(&mut self, operation: ConflictOperation) -> Result<String, String> {
+        self.load_conflict_files()?;
+        self.conflict_operation = Some(operation);
+        self.selected_conflict_idx = 0;
+        self.focused_pane = FocusedPane::GitStatus;
+        self.mode = AppMode::ConflictResolution;
+        let _ = self.init();
+
+        Err(format!(
+            "{} stopped on conflicts in {} file(s) - e: edit, a: stage, c: continue",
+            operation.label(),
+            self.conflict_files.len()
+        ))
+    }
+
+    fn load_conflict_files(&mut self) -> Result<(), String> {
+        let output = Command::new("git")
+            .args(&["diff", "--name-only", "--diff-filter=U"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        self.conflict_files = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|path| ConflictFile { path: path.to_string(), resolved: false })
+            .collect();
+
+        Ok(())
+    }
+
+    pub fn conflict_up(&mut self) {
+        if self.selected_conflict_idx > 0 {
+            self.selected_conflict_idx -= 1;
+        }
+    }
+
+    pub fn conflict_down(&mut self) {
+        if self.selected_conflict_idx + 1 < self.conflict_files.len() {
+            self.selected_conflict_idx += 1;
+        }
+    }
+
+    /// `e`: open the highlighted file in `$EDITOR` to fix the conflict markers
+    pub fn edit_selected_conflict(&mut self) {
+        let Some(path) = self.conflict_files.get(self.selected_conflict_idx).map(|file| file.path.clone()) else {
+            return;
+        };
+
+        let full_path = work_tree_root().join(&path);
+        match self.edit_in_external_editor(&full_path) {
+            Ok(()) => self.set_status_message(format!("✓ Edited {} - press a to stage it once resolved", path)),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    /// `a`: mark the highlighted file resolved by staging it
+    pub fn stage_selected_conflict(&mut self) {
+        let Some(path) = self.conflict_files.get(self.selected_conflict_idx).map(|file| file.path.clone()) else {
+            return;
+        };
+
+        let output = Command::new("git")
+            .args(&["add", "--", &path])
+            .current_dir(work_tree_root())
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                if let Some(file) = self.conflict_files.get_mut(self.selected_conflict_idx) {
+                    file.resolved = true;
+                }
+                self.load_git_status();
+
+                let remaining = self.conflict_files.iter().filter(|file| !file.resolved).count();
+                if remaining == 0 {
+                    self.set_status_message("✓ All conflicts resolved - press c to continue".to_string());
+                } else {
+                    self.set_status_message(format!("✓ Staged {} ({} conflict(s) left)", path, remaining));
+                    self.conflict_down();
+                }
+            }
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+            }
+        }
+    }
+
+    /// `c`: run `git <operation> --continue` once every file is staged
+    pub fn continue_conflict_operation(&mut self) {
+        let Some(operation) = self.conflict_operation else {
+            self.close_conflict_resolution();
+            return;
+        };
+
+        let remaining = self.conflict_files.iter().filter(|file| !file.resolved).count();
+        if remaining > 0 {
+            self.set_status_message(format!("✗ {} file(s) still conflicted - resolve and stage them first (a)", remaining));
+            return;
+        }
+
+        // Keep the prepared commit message instead of opening an editor over the TUI
+        let output = Command::new("git")
+            .args(operation.continue_args())
+            .env("GIT_EDITOR", "true")
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                self.close_conflict_resolution();
+                let _ = self.init();
+                self.set_status_message(format!("✓ {} continued", operation.label()));
+            }
+            // A rebase replaying the next commit can stop on new conflicts
+            Ok(output) if stopped_on_conflict(&output) => {
+                if let Err(e) = self.enter_conflict_resolution(operation) {
+                    self.set_status_message(format!("✗ {}", e));
+                }
+            }
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+            }
+        }
+    }
+
+    /// Leave the assistant; the operation stays in progress for the command line
+    pub fn close_conflict_resolution(&mut self) {
+        self.conflict_files.clear();
+        self.selected_conflict_idx = 0;
+        self.conflict_operation = None;
+        self.mode = AppMode::Normal;
+    }
+}
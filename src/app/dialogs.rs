@@ -1,5 +1,6 @@
 use super::App;
-use super::types::{AppMode, GitCommand, FocusedPane, FileStatus, StatusFile};
+use super::commands::squash_message;
+use super::types::{AppMode, DeleteSelection, GitCommand, FocusedPane, FileStatus, StatusFile};
 use std::process::Command;
 
 impl App {
@@ -14,11 +15,15 @@ impl App {
     }
 
     pub fn commit_message_input_char(&mut self, c: char) {
-        self.commit_message_input.push(c);
+        self.input_insert(c);
+    }
+
+    pub fn commit_message_newline(&mut self) {
+        self.input_insert('\n');
     }
 
     pub fn commit_message_backspace(&mut self) {
-        self.commit_message_input.pop();
+        self.input_backspace();
     }
 
     pub fn submit_commit_message(&mut self) {
@@ -38,6 +43,36 @@ impl App {
         }
     }
 
+    /// Ctrl+S in the commit dialog: add the `Signed-off-by` trailer for the configured user, or remove it again
+    pub fn toggle_commit_sign_off(&mut self) {
+        let (Some(name), Some(email)) = (&self.git_user_name, &self.git_user_email) else {
+            self.set_status_message("✗ Set user.name and user.email to sign off".to_string());
+            return;
+        };
+
+        let sign_off = format!("Signed-off-by: {} <{}>", name, email);
+        let input = &mut self.commit_message_input;
+        let old_len = input.chars().count();
+
+        if input.lines().any(|line| line == sign_off) {
+            let kept: Vec<&str> = input.lines().filter(|line| *line != sign_off).collect();
+            *input = kept.join("\n").trim_end().to_string();
+        } else {
+            let body_len = input.trim_end().len();
+            input.truncate(body_len);
+            input.push_str("\n\n");
+            input.push_str(&sign_off);
+        }
+
+        // The cursor counts chars from the end, so keep it where it was in the text before the trailer
+        let new_len = input.chars().count();
+        self.input_cursor = if new_len > old_len {
+            self.input_cursor + (new_len - old_len)
+        } else {
+            self.input_cursor.saturating_sub(old_len - new_len)
+        };
+    }
+
     pub fn cancel_branch_name(&mut self) {
         self.branch_name_input.clear();
         self.pending_branch_commit_id = None;
@@ -56,6 +91,24 @@ impl App {
         }
     }
 
+    pub fn hook_up(&mut self) {
+        if self.selected_hook_idx > 0 {
+            self.selected_hook_idx -= 1;
+        }
+    }
+
+    pub fn hook_down(&mut self) {
+        if self.selected_hook_idx + 1 < self.hooks.len() {
+            self.selected_hook_idx += 1;
+        }
+    }
+
+    pub fn close_hooks_view(&mut self) {
+        self.hooks.clear();
+        self.selected_hook_idx = 0;
+        self.mode = AppMode::Normal;
+    }
+
     pub fn select_branch(&mut self) {
         if let Some(branch_name) = self.available_branches.get(self.selected_branch_idx).cloned() {
             self.mode = AppMode::Normal;
@@ -89,33 +142,80 @@ impl App {
         }
     }
 
+    /// Space in the force-delete picker: tick or untick the highlighted branch
+    pub fn toggle_delete_branch_mark(&mut self) {
+        if let Some(branch) = self.available_branches.get(self.selected_branch_idx).cloned() {
+            if let Some(pos) = self.branches_marked_for_delete.iter().position(|b| *b == branch) {
+                self.branches_marked_for_delete.remove(pos);
+            } else {
+                self.branches_marked_for_delete.push(branch);
+            }
+        }
+    }
+
     pub fn select_branch_to_delete(&mut self) {
         if self.selected_branch_idx < self.available_branches.len() {
-            // Generate confirmation message with selected branch name
-            let branch_name = &self.available_branches[self.selected_branch_idx];
-            self.pending_command_message = Some(format!(
-                "Force delete branch '{}'?\n\n⚠️  WARNING: This will delete the branch using 'git branch -D'.\nCommits that are only reachable from this branch will become orphaned.\nOrphaned commits can be recovered from reflog for ~30 days.",
-                branch_name
-            ));
-
-            // Move to confirmation dialog with the selected branch
+            // With nothing ticked, Enter deletes just the highlighted branch
+            if self.branches_marked_for_delete.is_empty() {
+                let branch_name = self.available_branches[self.selected_branch_idx].clone();
+                self.branches_marked_for_delete.push(branch_name);
+            }
+
+            match self.delete_selection {
+                DeleteSelection::Tags => {
+                    self.pending_command_message = Some(self.tag_delete_confirmation_message(&self.branches_marked_for_delete));
+                    self.pending_command = Some(GitCommand::DeleteTag);
+                    self.mode = AppMode::Confirm;
+                    return;
+                }
+                DeleteSelection::RemoteBranches => {
+                    self.pending_command_message = Some(Self::remote_branch_delete_confirmation_message(&self.branches_marked_for_delete));
+                    self.pending_command = Some(GitCommand::DeleteRemoteBranch);
+                    self.mode = AppMode::Confirm;
+                    return;
+                }
+                DeleteSelection::MergedBranches => {
+                    self.pending_command_message = Some(Self::merged_branch_delete_confirmation_message(&self.branches_marked_for_delete));
+                    self.pending_command = Some(GitCommand::DeleteMergedBranches);
+                    self.mode = AppMode::Confirm;
+                    return;
+                }
+                DeleteSelection::Branches => {}
+            }
+
+            self.pending_command_message = Some(if let [branch_name] = &self.branches_marked_for_delete[..] {
+                format!(
+                    "Force delete branch '{}'?\n\n⚠️  WARNING: This will delete the branch using 'git branch -D'.\nCommits that are only reachable from this branch will become orphaned.\nOrphaned commits can be recovered from reflog for ~30 days.",
+                    branch_name
+                )
+            } else {
+                format!(
+                    "Force delete {} branches?\n\n{}\n\n⚠️  WARNING: This will delete the branches using 'git branch -D'.\nCommits that are only reachable from these branches will become orphaned.\nOrphaned commits can be recovered from reflog for ~30 days.",
+                    self.branches_marked_for_delete.len(),
+                    self.branches_marked_for_delete.iter().map(|b| format!("  - {}", b)).collect::<Vec<_>>().join("\n")
+                )
+            });
+
+            // Move to confirmation dialog with the selected branches
             self.pending_command = Some(GitCommand::ForceDeleteBranch);
             self.mode = AppMode::Confirm;
         }
     }
 
     pub fn cancel_delete_branch_selection(&mut self) {
+        self.branches_marked_for_delete.clear();
+        self.delete_selection = DeleteSelection::Branches;
         self.available_branches.clear();
         self.selected_branch_idx = 0;
         self.mode = AppMode::Normal;
     }
 
     pub fn branch_name_input_char(&mut self, c: char) {
-        self.branch_name_input.push(c);
+        self.input_insert(c);
     }
 
     pub fn branch_name_backspace(&mut self) {
-        self.branch_name_input.pop();
+        self.input_backspace();
     }
 
     pub fn submit_branch_name(&mut self) {
@@ -143,19 +243,118 @@ impl App {
             Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
         }
     }
+
+    pub fn cancel_rename_branch(&mut self) {
+        self.branch_name_input.clear();
+        self.pending_rename_branch = None;
+        self.mode = AppMode::Normal;
+    }
+
+    /// `git branch -m`, then offer to rename the branch on its remote too when it tracks one
+    pub fn submit_rename_branch(&mut self) {
+        let new_name = self.branch_name_input.trim().to_string();
+        let Some(old_name) = self.pending_rename_branch.take() else {
+            self.cancel_rename_branch();
+            return;
+        };
+        self.branch_name_input.clear();
+        self.mode = AppMode::Normal;
+
+        if new_name.is_empty() {
+            self.set_status_message("✗ Branch name cannot be empty".to_string());
+            return;
+        }
+        if new_name == old_name {
+            self.set_status_message("Branch name unchanged".to_string());
+            return;
+        }
+
+        // Read the upstream before renaming; "origin/feature/x" -> remote "origin", branch "feature/x"
+        let upstream = Command::new("git")
+            .args(&["rev-parse", "--abbrev-ref", "--symbolic-full-name", &format!("{}@{{upstream}}", old_name)])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+        let output = match Command::new("git").args(&["branch", "-m", &old_name, &new_name]).output() {
+            Ok(output) => output,
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+                return;
+            }
+        };
+
+        if !output.status.success() {
+            self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr).trim()));
+            return;
+        }
+
+        let _ = self.init();
+        self.set_status_message(format!("✓ Renamed branch '{}' to '{}'", old_name, new_name));
+
+        let Some((remote, remote_branch)) = upstream.as_deref().and_then(|upstream| upstream.split_once('/')) else {
+            return;
+        };
+
+        self.pending_command_message = Some(format!(
+            "Branch renamed locally to '{}'.\n\nIt tracks {}/{}. Rename it on {} too?\n\nThis will:\n- Push '{}' and track it (git push -u {} {})\n- Delete {}/{} (git push {} --delete {})\n\nOthers using {}/{} will need to switch to the new name.",
+            new_name, remote, remote_branch, remote,
+            new_name, remote, new_name,
+            remote, remote_branch, remote, remote_branch,
+            remote, remote_branch
+        ));
+        self.pending_remote_rename = Some((remote.to_string(), remote_branch.to_string(), new_name));
+        self.pending_command = Some(GitCommand::RenameBranch);
+        self.mode = AppMode::Confirm;
+    }
+
+    pub fn open_goto_hash_dialog(&mut self) {
+        self.goto_hash_input.clear();
+        self.mode = AppMode::GoToHash;
+    }
+
+    pub fn goto_hash_input_char(&mut self, c: char) {
+        if c.is_ascii_hexdigit() {
+            self.input_insert(c);
+        }
+    }
+
+    pub fn goto_hash_backspace(&mut self) {
+        self.input_backspace();
+    }
+
+    pub fn cancel_goto_hash(&mut self) {
+        self.goto_hash_input.clear();
+        self.mode = AppMode::Normal;
+    }
+
+    pub fn submit_goto_hash(&mut self) {
+        let input = self.goto_hash_input.trim().to_lowercase();
+        if input.len() < 4 {
+            self.set_status_message("✗ Enter at least 4 characters of the hash".to_string());
+            return;
+        }
+
+        self.goto_hash_input.clear();
+        self.mode = AppMode::Normal;
+        self.go_to_hash(&input);
+    }
+
     pub fn squash_count_input_char(&mut self, c: char) {
         if c.is_ascii_digit() {
-            self.squash_count_input.push(c);
+            self.input_insert(c);
         }
     }
 
     pub fn squash_count_backspace(&mut self) {
-        self.squash_count_input.pop();
+        self.input_backspace();
     }
 
     pub fn cancel_squash_count(&mut self) {
         self.squash_count_input.clear();
         self.pending_squash_commit_id = None;
+        self.pending_squash_message = None;
         self.mode = AppMode::Normal;
     }
 
@@ -175,8 +374,39 @@ impl App {
             return;
         };
 
-        // Get the commit range starting from the selected commit going backwards
         use std::process::Command;
+
+        // Check history depth up front so the error can say how far back we can actually go
+        let available = Command::new("git")
+            .args(&["rev-list", "--count", &commit_id])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| String::from_utf8_lossy(&o.stdout).trim().parse::<usize>().ok());
+
+        if let Some(available) = available {
+            if count > available {
+                self.set_status_message(format!("✗ Only {} commits available; you entered {}", available, count));
+                self.squash_count_input.clear();
+                return;
+            }
+        }
+
+        // Squashing across a merge flattens the merged branch into the result
+        let merge_count = Command::new("git")
+            .args(&["rev-list", "--first-parent", "--parents", &format!("--max-count={}", count), &commit_id])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .lines()
+                    .filter(|line| line.split_whitespace().count() > 2)
+                    .count()
+            })
+            .unwrap_or(0);
+
+        // Get the commit range starting from the selected commit going backwards
         let output = Command::new("git")
             .args(&["log", &format!("{}~{}", commit_id, count - 1), "--format=%h %s", &format!("-{}", count), &commit_id])
             .output();
@@ -193,15 +423,32 @@ impl App {
                 }
 
                 if lines.len() >= 2 {
-                    let first = lines.first().unwrap_or(&"");
-                    let last = lines.last().unwrap_or(&"");
+                    let combined_message = match squash_message(&commit_id, count) {
+                        Ok(message) => message,
+                        Err(e) => {
+                            self.set_status_message(format!("✗ Error: {}", e));
+                            self.squash_count_input.clear();
+                            return;
+                        }
+                    };
+
+                    let mut message = format!(
+                        "Squash {} commits from selected commit backwards?\n\nCommits to squash (newest first):\n{}\n\nCombined commit message:\n{}",
+                        count,
+                        lines.iter().map(|line| format!("  {}", line)).collect::<Vec<_>>().join("\n"),
+                        combined_message.lines().map(|line| format!("  │ {}", line)).collect::<Vec<_>>().join("\n")
+                    );
+                    if merge_count > 0 {
+                        message.push_str(&format!(
+                            "\n\n⚠ This range includes {} merge commit(s); squashing across a merge is not supported and will be refused.",
+                            merge_count
+                        ));
+                    }
 
                     self.pending_command = Some(GitCommand::SquashCommits);
-                    self.pending_command_message = Some(format!(
-                        "Squash {} commits from selected commit backwards?\n\nFrom: {} (oldest)\nTo:   {} (newest/selected)\n\nThis will combine these commits into one.\nAll commit messages will be combined.",
-                        count, last, first
-                    ));
+                    self.pending_command_message = Some(message);
                     self.pending_squash_commit_id = Some(format!("{}:{}", commit_id, count));
+                    self.pending_squash_message = Some(combined_message);
                     self.mode = AppMode::Confirm;
                 } else {
                     self.set_status_message(format!("✗ Only {} commit(s) available", lines.len()).to_string());
@@ -216,11 +463,11 @@ impl App {
 
     // Reword message input handlers
     pub fn reword_message_input_char(&mut self, c: char) {
-        self.reword_message_input.push(c);
+        self.input_insert(c);
     }
 
     pub fn reword_message_backspace(&mut self) {
-        self.reword_message_input.pop();
+        self.input_backspace();
     }
 
     pub fn cancel_reword_message(&mut self) {
@@ -248,11 +495,11 @@ impl App {
         }
     }
     pub fn config_input_char(&mut self, c: char) {
-        self.config_input.push(c);
+        self.input_insert(c);
     }
 
     pub fn config_input_backspace(&mut self) {
-        self.config_input.pop();
+        self.input_backspace();
     }
 
     pub fn cancel_config_input(&mut self) {
@@ -315,11 +562,11 @@ impl App {
     }
 
     pub fn remote_host_input_char(&mut self, c: char) {
-        self.remote_host_input.push(c);
+        self.input_insert(c);
     }
 
     pub fn remote_host_backspace(&mut self) {
-        self.remote_host_input.pop();
+        self.input_backspace();
     }
 
     pub fn cancel_remote_host_input(&mut self) {
@@ -327,6 +574,82 @@ impl App {
         self.mode = AppMode::Normal;
     }
 
+    pub fn push_target_input_char(&mut self, c: char) {
+        if !c.is_whitespace() {
+            self.input_insert(c);
+        }
+    }
+
+    pub fn push_target_backspace(&mut self) {
+        self.input_backspace();
+    }
+
+    pub fn push_target_switch_field(&mut self) {
+        self.push_editing_refspec = !self.push_editing_refspec;
+        self.input_cursor = 0;
+    }
+
+    pub fn cancel_push_target(&mut self) {
+        self.push_remote_input.clear();
+        self.push_refspec_input.clear();
+        self.mode = AppMode::Normal;
+    }
+
+    pub fn submit_push_target(&mut self) {
+        let remote = self.push_remote_input.trim().to_string();
+        let refspec = self.push_refspec_input.trim().to_string();
+
+        if remote.is_empty() || refspec.is_empty() {
+            self.set_status_message("✗ Remote and refspec are both required".to_string());
+            return;
+        }
+
+        self.cancel_push_target();
+
+        match self.execute_push_to(&remote, &refspec) {
+            Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    pub fn set_upstream_input_char(&mut self, c: char) {
+        if !c.is_whitespace() {
+            self.input_insert(c);
+        }
+    }
+
+    pub fn set_upstream_backspace(&mut self) {
+        self.input_backspace();
+    }
+
+    pub fn set_upstream_switch_field(&mut self) {
+        self.upstream_editing_branch = !self.upstream_editing_branch;
+        self.input_cursor = 0;
+    }
+
+    pub fn cancel_set_upstream(&mut self) {
+        self.upstream_remote_input.clear();
+        self.upstream_branch_input.clear();
+        self.mode = AppMode::Normal;
+    }
+
+    pub fn submit_set_upstream(&mut self) {
+        let remote = self.upstream_remote_input.trim().to_string();
+        let branch = self.upstream_branch_input.trim().to_string();
+
+        if remote.is_empty() || branch.is_empty() {
+            self.set_status_message("✗ Remote and branch are both required".to_string());
+            return;
+        }
+
+        self.cancel_set_upstream();
+
+        match self.execute_set_upstream(&remote, &branch) {
+            Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
     pub fn toggle_commit_selection(&mut self) {
         if let Some(idx) = self.selected_commit_idx {
             if let Some(node) = self.graph_nodes.get(idx) {
@@ -355,11 +678,11 @@ impl App {
     }
 
     pub fn assign_branch_name_input_char(&mut self, c: char) {
-        self.assign_branch_name_input.push(c);
+        self.input_insert(c);
     }
 
     pub fn assign_branch_name_backspace(&mut self) {
-        self.assign_branch_name_input.pop();
+        self.input_backspace();
     }
 
     pub fn cancel_assign_branch_name(&mut self) {
@@ -0,0 +1,61 @@
+use super::App;
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+impl App {
+    /// The configured editor, then `$GIT_EDITOR`, `$VISUAL`, `$EDITOR`, falling back to `vi`
+    fn editor_command(&self) -> String {
+        self.config.editor.clone()
+            .into_iter()
+            .chain(["GIT_EDITOR", "VISUAL", "EDITOR"].iter().filter_map(|var| std::env::var(var).ok()))
+            .find(|editor| !editor.trim().is_empty())
+            .unwrap_or_else(|| "vi".to_string())
+    }
+
+    /// Hand the terminal to the editor (see `editor_command`) to edit `path`, then take it back.
+    /// The main loop clears the screen afterwards since the editor drew over it.
+    pub(super) fn edit_in_external_editor(&mut self, path: &Path) -> Result<(), String> {
+        let editor = self.editor_command();
+
+        // Go through the shell so editors configured with arguments (e.g. "code -w") work
+        let mut command = Command::new("sh");
+        command.arg("-c")
+            .arg(format!("{} \"$1\"", editor))
+            .arg("sh")
+            .arg(path);
+
+        match self.suspend_for_subprocess(&mut command)? {
+            Ok(status) if status.success() => Ok(()),
+            Ok(status) => Err(format!("Editor '{}' exited with {}", editor, status)),
+            Err(e) => Err(format!("Failed to launch editor '{}': {}", editor, e)),
+        }
+    }
+
+    /// Run `command` with the terminal handed over (so it can prompt or open an editor), then take it back.
+    /// The outer error is the terminal failing to switch; the inner one is the command failing to start.
+    pub(super) fn suspend_for_subprocess(&mut self, command: &mut Command) -> Result<std::io::Result<ExitStatus>, String> {
+        let mut stdout = std::io::stdout();
+        let keyboard_enhancement = supports_keyboard_enhancement().unwrap_or(false);
+        if keyboard_enhancement {
+            let _ = execute!(stdout, PopKeyboardEnhancementFlags);
+        }
+        disable_raw_mode().map_err(|e| format!("Failed to leave raw mode: {}", e))?;
+        let _ = execute!(stdout, LeaveAlternateScreen, DisableMouseCapture);
+
+        let status = command.status();
+
+        let _ = execute!(stdout, EnterAlternateScreen, EnableMouseCapture);
+        let _ = enable_raw_mode();
+        if keyboard_enhancement {
+            let _ = execute!(stdout, PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES));
+        }
+        self.needs_full_redraw = true;
+
+        Ok(status)
+    }
+}
@@ -0,0 +1,145 @@
+use super::App;
+use super::types::{AppMode, FileStatus, FocusedPane};
+use crate::graph::{Commit, GraphNode};
+use std::process::Command;
+
+/// One `%H|%h|%P|%an|%at|%s` line as a single-lane graph node. The subject goes last since it may contain `|`.
+fn parse_history_line(line: &str) -> Option<GraphNode> {
+    let mut fields = line.splitn(6, '|');
+    let id = fields.next()?.to_string();
+    let short_id = fields.next()?.to_string();
+    let parents = fields.next()?.split_whitespace().map(str::to_string).collect();
+    let author = fields.next()?.to_string();
+    let timestamp = fields.next()?.parse().unwrap_or(0);
+    let message = fields.next().unwrap_or("").to_string();
+
+    Some(GraphNode {
+        commit: Commit {
+            id,
+            short_id,
+            parents,
+            children: Vec::new(),
+            message,
+            author,
+            timestamp,
+            author_timestamp: timestamp,
+        },
+        column: 0,
+        connections: Vec::new(),
+        in_current_branch: true,
+        remote_refs: Vec::new(),
+    })
+}
+
+impl App {
+    /// `H` on git status: list the commits that touched the highlighted file
+    pub fn open_file_history(&mut self) {
+        let Some(file) = self.selected_file_idx.and_then(|idx| self.git_status_files.get(idx)) else {
+            return;
+        };
+
+        if file.status == FileStatus::Untracked {
+            self.set_status_message("✗ Untracked files have no history".to_string());
+            return;
+        }
+
+        let path = file.path.clone();
+        let output = Command::new("git")
+            .args(&["log", "--pretty=format:%H|%h|%P|%an|%at|%s", "--", &path])
+            .output();
+
+        let output = match output {
+            Ok(output) if output.status.success() => output,
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+                return;
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+                return;
+            }
+        };
+
+        self.file_history_nodes = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_history_line)
+            .collect();
+
+        if self.file_history_nodes.is_empty() {
+            self.set_status_message(format!("✗ No commits touch {}", path));
+            return;
+        }
+
+        self.file_history_path = Some(path);
+        self.selected_file_history_idx = 0;
+        self.mode = AppMode::FileHistory;
+        self.load_file_history_diff();
+    }
+
+    /// `git show` of the selected commit, limited to the history's file
+    fn load_file_history_diff(&mut self) {
+        self.file_history_scroll = 0;
+        let (Some(path), Some(node)) = (&self.file_history_path, self.file_history_nodes.get(self.selected_file_history_idx)) else {
+            self.file_history_diff = None;
+            return;
+        };
+
+        self.file_history_diff = Command::new("git")
+            .args(&["show", "--format=", &node.commit.id, "--", path])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).to_string());
+    }
+
+    pub fn file_history_up(&mut self) {
+        if self.selected_file_history_idx > 0 {
+            self.selected_file_history_idx -= 1;
+            self.load_file_history_diff();
+        }
+    }
+
+    pub fn file_history_down(&mut self) {
+        if self.selected_file_history_idx + 1 < self.file_history_nodes.len() {
+            self.selected_file_history_idx += 1;
+            self.load_file_history_diff();
+        }
+    }
+
+    pub fn file_history_scroll_diff(&mut self, down: bool, lines: usize) {
+        let line_count = self.file_history_diff.as_deref().map_or(0, |diff| diff.lines().count());
+        self.file_history_scroll = if down {
+            (self.file_history_scroll + lines).min(line_count.saturating_sub(1))
+        } else {
+            self.file_history_scroll.saturating_sub(lines)
+        };
+    }
+
+    pub fn close_file_history(&mut self) {
+        self.file_history_path = None;
+        self.file_history_nodes.clear();
+        self.file_history_diff = None;
+        self.selected_file_history_idx = 0;
+        self.file_history_scroll = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Enter: select the highlighted commit in the main graph
+    pub fn file_history_jump(&mut self) {
+        let Some(hash) = self.file_history_nodes.get(self.selected_file_history_idx).map(|node| node.commit.id.clone()) else {
+            return;
+        };
+
+        match self.graph_nodes.iter().position(|node| node.commit.id == hash) {
+            Some(idx) => {
+                self.close_file_history();
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+                self.focused_pane = FocusedPane::CommitGraph;
+            }
+            None => {
+                self.set_status_message("✗ Commit not loaded – scroll to the end of the graph or use Load More to load older history".to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use super::App;
+use super::types::AppMode;
+use crossterm::event::KeyCode;
+
+/// Byte offset of a cursor sitting `chars_after` chars before the end of `input`
+pub fn cursor_byte_offset(input: &str, chars_after: usize) -> usize {
+    input.char_indices()
+        .rev()
+        .take(chars_after)
+        .last()
+        .map(|(idx, _)| idx)
+        .unwrap_or(input.len())
+}
+
+impl App {
+    /// The text field the current dialog edits. Only one is active at a time, so they share `input_cursor`.
+    fn active_input_mut(&mut self) -> Option<&mut String> {
+        match self.mode {
+            AppMode::CommitMessage | AppMode::MergeMessage => Some(&mut self.commit_message_input),
+            AppMode::BranchName | AppMode::RenameBranch => Some(&mut self.branch_name_input),
+            AppMode::SetUserName | AppMode::SetUserEmail => Some(&mut self.config_input),
+            AppMode::SetRemoteHost => Some(&mut self.remote_host_input),
+            AppMode::AddRemote if self.remote_editing_second => Some(&mut self.remote_url_input),
+            AppMode::RenameRemote if self.remote_editing_second => Some(&mut self.remote_new_name_input),
+            AppMode::AddRemote | AppMode::RenameRemote => Some(&mut self.remote_name_input),
+            AppMode::SquashCountInput => Some(&mut self.squash_count_input),
+            AppMode::RewordMessage => Some(&mut self.reword_message_input),
+            AppMode::AssignBranchName => Some(&mut self.assign_branch_name_input),
+            AppMode::GoToHash => Some(&mut self.goto_hash_input),
+            AppMode::TagName => Some(&mut self.tag_name_input),
+            AppMode::WorktreeCreate if self.worktree_editing_path => Some(&mut self.worktree_path_input),
+            AppMode::WorktreeCreate => Some(&mut self.worktree_branch_input),
+            AppMode::PushTarget if self.push_editing_refspec => Some(&mut self.push_refspec_input),
+            AppMode::PushTarget => Some(&mut self.push_remote_input),
+            AppMode::SetUpstream if self.upstream_editing_branch => Some(&mut self.upstream_branch_input),
+            AppMode::SetUpstream => Some(&mut self.upstream_remote_input),
+            _ => None,
+        }
+    }
+
+    /// Insert at the cursor; the cursor stays just after the new char
+    pub(super) fn input_insert(&mut self, c: char) {
+        let chars_after = self.input_cursor;
+        if let Some(input) = self.active_input_mut() {
+            let chars_after = chars_after.min(input.chars().count());
+            let offset = cursor_byte_offset(input, chars_after);
+            input.insert(offset, c);
+        }
+    }
+
+    /// Remove the char before the cursor
+    pub(super) fn input_backspace(&mut self) {
+        let chars_after = self.input_cursor;
+        if let Some(input) = self.active_input_mut() {
+            let len = input.chars().count();
+            if chars_after < len {
+                let offset = cursor_byte_offset(input, chars_after + 1);
+                input.remove(offset);
+            }
+        }
+    }
+
+    /// Remove the char after the cursor
+    fn input_delete(&mut self) {
+        if self.input_cursor == 0 {
+            return;
+        }
+        let chars_after = self.input_cursor;
+        if let Some(input) = self.active_input_mut() {
+            let chars_after = chars_after.min(input.chars().count());
+            let offset = cursor_byte_offset(input, chars_after);
+            if offset < input.len() {
+                input.remove(offset);
+            }
+        }
+        self.input_cursor -= 1;
+    }
+
+    /// Left/Right/Home/End move the cursor, Delete removes the char after it
+    pub fn input_cursor_key(&mut self, key: KeyCode) {
+        let len = self.active_input_mut().map(|input| input.chars().count()).unwrap_or(0);
+        self.input_cursor = self.input_cursor.min(len);
+
+        match key {
+            KeyCode::Left => self.input_cursor = (self.input_cursor + 1).min(len),
+            KeyCode::Right => self.input_cursor = self.input_cursor.saturating_sub(1),
+            KeyCode::Home => self.input_cursor = len,
+            KeyCode::End => self.input_cursor = 0,
+            KeyCode::Delete => self.input_delete(),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::fmt;
+
+/// A key plus the modifiers that must be held. Shift is folded into the character (`A`, not `shift+a`).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    const fn plain(code: KeyCode) -> Self {
+        Self { code, modifiers: KeyModifiers::NONE }
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self { code: KeyCode::Char(c), modifiers: KeyModifiers::CONTROL }
+    }
+
+    /// Ctrl and Alt must match exactly; Shift is ignored since it is already part of the character
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let held = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        key.code == self.code && held == self.modifiers
+    }
+
+    /// Parse bindings like `r`, `A`, `ctrl+q`, `alt+enter`, `pagedown` or `f1`
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut shift = false;
+        let mut parts: Vec<&str> = text.split('+').collect();
+        // "+" on its own (or "ctrl++") leaves an empty last part
+        let key = match parts.pop() {
+            Some("") if text.ends_with('+') => {
+                parts.pop();
+                "+"
+            }
+            Some(key) => key,
+            None => "",
+        };
+
+        for modifier in parts {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "shift" => shift = true,
+                other => return Err(format!("unknown modifier '{}' in key '{}'", other, text)),
+            }
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                if shift {
+                    KeyCode::Char(c.to_ascii_uppercase())
+                } else if modifiers.contains(KeyModifiers::CONTROL) {
+                    // Terminals report Ctrl+letter as the lowercase letter
+                    KeyCode::Char(c.to_ascii_lowercase())
+                } else {
+                    KeyCode::Char(c)
+                }
+            }
+            _ => {
+                if shift {
+                    return Err(format!("shift is only supported on character keys: '{}'", text));
+                }
+                match key.to_ascii_lowercase().as_str() {
+                    "up" => KeyCode::Up,
+                    "down" => KeyCode::Down,
+                    "left" => KeyCode::Left,
+                    "right" => KeyCode::Right,
+                    "tab" => KeyCode::Tab,
+                    "enter" | "return" => KeyCode::Enter,
+                    "space" => KeyCode::Char(' '),
+                    "esc" | "escape" => KeyCode::Esc,
+                    "backspace" => KeyCode::Backspace,
+                    "delete" | "del" => KeyCode::Delete,
+                    "home" => KeyCode::Home,
+                    "end" => KeyCode::End,
+                    "pageup" | "pgup" => KeyCode::PageUp,
+                    "pagedown" | "pgdn" => KeyCode::PageDown,
+                    name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                        Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                        _ => return Err(format!("unknown key '{}'", text)),
+                    },
+                }
+            }
+        };
+
+        Ok(Self { code, modifiers })
+    }
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Self::parse(&text)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if !self.modifiers.is_empty() => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Normal-mode key assignments, read from the `[keybindings]` section of the config file.
+/// Keys left out of the file keep these defaults.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyMap {
+    pub help: KeyBinding,
+    pub quit: KeyBinding,
+    pub refresh: KeyBinding,
+    pub refresh_status: KeyBinding,
+    pub toggle_watch: KeyBinding,
+    pub goto_hash: KeyBinding,
+    pub repeat_command: KeyBinding,
+    pub next_pane: KeyBinding,
+    pub move_up: KeyBinding,
+    pub move_down: KeyBinding,
+    pub page_up: KeyBinding,
+    pub page_down: KeyBinding,
+    pub select: KeyBinding,
+    pub show_all_branches: KeyBinding,
+    pub first_parent: KeyBinding,
+    pub reflog: KeyBinding,
+    pub repo_stats: KeyBinding,
+    // Commit graph
+    pub search: KeyBinding,
+    pub cycle_sort: KeyBinding,
+    pub toggle_compact: KeyBinding,
+    pub date_grouping: KeyBinding,
+    pub toggle_reviewed: KeyBinding,
+    pub jump_to_head: KeyBinding,
+    pub yank_hash: KeyBinding,
+    pub fixup: KeyBinding,
+    // Commit details
+    pub diff_search: KeyBinding,
+    pub commit_tree: KeyBinding,
+    pub cycle_parent: KeyBinding,
+    pub prev_file: KeyBinding,
+    pub next_file: KeyBinding,
+    pub toggle_file_list: KeyBinding,
+    pub staged_diff: KeyBinding,
+    pub unstaged_diff: KeyBinding,
+    pub more_context: KeyBinding,
+    pub less_context: KeyBinding,
+    pub word_diff: KeyBinding,
+    pub ignore_whitespace: KeyBinding,
+    pub next_match: KeyBinding,
+    pub prev_match: KeyBinding,
+    // Git status
+    pub toggle_stage: KeyBinding,
+    pub stage_all: KeyBinding,
+    pub unstage_all: KeyBinding,
+    pub edit_file: KeyBinding,
+    pub stage_patch: KeyBinding,
+    pub discard_file: KeyBinding,
+    pub blame_file: KeyBinding,
+    pub file_history: KeyBinding,
+    // Tag list
+    pub tag_sort: KeyBinding,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            help: KeyBinding::plain(KeyCode::F(1)),
+            quit: KeyBinding::ctrl('q'),
+            refresh: KeyBinding::plain(KeyCode::Char('r')),
+            refresh_status: KeyBinding::ctrl('r'),
+            toggle_watch: KeyBinding::ctrl('w'),
+            goto_hash: KeyBinding::ctrl('g'),
+            repeat_command: KeyBinding::plain(KeyCode::Char('.')),
+            next_pane: KeyBinding::plain(KeyCode::Tab),
+            move_up: KeyBinding::plain(KeyCode::Up),
+            move_down: KeyBinding::plain(KeyCode::Down),
+            page_up: KeyBinding::plain(KeyCode::PageUp),
+            page_down: KeyBinding::plain(KeyCode::PageDown),
+            select: KeyBinding::plain(KeyCode::Enter),
+            show_all_branches: KeyBinding::plain(KeyCode::Char('A')),
+            first_parent: KeyBinding::plain(KeyCode::Char('F')),
+            reflog: KeyBinding::plain(KeyCode::Char('l')),
+            repo_stats: KeyBinding::plain(KeyCode::Char('i')),
+            search: KeyBinding::plain(KeyCode::Char('/')),
+            cycle_sort: KeyBinding::plain(KeyCode::Char('o')),
+            toggle_compact: KeyBinding::plain(KeyCode::Char('C')),
+            date_grouping: KeyBinding::plain(KeyCode::Char('d')),
+            toggle_reviewed: KeyBinding::plain(KeyCode::Char('m')),
+            jump_to_head: KeyBinding::plain(KeyCode::Char('h')),
+            yank_hash: KeyBinding::plain(KeyCode::Char('y')),
+            fixup: KeyBinding::plain(KeyCode::Char('f')),
+            diff_search: KeyBinding::ctrl('f'),
+            commit_tree: KeyBinding::plain(KeyCode::Char('T')),
+            cycle_parent: KeyBinding::plain(KeyCode::Char('p')),
+            prev_file: KeyBinding::plain(KeyCode::Char('[')),
+            next_file: KeyBinding::plain(KeyCode::Char(']')),
+            toggle_file_list: KeyBinding::plain(KeyCode::Char('F')),
+            staged_diff: KeyBinding::plain(KeyCode::Char('s')),
+            unstaged_diff: KeyBinding::plain(KeyCode::Char('u')),
+            more_context: KeyBinding::plain(KeyCode::Char('+')),
+            less_context: KeyBinding::plain(KeyCode::Char('-')),
+            word_diff: KeyBinding::plain(KeyCode::Char('w')),
+            ignore_whitespace: KeyBinding::plain(KeyCode::Char('W')),
+            next_match: KeyBinding::plain(KeyCode::Char('n')),
+            prev_match: KeyBinding::plain(KeyCode::Char('N')),
+            toggle_stage: KeyBinding::plain(KeyCode::Char(' ')),
+            stage_all: KeyBinding::plain(KeyCode::Char('A')),
+            unstage_all: KeyBinding::plain(KeyCode::Char('U')),
+            edit_file: KeyBinding::plain(KeyCode::Char('e')),
+            stage_patch: KeyBinding::plain(KeyCode::Char('p')),
+            discard_file: KeyBinding::plain(KeyCode::Char('D')),
+            blame_file: KeyBinding::plain(KeyCode::Char('b')),
+            file_history: KeyBinding::plain(KeyCode::Char('H')),
+            tag_sort: KeyBinding::plain(KeyCode::Char('s')),
+        }
+    }
+}
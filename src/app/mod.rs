@@ -1,18 +1,54 @@
 mod types;
+mod config;
+mod keymap;
 mod navigation;
 mod dialogs;
 mod commands;
+mod search;
+mod stash;
+mod tree;
+mod tags;
+mod editor;
+mod watch;
+mod quicklog;
+mod worktree;
+mod input;
+mod conflict;
+mod reflog;
+mod blame;
+mod history;
+mod rebase;
+mod clipboard;
+mod remote;
+mod stats;
 
 pub use types::*;
-
-use crate::git::GitRepo;
-use crate::graph::{CommitGraph, GraphNode};
-use std::time::Instant;
+pub use config::{Config, GraphColumn};
+pub use input::cursor_byte_offset;
+
+use crate::git::{CommitBatch, GitRepo, HistoryWalker};
+use crate::graph::{CommitGraph, GraphNode, SignatureStatus};
+use navigation::MAX_DIFF_CONTEXT_LINES;
+use ratatui::style::Color;
+use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::process::Command;
 
+/// Longest any single git validation command may run before it is killed
+const VALIDATION_COMMAND_TIMEOUT: Duration = Duration::from_secs(1);
+/// Upper bound for the whole validation pass
+const VALIDATION_BUDGET: Duration = Duration::from_secs(3);
+/// hash, short hash, parents, subject, author, timestamp
+const VALIDATION_LOG_FORMAT: &str = "--pretty=format:%H%x00%h%x00%P%x00%s%x00%an%x00%at";
+/// How close (in graph rows) to the end of the loaded history scrolling has to get before the next batch loads
+const LOAD_MORE_MARGIN_ROWS: usize = 20;
+/// How long a failed batch waits before scrolling near the end tries it again
+const LOAD_MORE_RETRY_DELAY: Duration = Duration::from_secs(5);
+
 pub struct App {
+    pub config: Config,
     pub should_quit: bool,
     pub mode: AppMode,
     pub focused_pane: FocusedPane,
@@ -23,50 +59,257 @@ pub struct App {
     pub active_columns: Vec<usize>,
     pub selected_commit_idx: Option<usize>,
     pub current_branch: Option<String>,
+    pub repo_name: Option<String>,
+    pub main_branch_name: Option<String>,
+    pub branch_distances: std::collections::HashMap<String, usize>,
+    /// Show every branch, remote and tag in the graph instead of only HEAD's history
+    pub show_all_branches: bool,
+    /// Commits marked as read during this session
+    pub reviewed_commits: std::collections::HashSet<String>,
+    /// Hide reviewed commits from the graph
+    pub show_unreviewed_only: bool,
+    /// Only the first-parent chain from HEAD, drawn as a single lane
+    pub first_parent_only: bool,
+    /// Lane color per commit author, handed out from the theme's lane palette.
+    /// Kept across refreshes so an author's color doesn't change mid-session
+    pub author_colors: std::collections::HashMap<String, Color>,
+    /// Date separator lines in the commit graph: (index of the node drawn below it, label), by index
+    pub graph_separators: Vec<(usize, String)>,
+    /// Flat list of all tags for the tag list pane
+    pub tag_entries: Vec<TagEntry>,
+    pub selected_tag_idx: usize,
+    pub tag_sort: TagSort,
+    /// Signature check results for annotated tags, keyed by tag name
+    pub tag_verification_cache: std::collections::HashMap<String, GpgTagStatus>,
+    pub tag_verification_result: Arc<Mutex<Option<std::collections::HashMap<String, GpgTagStatus>>>>,
+    /// Tag names on 'origin' for the delete-tag warning: None while `git ls-remote` runs, Err if it failed
+    pub origin_tags: Option<OriginTags>,
+    pub origin_tags_result: Arc<Mutex<Option<OriginTags>>>,
+    /// Local branch names keyed by the commit they point at
+    pub branch_labels: std::collections::HashMap<String, Vec<String>>,
+    /// Commit id -> remote-tracking branch names, copied onto graph nodes by `assign_columns`
+    pub remote_ref_labels: std::collections::HashMap<String, Vec<String>>,
+    /// Tag names keyed by the commit they point at
+    pub tags: std::collections::HashMap<String, Vec<String>>,
     pub branch_ahead: usize,
     pub branch_behind: usize,
     pub has_upstream: bool,
     pub current_diff: Option<String>,
+    /// Unchanged lines shown around each diff hunk (`+`/`-` on commit details)
+    pub diff_context_lines: usize,
+    /// Show diffs as `git diff --word-diff=plain` (`w` on commit details)
+    pub diff_word_mode: bool,
+    /// Hide whitespace-only changes, like `git diff -w` (`W` on commit details)
+    pub diff_ignore_whitespace: bool,
+    pub file_diff_mode: FileDiffMode,
+    /// Path and diff shown in the details pane while `file_diff_mode` is Staged or Unstaged
+    pub status_file_diff: Option<(String, String)>,
+    /// Shortstat summary of the selected commit ("N files changed, ...")
+    pub current_diff_stat: Option<String>,
+    /// (status letter, path) of each file the selected commit changes
+    pub current_diff_files: Vec<(String, String)>,
+    /// Line of each file's header within `current_diff`, for `[` / `]`
+    pub diff_file_offsets: Vec<(String, usize)>,
+    /// File highlighted in the details pane's file list
+    pub details_file_cursor: Option<usize>,
+    /// The details pane's file list is folded to its heading (F)
+    pub details_files_collapsed: bool,
     pub git_user_name: Option<String>,
     pub git_user_email: Option<String>,
     pub git_remote_host: Option<String>,
+    /// Names from `git remote`
+    pub available_remotes: Vec<String>,
+    pub selected_remote_idx: usize,
+    pub remote_name_input: String,
+    pub remote_url_input: String,
+    pub remote_new_name_input: String,
+    /// Which remote dialog field has focus: the URL when adding, the new name when renaming (Tab switches)
+    pub remote_editing_second: bool,
+    /// Remote awaiting confirmation of its removal
+    pub pending_remote: Option<String>,
     pub has_git_repo: bool,
     pub git_repo: Option<GitRepo>,
     pub scroll_offset: usize,
     pub details_scroll_offset: usize,
+    /// Inner heights of the last drawn panes, for PageUp/PageDown
+    pub graph_viewport_height: usize,
+    pub details_viewport_height: usize,
+    pub status_viewport_height: usize,
     pub details_horizontal_offset: usize,
+    pub details_parent_cursor: Option<usize>,
+    /// Row in the details pane where the diff begins; set while drawing
+    pub details_diff_start: usize,
     pub command_scroll_offset: usize,
     pub command_list: Vec<GitCommand>,
     pub selected_command_idx: usize,
     pub status_message: Option<String>,
     pub status_message_time: Option<Instant>,
     pub pending_command: Option<GitCommand>,
+    /// Edit the message in $EDITOR when the pending cherry-pick runs (toggled with `e`)
+    pub cherry_pick_edit: bool,
+    /// Set after handing the terminal to an external program; the main loop clears the screen
+    pub needs_full_redraw: bool,
+    /// Chars between the text cursor and the end of the active input (0 = at the end)
+    pub input_cursor: usize,
+    pub worktrees: Vec<WorktreeEntry>,
+    pub selected_worktree_idx: usize,
+    pub worktree_branch_input: String,
+    pub worktree_path_input: String,
+    /// Which create-form field has focus (Tab switches)
+    pub worktree_editing_path: bool,
+    pub push_remote_input: String,
+    pub push_refspec_input: String,
+    /// Which push-to field has focus (Tab switches)
+    pub push_editing_refspec: bool,
+    pub upstream_remote_input: String,
+    pub upstream_branch_input: String,
+    /// Which set-upstream field has focus (Tab switches)
+    pub upstream_editing_branch: bool,
+    pub conflict_files: Vec<ConflictFile>,
+    pub selected_conflict_idx: usize,
+    pub conflict_operation: Option<ConflictOperation>,
+    pub quick_log_entries: Vec<QuickLogEntry>,
+    pub quick_log_query: String,
+    /// Index into the filtered quick log list
+    pub quick_log_selected: usize,
+    pub reflog_entries: Vec<ReflogEntry>,
+    pub selected_reflog_idx: usize,
+    /// The reflog view lists only commits no ref reaches (RecoverOrphanedCommit)
+    pub reflog_orphans_only: bool,
+    pub repo_stats: RepoStats,
+    pub repo_stats_result: Arc<Mutex<RepoStats>>,
+    pub blame_lines: Vec<BlameLine>,
+    pub selected_blame_idx: usize,
+    /// File shown in the blame view
+    pub blame_path: String,
+    /// File whose history is shown, with the commits that touched it (newest first)
+    pub file_history_path: Option<String>,
+    pub file_history_nodes: Vec<GraphNode>,
+    pub selected_file_history_idx: usize,
+    /// The selected commit's diff of just that file
+    pub file_history_diff: Option<String>,
+    pub file_history_scroll: usize,
+    pub rebase_todo_entries: Vec<RebaseTodoEntry>,
+    pub selected_rebase_todo_idx: usize,
+    /// Commit the todo list is replayed onto (parent of the oldest entry)
+    pub rebase_todo_base: Option<String>,
+    /// `rebase --onto` picks: the commit to land on, then the one whose descendants are replayed
+    pub rebase_onto_new_base: Option<String>,
+    pub rebase_onto_upstream: Option<String>,
+    /// Changed-line count of a selected diff held back for being too large
+    pub pending_large_diff: Option<usize>,
+    /// Commit being merged while the merge message dialog is open
+    pub pending_merge_commit_id: Option<String>,
+    /// Hash shown for manual copying when no clipboard tool is installed
+    pub yanked_hash: Option<String>,
+    /// File whose working-tree changes DiscardFileChanges throws away (D on git status)
+    pub pending_discard_file: Option<String>,
+    /// Most commits to load (`--limit`); `None` loads everything
+    pub commit_limit: Option<usize>,
+    /// How much LoadMore raises `commit_limit` by: the `--limit` given on the command line
+    pub commit_limit_step: Option<usize>,
+    /// Commits in `graph`, including batches loaded while scrolling
+    pub commits_loaded: usize,
+    /// The last load came back short, so there is no more history to fetch
+    pub all_commits_loaded: bool,
+    /// A background batch is in flight; the graph shows "Loading…" under the last commit
+    pub loading_more_commits: bool,
+    pub more_commits_result: Arc<Mutex<Option<CommitBatch>>>,
+    /// When the last batch failed to load, so a lasting error isn't retried on every tick
+    pub more_commits_failed_at: Option<Instant>,
+    /// The history walk later batches come from, started with the first of them
+    history_walker: Option<HistoryWalker>,
+    /// Auto-refresh when the repository changes; on by default, Ctrl+W toggles it
+    pub watch_mode: bool,
+    /// Raised by the watcher thread, consumed by the main loop
+    pub pending_refresh: Arc<AtomicBool>,
+    /// Stop flag of the running watcher thread
+    watch_stop: Option<Arc<AtomicBool>>,
     pub pending_command_message: Option<String>,
+    pub last_command: Option<String>,
+    pub last_repeatable_command: Option<GitCommand>,
     pub git_status_files: Vec<StatusFile>,
     pub selected_file_idx: Option<usize>,
     pub commit_message_input: String,
     pub branch_name_input: String,
     pub pending_branch_commit_id: Option<String>,
+    /// Branch being renamed while the rename dialog (which shares `branch_name_input`) is open
+    pub pending_rename_branch: Option<String>,
+    /// (remote, old remote branch, new name) offered for renaming on the remote after a local rename
+    pub pending_remote_rename: Option<(String, String, String)>,
+    pub tag_name_input: String,
+    pub pending_tag_commit_id: Option<String>,
     pub available_branches: Vec<String>,
     pub selected_branch_idx: usize,
+    pub hooks: Vec<HookInfo>,
+    pub selected_hook_idx: usize,
+    pub stash_entries: Vec<StashEntry>,
+    pub selected_stash_idx: usize,
+    /// Stash picked with `D` in the stash list, waiting on the drop confirmation
+    pub pending_stash_drop: Option<String>,
+    /// Stash marked with Ctrl+D, waiting for a second one to diff against
+    pub stash_compare_idx: Option<usize>,
+    pub stash_diff: Option<String>,
+    pub stash_diff_title: Option<String>,
+    /// Untracked files saved by `git stash push -u`, listed under the stash diff
+    pub stash_untracked_files: Vec<String>,
+    pub commit_tree_entries: Vec<CommitTreeEntry>,
+    pub selected_tree_idx: usize,
+    pub commit_tree_commit_id: Option<String>,
+    pub working_tree_summary: Option<WorkingTreeSummary>,
     pub pending_checkout_commit_id: Option<String>,
     pub config_input: String,
     pub remote_host_input: String,
     pub squash_count_input: String,
     pub pending_squash_commit_id: Option<String>,
+    /// The combined message shown in the squash confirmation; exactly this text is committed
+    pub pending_squash_message: Option<String>,
     pub reword_message_input: String,
     pub pending_reword_commit_id: Option<String>,
     pub git_validation: Arc<Mutex<Option<GitValidationResult>>>,
     pub validation_checked: bool,
+    /// Commits reachable in the current view (0 until the background count finishes)
+    pub total_commit_count: usize,
+    pub commit_count_result: Arc<Mutex<Option<usize>>>,
+    /// Files changed per commit (against the first parent) for the graph badges; commits never change, so this outlives refreshes
+    pub commit_file_counts: std::collections::HashMap<String, usize>,
+    pub commit_file_count_result: Arc<Mutex<Option<std::collections::HashMap<String, usize>>>>,
+    /// `%G?` per commit, checked in the background; kept across refreshes like `commit_file_counts`
+    pub commit_signatures: std::collections::HashMap<String, SignatureStatus>,
+    pub commit_signature_result: Arc<Mutex<Option<std::collections::HashMap<String, SignatureStatus>>>>,
     pub selected_commit_ids: Vec<String>,
+    /// Branches ticked with Space in the force-delete picker
+    pub branches_marked_for_delete: Vec<String>,
+    /// Whether the delete picker lists local branches, tags or remote branches
+    pub delete_selection: DeleteSelection,
     pub assign_branch_name_input: String,
+    pub goto_hash_input: String,
     pub commits_not_in_current_branch: std::collections::HashSet<String>,
     pub help_scroll_offset: usize,
+    pub search_query: String,
+    /// Indices into `graph_nodes` matching the search query; `None` shows the whole graph
+    pub filtered_graph_nodes: Option<Vec<usize>>,
+    pub search_editing: bool,
+    pub diff_search_query: String,
+    pub diff_search_results: Vec<usize>,
+    pub diff_search_current: usize,
+    pub file_staging_animation: Option<(usize, StagingAnimState)>,
+    pub diff_preview: Option<DiffPreview>,
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         Self {
+            diff_context_lines: config.diff_context.min(MAX_DIFF_CONTEXT_LINES),
+            commit_limit: config.commit_limit,
+            commit_limit_step: config.commit_limit,
+            commits_loaded: 0,
+            all_commits_loaded: false,
+            loading_more_commits: false,
+            more_commits_result: Arc::new(Mutex::new(None)),
+            more_commits_failed_at: None,
+            history_walker: None,
+            config,
             should_quit: false,
             mode: AppMode::Normal,
             focused_pane: FocusedPane::CommitGraph,
@@ -77,23 +320,67 @@ impl App {
             active_columns: Vec::new(),
             selected_commit_idx: None,
             current_branch: None,
+            repo_name: None,
+            main_branch_name: None,
+            branch_distances: std::collections::HashMap::new(),
+            show_all_branches: true,
+            reviewed_commits: std::collections::HashSet::new(),
+            show_unreviewed_only: false,
+            first_parent_only: false,
+            author_colors: std::collections::HashMap::new(),
+            graph_separators: Vec::new(),
+            tag_entries: Vec::new(),
+            selected_tag_idx: 0,
+            tag_sort: TagSort::Name,
+            tag_verification_cache: std::collections::HashMap::new(),
+            tag_verification_result: Arc::new(Mutex::new(None)),
+            origin_tags: None,
+            origin_tags_result: Arc::new(Mutex::new(None)),
+            branch_labels: std::collections::HashMap::new(),
+            remote_ref_labels: std::collections::HashMap::new(),
+            tags: std::collections::HashMap::new(),
             branch_ahead: 0,
             branch_behind: 0,
             has_upstream: false,
             current_diff: None,
+            diff_word_mode: false,
+            diff_ignore_whitespace: false,
+            file_diff_mode: FileDiffMode::Commit,
+            status_file_diff: None,
+            current_diff_stat: None,
+            current_diff_files: Vec::new(),
+            diff_file_offsets: Vec::new(),
+            details_file_cursor: None,
+            details_files_collapsed: false,
             git_user_name: None,
             git_user_email: None,
             git_remote_host: None,
+            available_remotes: Vec::new(),
+            selected_remote_idx: 0,
+            remote_name_input: String::new(),
+            remote_url_input: String::new(),
+            remote_new_name_input: String::new(),
+            remote_editing_second: false,
+            pending_remote: None,
             has_git_repo: false,
             git_repo: None,
             scroll_offset: 0,
             details_scroll_offset: 0,
+            graph_viewport_height: 0,
+            details_viewport_height: 0,
+            status_viewport_height: 0,
             details_horizontal_offset: 0,
+            details_parent_cursor: None,
+            details_diff_start: 0,
             command_scroll_offset: 0,
             command_list: vec![
                 GitCommand::Checkout,
                 GitCommand::CreateBranch,
+                GitCommand::RenameBranch,
+                GitCommand::CreateTag,
+                GitCommand::DeleteTag,
                 GitCommand::ForceDeleteBranch,
+                GitCommand::DeleteMergedBranches,
                 GitCommand::Reset,
                 GitCommand::ResetSoft,
                 GitCommand::ResetHard,
@@ -101,43 +388,156 @@ impl App {
                 GitCommand::Revert,
                 GitCommand::Rebase,
                 GitCommand::Merge,
+                GitCommand::MergeNoFF,
+                GitCommand::MergeSquash,
                 GitCommand::SquashCommits,
                 GitCommand::Reword,
                 GitCommand::AssignToBranch,
                 GitCommand::Add,
+                GitCommand::StageAll,
+                GitCommand::UnstageAll,
                 GitCommand::Commit,
+                GitCommand::AmendKeepMessage,
+                GitCommand::Fixup,
                 GitCommand::Push,
+                GitCommand::PushForceWithLease,
+                GitCommand::PushTo,
+                GitCommand::SetUpstream,
+                GitCommand::DeleteRemoteBranch,
+                GitCommand::InteractiveRebase,
+                GitCommand::RebaseOnto,
+                GitCommand::RebaseAutosquash,
+                GitCommand::LoadMore,
                 GitCommand::Pull,
+                GitCommand::Fetch,
                 GitCommand::PullAll,
                 GitCommand::SetUserName,
                 GitCommand::SetUserEmail,
+                GitCommand::EditRepoConfig,
                 GitCommand::SetRemoteHost,
+                GitCommand::AddRemote,
+                GitCommand::RemoveRemote,
+                GitCommand::RenameRemote,
+                GitCommand::PruneRemote,
+                GitCommand::RecoverOrphanedCommit,
+                GitCommand::RepeatLastCommand,
+                GitCommand::ShowHooks,
+                GitCommand::Stash,
+                GitCommand::StashApply,
+                GitCommand::StashPop,
+                GitCommand::StashDrop,
+                GitCommand::ShowWorkingTreeSummary,
+                GitCommand::ShowUnreviewed,
+                GitCommand::QuickLog,
+                GitCommand::ShowWorktrees,
             ],
             selected_command_idx: 0,
             status_message: None,
             status_message_time: None,
             pending_command: None,
+            cherry_pick_edit: false,
+            needs_full_redraw: false,
+            input_cursor: 0,
+            worktrees: Vec::new(),
+            selected_worktree_idx: 0,
+            worktree_branch_input: String::new(),
+            worktree_path_input: String::new(),
+            worktree_editing_path: false,
+            push_remote_input: String::new(),
+            push_refspec_input: String::new(),
+            push_editing_refspec: false,
+            upstream_remote_input: String::new(),
+            upstream_branch_input: String::new(),
+            upstream_editing_branch: false,
+            conflict_files: Vec::new(),
+            selected_conflict_idx: 0,
+            conflict_operation: None,
+            quick_log_entries: Vec::new(),
+            quick_log_query: String::new(),
+            quick_log_selected: 0,
+            reflog_entries: Vec::new(),
+            selected_reflog_idx: 0,
+            reflog_orphans_only: false,
+            repo_stats: RepoStats::default(),
+            repo_stats_result: Arc::new(Mutex::new(RepoStats::default())),
+            blame_lines: Vec::new(),
+            selected_blame_idx: 0,
+            blame_path: String::new(),
+            file_history_path: None,
+            file_history_nodes: Vec::new(),
+            selected_file_history_idx: 0,
+            file_history_diff: None,
+            file_history_scroll: 0,
+            rebase_todo_entries: Vec::new(),
+            selected_rebase_todo_idx: 0,
+            rebase_todo_base: None,
+            rebase_onto_new_base: None,
+            rebase_onto_upstream: None,
+            pending_large_diff: None,
+            pending_merge_commit_id: None,
+            yanked_hash: None,
+            pending_discard_file: None,
+            watch_mode: true,
+            pending_refresh: Arc::new(AtomicBool::new(false)),
+            watch_stop: None,
             pending_command_message: None,
+            last_command: None,
+            last_repeatable_command: None,
             git_status_files: Vec::new(),
             selected_file_idx: None,
             commit_message_input: String::new(),
             branch_name_input: String::new(),
             pending_branch_commit_id: None,
+            pending_rename_branch: None,
+            pending_remote_rename: None,
+            tag_name_input: String::new(),
+            pending_tag_commit_id: None,
             available_branches: Vec::new(),
             selected_branch_idx: 0,
+            hooks: Vec::new(),
+            selected_hook_idx: 0,
+            stash_entries: Vec::new(),
+            selected_stash_idx: 0,
+            stash_compare_idx: None,
+            stash_diff: None,
+            stash_diff_title: None,
+            stash_untracked_files: Vec::new(),
+            pending_stash_drop: None,
+            commit_tree_entries: Vec::new(),
+            selected_tree_idx: 0,
+            commit_tree_commit_id: None,
+            working_tree_summary: None,
             pending_checkout_commit_id: None,
             config_input: String::new(),
             remote_host_input: String::new(),
             squash_count_input: String::new(),
             pending_squash_commit_id: None,
+            pending_squash_message: None,
             reword_message_input: String::new(),
             pending_reword_commit_id: None,
             git_validation: Arc::new(Mutex::new(None)),
             validation_checked: false,
+            total_commit_count: 0,
+            commit_count_result: Arc::new(Mutex::new(None)),
+            commit_file_counts: std::collections::HashMap::new(),
+            commit_file_count_result: Arc::new(Mutex::new(None)),
+            commit_signatures: std::collections::HashMap::new(),
+            commit_signature_result: Arc::new(Mutex::new(None)),
             selected_commit_ids: Vec::new(),
+            branches_marked_for_delete: Vec::new(),
+            delete_selection: DeleteSelection::Branches,
             assign_branch_name_input: String::new(),
+            goto_hash_input: String::new(),
             commits_not_in_current_branch: std::collections::HashSet::new(),
             help_scroll_offset: 0,
+            search_query: String::new(),
+            filtered_graph_nodes: None,
+            search_editing: false,
+            diff_search_query: String::new(),
+            diff_search_results: Vec::new(),
+            diff_search_current: 0,
+            file_staging_animation: None,
+            diff_preview: None,
         }
     }
 
@@ -151,38 +551,38 @@ impl App {
                 if let Ok(branch) = repo.get_current_branch() {
                     self.current_branch = Some(branch);
                 }
+                self.repo_name = repo.get_repo_name();
 
                 // Load git user configuration
                 self.load_git_user_config();
 
                 // Load git remote host
                 self.load_git_remote_host();
+                self.load_remotes();
 
                 // Load commits
-                let mut graph = repo.load_commits()?;
+                let mut graph = repo.load_commits(self.show_all_branches, self.first_parent_only, self.commit_limit)?;
+                self.commits_loaded = graph.commits.len();
+                self.all_commits_loaded = self.commit_limit.is_none_or(|limit| self.commits_loaded < limit);
+                // Batches still loading belong to the previous graph, and the walk to its refs
+                self.more_commits_result = Arc::new(Mutex::new(None));
+                self.loading_more_commits = false;
+                self.more_commits_failed_at = None;
+                self.history_walker = None;
 
                 // Get main branch commit ID (try "master" first, then "main")
-                let main_branch_commit = repo.get_branch_commit_id("master")
-                    .or_else(|_| repo.get_branch_commit_id("main"))
-                    .ok();
+                let main_branch = ["master", "main"].iter()
+                    .find_map(|name| repo.get_branch_commit_id(name).ok().map(|id| (name.to_string(), id)));
+                let main_branch_commit = main_branch.as_ref().map(|(_, id)| id.clone());
+                self.main_branch_name = main_branch.map(|(name, _)| name);
+                self.branch_distances.clear();
+                self.load_tags();
+                self.load_branch_labels();
+                self.load_remote_ref_labels();
 
-                // Perform topological sort (returns oldest-to-newest)
-                let sorted_commits = graph.topological_sort();
-
-                // Calculate which commits are not in current branch BEFORE creating nodes
-                // This populates commits_not_in_current_branch HashSet
                 self.commits_not_in_current_branch.clear();
-                for commit_id in &sorted_commits {
-                    if !self.is_ancestor_of_head(commit_id) {
-                        self.commits_not_in_current_branch.insert(commit_id.clone());
-                    }
-                }
-
-                // Reverse to newest-to-oldest for column assignment
-                // This ensures the newest child continues in parent's lane (main line)
-                let mut newest_first = sorted_commits.clone();
-                newest_first.reverse();
-                self.graph_nodes = self.assign_columns(&mut graph, &newest_first, main_branch_commit, &repo);
+                self.mark_commits_not_in_current_branch(graph.commits.keys());
+                self.layout_graph(&mut graph, main_branch_commit);
 
                 self.graph = graph;
                 self.git_repo = Some(repo);
@@ -196,6 +596,7 @@ impl App {
                     }
                     // Load diff for the first commit
                     self.load_current_diff();
+                    self.load_branch_distances();
                 }
 
                 // Load git status
@@ -204,9 +605,17 @@ impl App {
                 // Calculate ahead/behind counts
                 self.update_branch_ahead_behind();
 
+                // Count the full history in background so the graph title can show how much is loaded
+                self.start_commit_count();
+                self.start_file_counts();
+                self.start_signature_checks();
+
                 // Start git validation in background
                 self.start_git_validation();
 
+                // Pick up commits, checkouts and staging done outside the app
+                self.ensure_watcher();
+
                 Ok(())
             }
             Err(e) => {
@@ -217,7 +626,7 @@ impl App {
         }
     }
 
-    fn update_branch_ahead_behind(&mut self) {
+    pub(super) fn update_branch_ahead_behind(&mut self) {
         // Reset to 0
         self.branch_ahead = 0;
         self.branch_behind = 0;
@@ -261,13 +670,46 @@ impl App {
         }
     }
 
-    fn assign_columns(&mut self, graph: &mut CommitGraph, sorted_commits: &[String], main_branch_commit: Option<String>, repo: &GitRepo) -> Vec<GraphNode> {
+    /// Separators wherever two neighbouring commits fall in different periods. They sit beside
+    /// `graph_nodes` rather than in it, so node indices (selection, search, marks) are unaffected
+    pub(super) fn assign_date_separators(&mut self) {
+        let grouping = self.config.date_grouping;
+        let periods: Vec<Option<String>> = self.graph_nodes.iter()
+            .map(|node| grouping.period_label(node.commit.timestamp))
+            .collect();
+
+        self.graph_separators = periods.windows(2)
+            .enumerate()
+            .filter_map(|(idx, pair)| match pair {
+                [Some(above), Some(below)] if above != below => Some((idx + 1, below.clone())),
+                _ => None,
+            })
+            .collect();
+    }
+
+    /// Give each author not seen yet the next lane color, newest commits first
+    fn assign_author_colors(&mut self) {
+        let palette = self.config.theme.lane_colors;
+        for node in &self.graph_nodes {
+            let next = self.author_colors.len();
+            self.author_colors.entry(node.commit.author.clone()).or_insert(palette[next % palette.len()]);
+        }
+    }
+
+    /// Lay out `sorted_commits` (newest first) into graph lanes. Public so `benches/assign_columns.rs` can time it.
+    pub fn assign_columns(&mut self, graph: &mut CommitGraph, sorted_commits: &[String], main_branch_commit: Option<String>) -> Vec<GraphNode> {
         use crate::graph::Connection;
         use std::collections::{HashMap, HashSet};
 
         let mut nodes = Vec::new();
         let mut commit_columns: HashMap<String, usize> = HashMap::new();
 
+        // Row of every commit, built once - scanning sorted_commits per parent is quadratic on big repos
+        let position_map: HashMap<&str, usize> = sorted_commits.iter()
+            .enumerate()
+            .map(|(idx, id)| (id.as_str(), idx))
+            .collect();
+
         // Step 1: Identify main branch (follow first-parent chain from master/main)
         let mut main_branch: HashSet<String> = HashSet::new();
         let start_commit = main_branch_commit.or_else(|| sorted_commits.first().cloned());
@@ -299,7 +741,11 @@ impl App {
         lane_live_commits.insert(0, HashSet::new());
 
         for (commit_idx, commit_id) in sorted_commits.iter().enumerate() {
-            if let Some(commit) = graph.commits.get(commit_id).cloned() {
+            if let Some(mut commit) = graph.commits.get(commit_id).cloned() {
+                // Merged-in parents aren't loaded in first-parent mode; opening lanes for them would undo the single line
+                if self.first_parent_only {
+                    commit.parents.truncate(1);
+                }
                 let mut connections = Vec::new();
 
                 // Get this commit's column (already assigned for main branch)
@@ -359,9 +805,8 @@ impl App {
                         // Remove this commit from live commits now that it's been processed
                         // But only if the parent has already been processed (is earlier in the list)
                         // For commits whose parents come later (feature branches), keep them live
-                        let parent_processed = sorted_commits.iter()
-                            .take(commit_idx)
-                            .any(|id| id == parent_id);
+                        let parent_processed = position_map.get(parent_id.as_str())
+                            .is_some_and(|&parent_idx| parent_idx < commit_idx);
 
                         if parent_processed {
                             if let Some(live) = lane_live_commits.get_mut(&column) {
@@ -420,6 +865,7 @@ impl App {
                     column,
                     connections,
                     in_current_branch: !self.is_commit_not_in_current_branch(commit_id),
+                    remote_refs: self.remote_ref_labels.get(commit_id).cloned().unwrap_or_default(),
                 });
             }
         }
@@ -438,12 +884,9 @@ impl App {
                 // Add this commit's extent - extend to parent location
                 if let Some(commit) = graph.commits.get(commit_id) {
                     // Find the furthest parent index for this commit
-                    let mut max_parent_idx = idx;
-                    for parent_id in &commit.parents {
-                        if let Some(parent_idx) = sorted_commits.iter().position(|id| id == parent_id) {
-                            max_parent_idx = max_parent_idx.max(parent_idx);
-                        }
-                    }
+                    let max_parent_idx = commit.parents.iter()
+                        .filter_map(|parent_id| position_map.get(parent_id.as_str()).copied())
+                        .fold(idx, usize::max);
                     // Extend by 1 for the edge row after the furthest parent
                     lane_extent_map.entry(col)
                         .and_modify(|e| { e.0 = e.0.min(idx); e.1 = e.1.max(max_parent_idx + 1); })
@@ -622,6 +1065,7 @@ impl App {
         use std::process::Command;
 
         // Load remote URL for origin
+        self.git_remote_host = None;
         if let Ok(output) = Command::new("git")
             .args(&["remote", "get-url", "origin"])
             .output()
@@ -651,11 +1095,347 @@ impl App {
         self.commits_not_in_current_branch.contains(commit_id)
     }
 
+    /// Terminal window title: "git_manager – <branch> – <repo>", plus the open dialog in brackets
+    pub fn window_title(&self) -> String {
+        let mut title = String::from("git_manager");
+        if let Some(branch) = &self.current_branch {
+            title.push_str(&format!(" – {}", branch));
+        }
+        if let Some(repo_name) = &self.repo_name {
+            title.push_str(&format!(" – {}", repo_name));
+        }
+        if let Some(mode_name) = self.mode.title_name() {
+            title.push_str(&format!(" [{}]", mode_name));
+        }
+        title
+    }
+
+    pub fn toggle_show_all_branches(&mut self) {
+        self.show_all_branches = !self.show_all_branches;
+        self.refresh();
+        if self.show_all_branches {
+            self.set_status_message("✓ Showing all branches".to_string());
+        } else {
+            self.set_status_message("✓ Showing current branch only".to_string());
+        }
+    }
+
+    pub fn toggle_first_parent_only(&mut self) {
+        self.first_parent_only = !self.first_parent_only;
+        self.refresh();
+        if self.first_parent_only {
+            self.set_status_message("✓ Showing the first-parent line from HEAD".to_string());
+        } else {
+            self.set_status_message("✓ Showing the full graph".to_string());
+        }
+    }
+
+    pub fn toggle_selected_reviewed(&mut self) {
+        let Some(node) = self.selected_commit_idx.and_then(|idx| self.graph_nodes.get(idx)) else {
+            return;
+        };
+        let commit_id = node.commit.id.clone();
+        let short_id = node.commit.short_id.clone();
+
+        if self.reviewed_commits.remove(&commit_id) {
+            self.set_status_message(format!("✓ Unmarked {} as reviewed", short_id));
+        } else {
+            self.reviewed_commits.insert(commit_id);
+            self.set_status_message(format!("✓ Marked {} as reviewed", short_id));
+            // With only unreviewed commits shown, the one just marked drops out of view
+            if self.show_unreviewed_only {
+                let scroll_offset = self.scroll_offset;
+                self.update_search_filter();
+                self.scroll_offset = scroll_offset;
+                self.select_filtered_commit();
+            }
+        }
+    }
+
+    pub fn cycle_commit_sort(&mut self) {
+        self.config.commit_sort = self.config.commit_sort.next();
+        self.refresh();
+        match Config::save_setting("commit_sort", self.config.commit_sort.config_name()) {
+            Ok(()) => self.set_status_message(format!("✓ Sorting commits by {}", self.config.commit_sort.label())),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    /// Add the commits of `ids` that HEAD doesn't contain to `commits_not_in_current_branch`.
+    /// One git call per commit, so a new batch only checks its own commits.
+    fn mark_commits_not_in_current_branch<'a>(&mut self, ids: impl IntoIterator<Item = &'a String>) {
+        for commit_id in ids {
+            if !self.is_ancestor_of_head(commit_id) {
+                self.commits_not_in_current_branch.insert(commit_id.clone());
+            }
+        }
+    }
+
+    /// Rebuild `graph_nodes` from every commit in `graph`; `commits_not_in_current_branch` must
+    /// already cover them
+    fn layout_graph(&mut self, graph: &mut CommitGraph, main_branch_commit: Option<String>) {
+        // Sort in the configured order (returns oldest-to-newest)
+        let sorted_commits = graph.sorted_commits(self.config.commit_sort);
+
+        // Reverse to newest-to-oldest for column assignment
+        // This ensures the newest child continues in parent's lane (main line)
+        let mut newest_first = sorted_commits.clone();
+        newest_first.reverse();
+        self.graph_nodes = self.assign_columns(graph, &newest_first, main_branch_commit);
+        self.assign_author_colors();
+        self.assign_date_separators();
+        // Indices into the old node list are meaningless now
+        if self.filtered_graph_nodes.is_some() {
+            self.update_search_filter();
+        }
+    }
+
+    /// Fetch the next `commit_limit_step` commits in the background once the unfiltered graph is
+    /// scrolled within `LOAD_MORE_MARGIN_ROWS` of the last loaded row
+    pub fn check_load_more_commits(&mut self) {
+        let batch = self.more_commits_result.lock().ok().and_then(|mut slot| slot.take());
+        if let Some(batch) = batch {
+            self.loading_more_commits = false;
+            match batch {
+                Ok(batch) => self.merge_commit_batch(batch),
+                // Not the end of the history, so the batch is tried again after a while, on a fresh
+                // walk since the failed one may have stopped partway through it
+                Err(e) => {
+                    self.more_commits_failed_at = Some(Instant::now());
+                    self.history_walker = None;
+                    self.set_status_message(format!("✗ Error: Failed to load more commits: {}", e));
+                }
+            }
+        }
+        if self.more_commits_failed_at.is_some_and(|failed_at| failed_at.elapsed() < LOAD_MORE_RETRY_DELAY) {
+            return;
+        }
+
+        // A filter with few matches always looks scrolled to the end, which would pull in the whole
+        // history batch by batch; LoadMore still works while filtering
+        if self.loading_more_commits || self.all_commits_loaded || !self.has_git_repo || self.filtered_graph_nodes.is_some() {
+            return;
+        }
+        let Some(step) = self.commit_limit_step else {
+            return;
+        };
+        let last_row = self.graph_row_of(self.graph_nodes.len());
+        if self.scroll_offset + self.graph_viewport_height + LOAD_MORE_MARGIN_ROWS < last_row {
+            return;
+        }
+
+        // A fresh slot per batch so one started before a refresh can't land in the new graph
+        let result = Arc::new(Mutex::new(None));
+        self.more_commits_result = Arc::clone(&result);
+        self.loading_more_commits = true;
+
+        let (all_refs, first_parent, offset) = (self.show_all_branches, self.first_parent_only, self.commits_loaded);
+        self.history_walker
+            .get_or_insert_with(|| HistoryWalker::start(all_refs, first_parent, offset))
+            .request(step, result);
+    }
+
+    /// Lay the graph out again with `batch` added, keeping the selected commit selected
+    fn merge_commit_batch(&mut self, batch: CommitGraph) {
+        let added = batch.commits.len();
+        if self.commit_limit_step.is_none_or(|step| added < step) {
+            self.all_commits_loaded = true;
+        }
+        if added == 0 {
+            return;
+        }
+
+        let selected_id = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .map(|node| node.commit.id.clone());
+        let main_branch_commit = self.main_branch_name.as_ref().zip(self.git_repo.as_ref())
+            .and_then(|(name, repo)| repo.get_branch_commit_id(name).ok());
+
+        self.mark_commits_not_in_current_branch(batch.commits.keys());
+        let mut graph = std::mem::replace(&mut self.graph, CommitGraph::new());
+        graph.extend(batch);
+        self.layout_graph(&mut graph, main_branch_commit);
+        self.graph = graph;
+
+        // A refresh reloads as much history as is loaded now
+        self.commits_loaded += added;
+        self.commit_limit = Some(self.commits_loaded);
+
+        if let Some(id) = selected_id {
+            self.selected_commit_idx = self.graph_nodes.iter().position(|node| node.commit.id == id);
+            self.graph.trace_ancestry(&id);
+        }
+        self.start_file_counts();
+        self.start_signature_checks();
+    }
+
+    /// Cheap refresh of the working tree status and ahead/behind counts, leaving the graph untouched
+    pub fn refresh_status(&mut self) {
+        self.load_git_status();
+        self.update_branch_ahead_behind();
+        self.set_status_message("✓ Status refreshed".to_string());
+    }
+
     pub fn refresh(&mut self) {
+        let previous_nodes = self.graph_nodes.clone();
+        let previous_selected_id = self.selected_commit_idx
+            .and_then(|idx| previous_nodes.get(idx))
+            .map(|node| node.commit.id.clone());
+        let previous_details_scroll = self.details_scroll_offset;
+
         let _ = self.init();
+
+        // init() always selects the newest commit; map the old selection onto the new graph instead
+        let new_idx = previous_selected_id.as_ref().and_then(|id| {
+            self.graph_nodes.iter().position(|node| &node.commit.id == id)
+        });
+
+        match new_idx {
+            Some(idx) => {
+                if Some(idx) != self.selected_commit_idx {
+                    self.selected_commit_idx = Some(idx);
+                    self.update_selection();
+                }
+                // Unchanged layout means the same diff is showing, so keep the reader's place in it
+                if self.graph_nodes == previous_nodes {
+                    self.details_scroll_offset = previous_details_scroll;
+                }
+            }
+            None => {
+                self.scroll_offset = 0;
+            }
+        }
+
         self.set_status_message("✓ Refreshed".to_string());
     }
 
+    pub fn start_commit_count(&mut self) {
+        // A fresh slot per run so a count started before a refresh can't overwrite a newer one
+        let result = Arc::new(Mutex::new(None));
+        self.commit_count_result = Arc::clone(&result);
+        // Same refs load_commits walks; plain --all would also count stashes and notes
+        let mut args = vec!["rev-list", "--count", "HEAD"];
+        if self.first_parent_only {
+            args.push("--first-parent");
+        } else if self.show_all_branches {
+            args.extend(["--branches", "--remotes", "--tags"]);
+        }
+
+        thread::spawn(move || {
+            let count = Command::new("git")
+                .args(&args)
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .and_then(|o| String::from_utf8_lossy(&o.stdout).trim().parse::<usize>().ok());
+
+            if let (Some(count), Ok(mut guard)) = (count, result.lock()) {
+                *guard = Some(count);
+            }
+        });
+    }
+
+    pub fn check_commit_count(&mut self) {
+        let count = self.commit_count_result.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(count) = count {
+            self.total_commit_count = count;
+        }
+    }
+
+    /// Count changed files for every loaded commit not counted yet, in one `git log` call in the background
+    pub fn start_file_counts(&mut self) {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let result = Arc::new(Mutex::new(None));
+        self.commit_file_count_result = Arc::clone(&result);
+
+        let ids: String = self.graph_nodes.iter()
+            .filter(|node| !self.commit_file_counts.contains_key(&node.commit.id))
+            .map(|node| format!("{}\n", node.commit.id))
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+
+        thread::spawn(move || {
+            // Merges are counted against their first parent, like the details pane diff
+            let child = Command::new("git")
+                .args(&["log", "--no-walk=unsorted", "--stdin", "-m", "--first-parent", "--name-only", "--format=%x01%H"])
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn();
+            let Ok(mut child) = child else {
+                return;
+            };
+
+            // git reads all of stdin before it starts writing, so this can't deadlock
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = stdin.write_all(ids.as_bytes());
+            }
+            let Ok(output) = child.wait_with_output() else {
+                return;
+            };
+
+            let mut counts = std::collections::HashMap::new();
+            let mut current: Option<String> = None;
+            for line in String::from_utf8_lossy(&output.stdout).lines() {
+                if let Some(id) = line.strip_prefix('\u{1}') {
+                    counts.insert(id.to_string(), 0);
+                    current = Some(id.to_string());
+                } else if let (false, Some(id)) = (line.is_empty(), &current) {
+                    *counts.entry(id.clone()).or_insert(0) += 1;
+                }
+            }
+
+            if let Ok(mut guard) = result.lock() {
+                *guard = Some(counts);
+            }
+        });
+    }
+
+    pub fn check_file_counts(&mut self) {
+        let counts = self.commit_file_count_result.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(counts) = counts {
+            self.commit_file_counts.extend(counts);
+        }
+    }
+
+    /// Verify the signatures of loaded commits not checked yet, off the UI thread
+    pub fn start_signature_checks(&mut self) {
+        // A fresh slot per run so a check started before a refresh can't overwrite a newer one
+        let result = Arc::new(Mutex::new(None));
+        self.commit_signature_result = Arc::clone(&result);
+
+        let ids: String = self.graph_nodes.iter()
+            .filter(|node| !self.commit_signatures.contains_key(&node.commit.id))
+            .map(|node| format!("{}\n", node.commit.id))
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+
+        thread::spawn(move || {
+            let statuses = GitRepo::load_signature_statuses(&ids);
+            if let Ok(mut guard) = result.lock() {
+                *guard = Some(statuses);
+            }
+        });
+    }
+
+    pub fn check_signature_statuses(&mut self) {
+        let statuses = self.commit_signature_result.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(statuses) = statuses {
+            self.commit_signatures.extend(statuses);
+        }
+    }
+
+    /// None until the background check has covered the commit, or when it couldn't be checked
+    pub fn signature_status(&self, commit_id: &str) -> Option<SignatureStatus> {
+        self.commit_signatures.get(commit_id).copied()
+    }
+
     pub fn start_git_validation(&self) {
         let validation_result = Arc::clone(&self.git_validation);
 
@@ -669,125 +1449,185 @@ impl App {
     }
 
     fn validate_git_environment() -> GitValidationResult {
-        use std::process::Command;
-
         let mut result = GitValidationResult::new();
 
+        // Every test shares one overall budget so a slow system can't hold validation open
+        let deadline = Instant::now() + VALIDATION_BUDGET;
+
         // Test 1: Check git version
-        if let Ok(output) = Command::new("git").arg("--version").output() {
-            if output.status.success() {
-                let version_str = String::from_utf8_lossy(&output.stdout);
-                if let Some(version) = version_str.split_whitespace().nth(2) {
-                    result.git_version = Some(version.to_string());
-
-                    // Parse version and check if it's >= 2.23 (recommended)
-                    if let Some(major_minor) = version.split('.').take(2).collect::<Vec<_>>().get(0..2) {
-                        if let (Ok(major), Ok(minor)) = (major_minor[0].parse::<u32>(), major_minor[1].parse::<u32>()) {
-                            result.version_ok = major > 2 || (major == 2 && minor >= 23);
+        if let Some(version_test) = Self::run_validation_command(&["--version"], deadline, &mut result) {
+            if let Ok(output) = version_test {
+                if output.status.success() {
+                    let version_str = String::from_utf8_lossy(&output.stdout);
+                    if let Some(version) = version_str.split_whitespace().nth(2) {
+                        result.git_version = Some(version.to_string());
+
+                        // Parse version and check if it's >= 2.23 (recommended)
+                        if let Some(major_minor) = version.split('.').take(2).collect::<Vec<_>>().get(0..2) {
+                            if let (Ok(major), Ok(minor)) = (major_minor[0].parse::<u32>(), major_minor[1].parse::<u32>()) {
+                                result.version_ok = major > 2 || (major == 2 && minor >= 23);
+                            }
                         }
                     }
                 }
+            } else {
+                result.warnings.push("Could not execute git command".to_string());
             }
-        } else {
-            result.warnings.push("Could not execute git command".to_string());
         }
 
         // Test 2: Validate git log format
-        let log_test = Command::new("git")
-            .args(&["log", "--pretty=format:%H|%h|%P|%s|%an|%at", "-1"])
-            .output();
-
-        if let Ok(output) = log_test {
-            if !output.status.success() || output.stdout.is_empty() {
-                result.failed_commands.push("git log --pretty=format:...".to_string());
-            } else {
-                // Validate format: should have 6 pipe-separated fields
-                let log_str = String::from_utf8_lossy(&output.stdout);
-                let fields: Vec<&str> = log_str.trim().split('|').collect();
-                if fields.len() != 6 {
-                    result.warnings.push(format!("git log format validation failed: expected 6 fields, got {}", fields.len()));
+        // Fields are NUL-separated since a subject may legitimately contain '|'
+        if let Some(log_test) = Self::run_validation_command(&["log", VALIDATION_LOG_FORMAT, "-1"], deadline, &mut result) {
+            if let Ok(output) = log_test {
+                if !output.status.success() || output.stdout.is_empty() {
+                    result.failed_commands.push("git log --pretty=format:...".to_string());
+                } else {
+                    let log_str = String::from_utf8_lossy(&output.stdout);
+                    if let Err(count) = parse_validation_log_line(log_str.trim()) {
+                        result.warnings.push(format!("git log format validation failed: expected 6 fields, got {}", count));
+                    }
                 }
+            } else {
+                result.failed_commands.push("git log".to_string());
             }
-        } else {
-            result.failed_commands.push("git log".to_string());
         }
 
         // Test 3: Validate git status --porcelain
-        let status_test = Command::new("git")
-            .args(&["status", "--porcelain"])
-            .output();
-
-        if let Err(_) = status_test {
+        if let Some(Err(_)) = Self::run_validation_command(&["status", "--porcelain"], deadline, &mut result) {
             result.failed_commands.push("git status --porcelain".to_string());
         }
 
         // Test 4: Validate git rev-parse
-        let revparse_test = Command::new("git")
-            .args(&["rev-parse", "HEAD"])
-            .output();
-
-        if let Ok(output) = revparse_test {
-            if !output.status.success() {
-                result.warnings.push("Could not get HEAD commit (empty repo?)".to_string());
+        if let Some(revparse_test) = Self::run_validation_command(&["rev-parse", "HEAD"], deadline, &mut result) {
+            if let Ok(output) = revparse_test {
+                if !output.status.success() {
+                    result.warnings.push("Could not get HEAD commit (empty repo?)".to_string());
+                }
+            } else {
+                result.failed_commands.push("git rev-parse HEAD".to_string());
             }
-        } else {
-            result.failed_commands.push("git rev-parse HEAD".to_string());
         }
 
         // Test 5: Validate git branch
-        let branch_test = Command::new("git")
-            .args(&["branch", "--list"])
-            .output();
-
-        if let Err(_) = branch_test {
+        if let Some(Err(_)) = Self::run_validation_command(&["branch", "--list"], deadline, &mut result) {
             result.failed_commands.push("git branch --list".to_string());
         }
 
         // Test 6: Validate git diff
-        let diff_test = Command::new("git")
-            .args(&["diff", "--help"])
-            .output();
-
-        if let Err(_) = diff_test {
+        if let Some(Err(_)) = Self::run_validation_command(&["diff", "--help"], deadline, &mut result) {
             result.failed_commands.push("git diff".to_string());
         }
 
         // Test 7: Validate git commit --amend is available
-        let commit_test = Command::new("git")
-            .args(&["commit", "--help"])
-            .output();
+        if let Some(commit_test) = Self::run_validation_command(&["commit", "--help"], deadline, &mut result) {
+            if let Ok(output) = commit_test {
+                let help_str = String::from_utf8_lossy(&output.stdout);
+                if !help_str.contains("--amend") {
+                    result.warnings.push("git commit --amend may not be available".to_string());
+                }
+            } else {
+                result.failed_commands.push("git commit".to_string());
+            }
+        }
 
-        if let Ok(output) = commit_test {
-            let help_str = String::from_utf8_lossy(&output.stdout);
-            if !help_str.contains("--amend") {
-                result.warnings.push("git commit --amend may not be available".to_string());
+        // Test 8 & 9: Validate git rebase and interactive rebase support
+        if let Some(rebase_test) = Self::run_validation_command(&["rebase", "--help"], deadline, &mut result) {
+            if let Ok(output) = rebase_test {
+                let help_str = String::from_utf8_lossy(&output.stdout);
+                if !help_str.contains("--interactive") && !help_str.contains("-i") {
+                    result.warnings.push("git rebase -i may not be available".to_string());
+                }
+            } else {
+                result.failed_commands.push("git rebase".to_string());
             }
-        } else {
-            result.failed_commands.push("git commit".to_string());
         }
 
-        // Test 8: Validate git rebase is available
-        let rebase_test = Command::new("git")
-            .args(&["rebase", "--help"])
-            .output();
+        result
+    }
 
-        if let Err(_) = rebase_test {
-            result.failed_commands.push("git rebase".to_string());
+    /// Run one validation command within the remaining budget.
+    /// Returns None (after recording a warning) when the command timed out or was skipped.
+    fn run_validation_command(
+        args: &[&str],
+        deadline: Instant,
+        result: &mut GitValidationResult,
+    ) -> Option<std::io::Result<std::process::Output>> {
+        let timeout = VALIDATION_COMMAND_TIMEOUT.min(deadline.saturating_duration_since(Instant::now()));
+
+        if timeout.is_zero() {
+            result.warnings.push(format!("git {} skipped: validation ran out of time", args.join(" ")));
+            return None;
         }
 
-        // Test 9: Validate interactive rebase support
-        let interactive_test = Command::new("git")
-            .args(&["rebase", "--help"])
-            .output();
-
-        if let Ok(output) = interactive_test {
-            let help_str = String::from_utf8_lossy(&output.stdout);
-            if !help_str.contains("--interactive") && !help_str.contains("-i") {
-                result.warnings.push("git rebase -i may not be available".to_string());
+        match Self::run_git_with_timeout(args, timeout) {
+            Ok(None) => {
+                result.warnings.push(format!("git {} timed out after {}ms", args.join(" "), timeout.as_millis()));
+                None
             }
+            other => other.transpose(),
         }
+    }
 
-        result
+    /// Run git, killing it from a monitoring thread if it outlives `timeout`.
+    /// Ok(None) means the command was killed.
+    fn run_git_with_timeout(args: &[&str], timeout: Duration) -> std::io::Result<Option<std::process::Output>> {
+        use std::io::Read;
+        use std::process::Stdio;
+        use std::sync::mpsc;
+
+        let mut child = Command::new("git")
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        // Drain stdout on its own thread so a chatty command can't block on a full pipe
+        let mut stdout = child.stdout.take();
+        let (stdout_tx, stdout_rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            if let Some(ref mut pipe) = stdout {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            let _ = stdout_tx.send(buf);
+        });
+
+        let (status_tx, status_rx) = mpsc::channel();
+        thread::spawn(move || {
+            let deadline = Instant::now() + timeout;
+            loop {
+                match child.try_wait() {
+                    Ok(Some(status)) => {
+                        let _ = status_tx.send(Ok(Some(status)));
+                        return;
+                    }
+                    Ok(None) if Instant::now() >= deadline => {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        let _ = status_tx.send(Ok(None));
+                        return;
+                    }
+                    Ok(None) => thread::sleep(Duration::from_millis(10)),
+                    Err(e) => {
+                        let _ = status_tx.send(Err(e));
+                        return;
+                    }
+                }
+            }
+        });
+
+        let status = status_rx.recv()
+            .map_err(std::io::Error::other)??;
+
+        match status {
+            Some(status) => {
+                // A helper the command spawned (e.g. a pager) may still hold the pipe; don't wait on it forever
+                let stdout = stdout_rx.recv_timeout(Duration::from_millis(200)).unwrap_or_default();
+                Ok(Some(std::process::Output { status, stdout, stderr: Vec::new() }))
+            }
+            None => Ok(None),
+        }
     }
 
     pub fn check_validation_results(&mut self) {
@@ -819,7 +1659,108 @@ impl App {
         }
     }
 
+    pub fn start_diff_preview(&mut self) {
+        self.cancel_diff_preview();
+
+        let commit_id = match self.selected_commit_idx.and_then(|idx| self.graph_nodes.get(idx)) {
+            Some(node) => node.commit.id.clone(),
+            None => return,
+        };
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let result = Arc::new(Mutex::new(None));
+
+        let thread_cancelled = Arc::clone(&cancelled);
+        let thread_result = Arc::clone(&result);
+        let thread_commit_id = commit_id.clone();
+        let rename_threshold = self.config.rename_detection_threshold;
+        let context_lines = self.diff_context_lines as u32;
+        let ignore_whitespace = self.diff_ignore_whitespace;
+
+        thread::spawn(move || {
+            let lines = GitRepo::open_current_dir()
+                .and_then(|repo| repo.get_commit_diff_preview(&thread_commit_id, 10, rename_threshold, context_lines, ignore_whitespace, &thread_cancelled));
+
+            if let Ok(lines) = lines {
+                if !thread_cancelled.load(Ordering::Relaxed) {
+                    if let Ok(mut guard) = thread_result.lock() {
+                        *guard = Some(lines);
+                    }
+                }
+            }
+        });
+
+        self.diff_preview = Some(DiffPreview {
+            commit_id,
+            started: Instant::now(),
+            lines: None,
+            cancelled,
+            result,
+        });
+    }
+
+    pub fn cancel_diff_preview(&mut self) {
+        if let Some(preview) = self.diff_preview.take() {
+            preview.cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub fn check_diff_preview(&mut self) {
+        if let Some(preview) = self.diff_preview.as_mut() {
+            if preview.lines.is_none() {
+                if let Ok(mut guard) = preview.result.lock() {
+                    preview.lines = guard.take();
+                }
+            }
+        }
+    }
+
+    /// The selected commit's preview, once it has loaded and the 500ms hover delay has passed
+    pub fn visible_diff_preview(&self) -> Option<&DiffPreview> {
+        if self.mode != AppMode::Normal || self.focused_pane != FocusedPane::CommitGraph || self.details_expanded {
+            return None;
+        }
+
+        let preview = self.diff_preview.as_ref()?;
+        if preview.started.elapsed() < Duration::from_millis(500) {
+            return None;
+        }
+
+        preview.lines.as_ref()?;
+        Some(preview)
+    }
+
     pub fn is_commit_selected(&self, commit_id: &str) -> bool {
         self.selected_commit_ids.contains(&commit_id.to_string())
     }
 }
+
+/// Split a `VALIDATION_LOG_FORMAT` line into its six fields, or give the field count found instead
+fn parse_validation_log_line(line: &str) -> Result<[&str; 6], usize> {
+    let fields: Vec<&str> = line.split('\0').collect();
+    fields.as_slice().try_into().map_err(|_| fields.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation_log_line_with_pipe_in_subject() {
+        let line = "0123abcd\x000123abc\x00fedc9876\x00Fix a | b parsing | again\x00Jane Doe\x001700000000";
+
+        let [hash, short_hash, parents, subject, author, timestamp] = parse_validation_log_line(line).unwrap();
+
+        assert_eq!(hash, "0123abcd");
+        assert_eq!(short_hash, "0123abc");
+        assert_eq!(parents, "fedc9876");
+        assert_eq!(subject, "Fix a | b parsing | again");
+        assert_eq!(author, "Jane Doe");
+        assert_eq!(timestamp, "1700000000");
+    }
+
+    #[test]
+    fn validation_log_line_with_missing_fields() {
+        assert_eq!(parse_validation_log_line("0123abcd\x00Fix a | b"), Err(2));
+    }
+}
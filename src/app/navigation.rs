@@ -1,9 +1,21 @@
-use super::App;
-use super::types::{AppMode, FocusedPane, FileStatus};
+use super::{App, Config};
+use super::types::{AppMode, FileDiffMode, FocusedPane, FileStatus};
 use std::time::Instant;
 
+pub(super) const MAX_DIFF_CONTEXT_LINES: usize = 20;
+
 impl App {
     pub fn move_selection_up(&mut self) {
+        if let Some(ref filtered) = self.filtered_graph_nodes {
+            // Step through the search matches only
+            let current = self.selected_commit_idx.unwrap_or(usize::MAX);
+            if let Some(&idx) = filtered.iter().rev().find(|&&idx| idx < current) {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+            }
+            return;
+        }
+
         if let Some(idx) = self.selected_commit_idx {
             if idx > 0 {
                 self.selected_commit_idx = Some(idx - 1);
@@ -16,6 +28,15 @@ impl App {
     }
 
     pub fn move_selection_down(&mut self) {
+        if let Some(ref filtered) = self.filtered_graph_nodes {
+            let next = filtered.iter().find(|&&idx| self.selected_commit_idx.is_none_or(|current| idx > current));
+            if let Some(&idx) = next {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+            }
+            return;
+        }
+
         if let Some(idx) = self.selected_commit_idx {
             if idx + 1 < self.graph_nodes.len() {
                 self.selected_commit_idx = Some(idx + 1);
@@ -27,38 +48,364 @@ impl App {
         }
     }
 
+    /// Move the graph selection by `delta` commits (search matches only while filtering), clamped to the ends
+    fn move_selection_by(&mut self, delta: isize) {
+        let target = match self.filtered_graph_nodes {
+            Some(ref filtered) => {
+                if filtered.is_empty() {
+                    return;
+                }
+                let pos = self.selected_commit_idx
+                    .map(|idx| filtered.binary_search(&idx).unwrap_or_else(|pos| pos))
+                    .unwrap_or(0);
+                let pos = pos.saturating_add_signed(delta).min(filtered.len() - 1);
+                filtered[pos]
+            }
+            None => {
+                if self.graph_nodes.is_empty() {
+                    return;
+                }
+                let idx = self.selected_commit_idx.unwrap_or(0);
+                idx.saturating_add_signed(delta).min(self.graph_nodes.len() - 1)
+            }
+        };
+
+        if Some(target) != self.selected_commit_idx {
+            self.selected_commit_idx = Some(target);
+            self.update_selection();
+        }
+    }
+
+    /// PageUp/PageDown: half a page of commits on the graph, a full page in the details and status panes
+    pub fn page_scroll(&mut self, down: bool) {
+        let sign = if down { 1 } else { -1 };
+
+        match self.focused_pane {
+            FocusedPane::CommitGraph => {
+                let step = (self.graph_viewport_height / 2).max(1) as isize;
+                self.move_selection_by(sign * step);
+            }
+            FocusedPane::CommitDetails => {
+                let step = self.details_viewport_height.max(1);
+                self.details_scroll_offset = if down {
+                    self.details_scroll_offset + step
+                } else {
+                    self.details_scroll_offset.saturating_sub(step)
+                };
+            }
+            FocusedPane::GitStatus => {
+                if self.git_status_files.is_empty() {
+                    return;
+                }
+                let step = self.status_viewport_height.max(1) as isize;
+                let idx = self.selected_file_idx.unwrap_or(0);
+                self.selected_file_idx = Some(idx.saturating_add_signed(sign * step).min(self.git_status_files.len() - 1));
+            }
+            FocusedPane::GitActions | FocusedPane::TagList => {}
+        }
+    }
+
+    /// Select the first loaded commit whose hash starts with `prefix`, asking git to resolve it otherwise
+    pub fn go_to_hash(&mut self, prefix: &str) {
+        let find = |app: &App, prefix: &str| app.graph_nodes.iter().position(|node| node.commit.id.starts_with(prefix));
+
+        let mut found = find(self, prefix);
+        if found.is_none() {
+            let resolved = std::process::Command::new("git")
+                .args(&["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", prefix)])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+            match resolved {
+                Some(id) => {
+                    found = find(self, &id);
+                    if found.is_none() {
+                        self.set_status_message(format!("✗ Commit {} is not loaded in the graph", &id[..7.min(id.len())]));
+                        return;
+                    }
+                }
+                None => {
+                    self.set_status_message(format!("✗ No commit matches '{}'", prefix));
+                    return;
+                }
+            }
+        }
+
+        if let Some(idx) = found {
+            self.selected_commit_idx = Some(idx);
+            self.scroll_offset = self.graph_row_of(idx);
+            self.focused_pane = FocusedPane::CommitGraph;
+            self.update_selection();
+            let short_id = self.graph_nodes[idx].commit.short_id.clone();
+            self.set_status_message(format!("✓ Jumped to {}", short_id));
+        }
+    }
+
+    /// Select the commit HEAD points at, wherever the graph is scrolled to
+    pub fn jump_to_head(&mut self) {
+        let head = std::process::Command::new("git")
+            .args(&["rev-parse", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+        let Some(head) = head else {
+            self.set_status_message("✗ Could not resolve HEAD".to_string());
+            return;
+        };
+
+        match self.graph_nodes.iter().position(|node| node.commit.id == head) {
+            Some(idx) => {
+                self.selected_commit_idx = Some(idx);
+                self.scroll_offset = self.graph_row_of(idx);
+                self.update_selection();
+                self.set_status_message("Jumped to HEAD".to_string());
+            }
+            None => self.set_status_message("✗ HEAD is not in the loaded graph".to_string()),
+        }
+    }
+
+    /// Load the selected commit's diff, holding back diffs over `large_diff_threshold` lines until confirmed
     pub fn load_current_diff(&mut self) {
+        self.pending_large_diff = None;
+
+        let Some(commit_id) = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .map(|node| node.commit.id.clone())
+        else {
+            return;
+        };
+
+        self.current_diff_stat = Self::diff_shortstat(&commit_id);
+        self.current_diff_files = Self::diff_name_status(&commit_id);
+        self.diff_file_offsets.clear();
+
+        if let Some(changed) = self.current_diff_stat.as_deref().map(Self::diff_line_count) {
+            if changed > self.config.large_diff_threshold {
+                self.current_diff = None;
+                self.details_scroll_offset = 0;
+                self.pending_large_diff = Some(changed);
+                self.set_status_message(format!("Diff is large ({} lines) – loading may be slow. Press Enter to load.", changed));
+                return;
+            }
+        }
+
+        self.force_load_current_diff();
+    }
+
+    /// Load the selected commit's diff regardless of its size
+    pub fn force_load_current_diff(&mut self) {
+        self.pending_large_diff = None;
+
         if let Some(idx) = self.selected_commit_idx {
             if let Some(node) = self.graph_nodes.get(idx) {
-                if let Some(ref repo) = self.git_repo {
-                    if let Ok(diff) = repo.get_commit_diff(&node.commit.id) {
+                if self.diff_word_mode {
+                    if let Some(diff) = self.word_diff(&node.commit.id) {
+                        self.diff_file_offsets = Self::diff_file_offsets(&diff);
+                        self.current_diff = Some(diff);
+                        self.details_scroll_offset = 0;
+                    }
+                } else if let Some(ref repo) = self.git_repo {
+                    if let Ok(diff) = repo.get_commit_diff(&node.commit.id, self.config.rename_detection_threshold, self.diff_context_lines as u32, self.diff_ignore_whitespace) {
+                        self.diff_file_offsets = Self::diff_file_offsets(&diff);
                         self.current_diff = Some(diff);
                         self.details_scroll_offset = 0;
                     }
                 }
+                // Reloading the same commit (context, word diff, whitespace) keeps the search going
+                if !self.diff_search_query.is_empty() {
+                    self.update_diff_search_results();
+                }
             }
         }
     }
 
+    /// The commit's diff with `[-removed-]{+added+}` markers instead of whole-line +/- (git2 has no word diff)
+    fn word_diff(&self, commit_id: &str) -> Option<String> {
+        use std::process::Command;
+
+        let context = format!("-U{}", self.diff_context_lines);
+        let renames = format!("--find-renames={}%", self.config.rename_detection_threshold);
+        let mut args = vec!["show", "-m", "--first-parent", "--format=", "--word-diff=plain", &context, &renames];
+        if self.diff_ignore_whitespace {
+            args.push("-w");
+        }
+        args.push(commit_id);
+
+        let output = Command::new("git")
+            .args(&args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())?;
+
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    /// Summary line of `git show --shortstat` against the first parent like the diff itself (works for root commits too), e.g.
+    /// "3 files changed, 120 insertions(+), 4 deletions(-)"
+    fn diff_shortstat(commit_id: &str) -> Option<String> {
+        use std::process::Command;
+
+        let output = Command::new("git")
+            .args(&["show", "-m", "--first-parent", "--shortstat", "--format=", commit_id])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())?;
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .rfind(|line| !line.is_empty())
+            .map(str::to_string)
+    }
+
+    /// (status letter, path) per changed file; renames and copies show as "old → new"
+    fn diff_name_status(commit_id: &str) -> Vec<(String, String)> {
+        use std::process::Command;
+
+        let Some(output) = Command::new("git")
+            .args(&["show", "-m", "--first-parent", "--name-status", "--format=", commit_id])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+        else {
+            return Vec::new();
+        };
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                // "R086" -> "R": the similarity score isn't worth the width
+                let status = fields.next()?.chars().next()?.to_string();
+                let paths: Vec<&str> = fields.collect();
+                let path = match paths.as_slice() {
+                    [path] => path.to_string(),
+                    [from, to] => format!("{} → {}", from, to),
+                    _ => return None,
+                };
+                Some((status, path))
+            })
+            .collect()
+    }
+
+    /// Line index of each file's "diff --git" header within `diff`, keyed by the new path
+    fn diff_file_offsets(diff: &str) -> Vec<(String, usize)> {
+        diff.lines()
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                let header = line.strip_prefix("diff --git ")?;
+                let path = header.rsplit_once(" b/").map(|(_, path)| path).unwrap_or(header);
+                Some((path.to_string(), idx))
+            })
+            .collect()
+    }
+
+    /// `[` / `]` in the details pane: highlight the previous/next changed file and scroll its diff into view
+    pub fn jump_to_diff_file(&mut self, forward: bool) {
+        let count = self.current_diff_files.len();
+        if count == 0 {
+            return;
+        }
+
+        let cursor = match (self.details_file_cursor, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(cursor), true) => (cursor + 1).min(count - 1),
+            (Some(cursor), false) => cursor.saturating_sub(1),
+        };
+        self.details_file_cursor = Some(cursor);
+        self.details_files_collapsed = false;
+
+        // A rename's list entry is "old → new"; the diff header is keyed by the new path
+        let path = &self.current_diff_files[cursor].1;
+        let path = path.rsplit_once(" → ").map(|(_, to)| to).unwrap_or(path);
+        match self.diff_file_offsets.iter().find(|(file, _)| file == path) {
+            Some((_, offset)) => self.details_scroll_offset = self.details_diff_start + offset,
+            None if self.current_diff.is_none() => {
+                self.set_status_message("Diff not loaded – press Enter on the commit graph to load it".to_string());
+            }
+            None => {}
+        }
+    }
+
+    /// `w` on commit details: switch between line and word diffs
+    pub fn toggle_word_diff(&mut self) {
+        self.diff_word_mode = !self.diff_word_mode;
+        if self.status_file_diff.is_some() {
+            self.reload_status_file_diff();
+        } else {
+            self.load_current_diff();
+        }
+    }
+
+    /// `W` on commit details: hide or show whitespace-only changes. Stays on across commit selections.
+    pub fn toggle_ignore_whitespace(&mut self) {
+        self.diff_ignore_whitespace = !self.diff_ignore_whitespace;
+        if self.status_file_diff.is_some() {
+            self.reload_status_file_diff();
+        } else {
+            self.load_current_diff();
+        }
+    }
+
+    /// `+`/`-` on commit details: show more or fewer unchanged lines around each hunk
+    pub fn change_diff_context(&mut self, wider: bool) {
+        let lines = if wider {
+            (self.diff_context_lines + 1).min(MAX_DIFF_CONTEXT_LINES)
+        } else {
+            self.diff_context_lines.saturating_sub(1)
+        };
+
+        if lines != self.diff_context_lines {
+            self.diff_context_lines = lines;
+            self.config.diff_context = lines;
+            if let Err(e) = Config::save_setting("diff_context", lines as i64) {
+                self.set_status_message(format!("✗ Error: {}", e));
+            }
+            if self.status_file_diff.is_some() {
+                self.reload_status_file_diff();
+            } else {
+                self.load_current_diff();
+            }
+        }
+    }
+
+    pub fn toggle_details_files(&mut self) {
+        self.details_files_collapsed = !self.details_files_collapsed;
+    }
+
+    /// Inserted plus deleted lines in a shortstat summary
+    fn diff_line_count(stat: &str) -> usize {
+        stat.split(',')
+            .filter(|part| part.contains("insertion") || part.contains("deletion"))
+            .filter_map(|part| part.split_whitespace().next()?.parse::<usize>().ok())
+            .sum()
+    }
+
     pub fn load_file_diff(&mut self) {
         use std::process::Command;
 
+        let context = format!("-U{}", self.diff_context_lines);
         if let Some(idx) = self.selected_file_idx {
             if let Some(file) = self.git_status_files.get(idx) {
                 let diff_output = match file.status {
                     FileStatus::Staged => {
                         Command::new("git")
-                            .args(&["diff", "--cached", "--", &file.path])
+                            .args(&["diff", &context, "--cached", "--", &file.path])
                             .output()
                     }
                     FileStatus::Modified | FileStatus::Deleted => {
                         Command::new("git")
-                            .args(&["diff", "--", &file.path])
+                            .args(&["diff", &context, "--", &file.path])
                             .output()
                     }
                     FileStatus::Untracked => {
                         Command::new("git")
-                            .args(&["diff", "--no-index", "/dev/null", &file.path])
+                            .args(&["diff", &context, "--no-index", "/dev/null", &file.path])
                             .output()
                     }
                 };
@@ -73,6 +420,58 @@ impl App {
         }
     }
 
+    /// `s`/`u` on commit details: show the selected git status file's staged or unstaged diff in the pane.
+    /// Pressing the key of the side already shown goes back to the commit.
+    pub fn toggle_status_file_diff(&mut self, mode: FileDiffMode) {
+        self.details_scroll_offset = 0;
+        if self.file_diff_mode == mode {
+            self.file_diff_mode = FileDiffMode::Commit;
+            self.status_file_diff = None;
+            return;
+        }
+
+        let Some(path) = self.selected_file_idx.and_then(|idx| self.git_status_files.get(idx)).map(|file| file.path.clone()) else {
+            self.set_status_message("✗ Select a file in git status first".to_string());
+            return;
+        };
+
+        self.file_diff_mode = mode;
+        self.status_file_diff = Some((path, String::new()));
+        self.reload_status_file_diff();
+    }
+
+    /// Re-read the shown staged/unstaged diff, e.g. after the file was (un)staged
+    pub(super) fn reload_status_file_diff(&mut self) {
+        use std::process::Command;
+
+        let Some((path, _)) = &self.status_file_diff else {
+            return;
+        };
+
+        let untracked = self.git_status_files.iter().any(|file| &file.path == path && file.status == FileStatus::Untracked);
+        let context = format!("-U{}", self.diff_context_lines);
+        let mut args = vec!["diff", context.as_str()];
+        if self.diff_word_mode {
+            args.push("--word-diff=plain");
+        }
+        if self.diff_ignore_whitespace {
+            args.push("-w");
+        }
+        match self.file_diff_mode {
+            FileDiffMode::Commit => return,
+            FileDiffMode::Staged => args.extend(["--cached", "--", path]),
+            // Untracked files have no index entry to diff against
+            FileDiffMode::Unstaged if untracked => args.extend(["--no-index", "/dev/null", path]),
+            FileDiffMode::Unstaged => args.extend(["--", path]),
+        }
+
+        let output = Command::new("git").args(&args).output();
+        let diff = output.map(|output| String::from_utf8_lossy(&output.stdout).to_string()).unwrap_or_default();
+        if let Some((_, shown)) = &mut self.status_file_diff {
+            *shown = diff;
+        }
+    }
+
     pub fn open_file_diff_view(&mut self) {
         if self.selected_file_idx.is_some() {
             self.load_file_diff();
@@ -83,7 +482,12 @@ impl App {
     }
 
     pub fn close_file_diff_view(&mut self) {
-        self.mode = AppMode::Normal;
+        // A file opened from the changed-files tree goes back to the tree
+        self.mode = if self.commit_tree_commit_id.is_some() {
+            AppMode::CommitTree
+        } else {
+            AppMode::Normal
+        };
         self.details_scroll_offset = 0;
         self.details_horizontal_offset = 0;
     }
@@ -94,12 +498,100 @@ impl App {
                 self.graph.trace_ancestry(&node.commit.id);
             }
         }
+        self.details_parent_cursor = None;
+        self.details_file_cursor = None;
+        // A new commit selection brings the details pane back to commits
+        self.file_diff_mode = FileDiffMode::Commit;
+        self.status_file_diff = None;
+        // Matches are line numbers in the previous commit's diff
+        self.diff_search_query.clear();
+        self.diff_search_results.clear();
         self.load_current_diff();
+        self.load_branch_distances();
+        self.start_diff_preview();
+    }
+
+    /// Move the highlight in the details pane's "Parents:" line to the next parent of a merge
+    pub fn cycle_details_parent(&mut self) {
+        let parent_count = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .map(|node| node.commit.parents.len())
+            .unwrap_or(0);
+
+        if parent_count < 2 {
+            return;
+        }
+
+        self.details_parent_cursor = Some(match self.details_parent_cursor {
+            Some(cursor) => (cursor + 1) % parent_count,
+            None => 0,
+        });
+    }
+
+    /// Select the parent highlighted in the details pane. Returns false when no parent is highlighted
+    pub fn goto_details_parent(&mut self) -> bool {
+        let parent_id = match (self.details_parent_cursor, self.selected_commit_idx) {
+            (Some(cursor), Some(idx)) => self.graph_nodes.get(idx)
+                .and_then(|node| node.commit.parents.get(cursor))
+                .cloned(),
+            _ => None,
+        };
+
+        let Some(parent_id) = parent_id else {
+            return false;
+        };
+
+        match self.graph_nodes.iter().position(|node| node.commit.id == parent_id) {
+            Some(parent_idx) => {
+                self.selected_commit_idx = Some(parent_idx);
+                self.update_selection();
+                self.focused_pane = FocusedPane::CommitGraph;
+            }
+            None => {
+                self.set_status_message(format!("✗ Parent {} is not loaded in the graph", &parent_id[..7]));
+            }
+        }
+        true
+    }
+
+    /// Screen rows each commit takes in the graph: node row plus edge row, or just the node row when compact
+    pub fn graph_rows_per_commit(&self) -> usize {
+        if self.config.compact_graph { 1 } else { 2 }
+    }
+
+    pub fn toggle_compact_graph(&mut self) {
+        // Keep roughly the same commits on screen across the switch
+        let top_commit = self.scroll_offset / self.graph_rows_per_commit();
+        self.config.compact_graph = !self.config.compact_graph;
+        self.scroll_offset = top_commit * self.graph_rows_per_commit();
+
+        if self.config.compact_graph {
+            self.set_status_message("✓ Compact graph on".to_string());
+        } else {
+            self.set_status_message("✓ Compact graph off".to_string());
+        }
+    }
+
+    /// Row of commit `idx` in the graph pane, accounting for compact mode, date separators and an active search filter
+    pub fn graph_row_of(&self, idx: usize) -> usize {
+        match self.filtered_graph_nodes {
+            Some(ref filtered) => filtered.binary_search(&idx).unwrap_or_else(|pos| pos),
+            None => {
+                let separators_above = self.graph_separators.partition_point(|(node_idx, _)| *node_idx <= idx);
+                idx * self.graph_rows_per_commit() + separators_above
+            }
+        }
+    }
+
+    pub fn cycle_date_grouping(&mut self) {
+        self.config.date_grouping = self.config.date_grouping.next();
+        self.assign_date_separators();
+        self.set_status_message(format!("✓ Date separators: {}", self.config.date_grouping.label()));
     }
 
     pub fn adjust_scroll(&mut self, viewport_height: usize) {
         if let Some(selected_idx) = self.selected_commit_idx {
-            let selected_row = selected_idx * 2;
+            let selected_row = self.graph_row_of(selected_idx);
 
             if selected_row < self.scroll_offset {
                 self.scroll_offset = selected_row;
@@ -142,12 +634,33 @@ impl App {
     }
 
     pub fn next_pane(&mut self) {
-        self.focused_pane = match self.focused_pane {
-            FocusedPane::CommitGraph => FocusedPane::GitActions,
-            FocusedPane::GitActions => FocusedPane::CommitDetails,
-            FocusedPane::CommitDetails => FocusedPane::GitStatus,
-            FocusedPane::GitStatus => FocusedPane::CommitGraph,
+        // Tab follows the on-screen slot order so reordered panes are still visited top to bottom;
+        // the tag list shares the left-bottom slot and comes right after it
+        let mut order = self.config.pane_order.to_vec();
+        order.insert(2, FocusedPane::TagList);
+        let slot = order.iter().position(|&p| p == self.focused_pane).unwrap_or(0);
+        self.focused_pane = order[(slot + 1) % order.len()];
+    }
+
+    /// Swap the focused pane with its neighbour in the layout (`delta` is -1 for left, 1 for right)
+    pub fn move_focused_pane(&mut self, delta: isize) {
+        let order = &mut self.config.pane_order;
+        let Some(slot) = order.iter().position(|&p| p == self.focused_pane) else {
+            // Only the tag list is outside `pane_order`; it always shares the left-bottom slot
+            self.set_status_message("The tag list can't be moved".to_string());
+            return;
         };
+
+        let target = slot as isize + delta;
+        if target < 0 || target as usize >= order.len() {
+            return;
+        }
+
+        order.swap(slot, target as usize);
+        let names: toml_edit::Array = order.iter().map(|pane| pane.config_name()).collect();
+        if let Err(e) = Config::save_setting("pane_order", names) {
+            self.set_status_message(format!("✗ Error: {}", e));
+        }
     }
 
     pub fn details_scroll_up(&mut self) {
@@ -0,0 +1,112 @@
+use super::App;
+use super::types::{AppMode, FocusedPane, QuickLogEntry};
+use std::process::Command;
+
+/// Number of commits listed in the quick log popup
+const QUICK_LOG_LIMIT: usize = 50;
+
+/// Char positions in `text` matching `query` as a case-insensitive subsequence, or `None` if it doesn't match
+pub fn fuzzy_match(query: &str, text: &str) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut query_chars = query.chars().flat_map(|c| c.to_lowercase()).peekable();
+
+    for (idx, c) in text.chars().enumerate() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(std::iter::once(wanted)) {
+            positions.push(idx);
+            query_chars.next();
+        }
+    }
+
+    query_chars.peek().is_none().then_some(positions)
+}
+
+impl App {
+    pub(super) fn cmd_quick_log(&mut self) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["log", &format!("-{}", QUICK_LOG_LIMIT), "--format=%H%x00%h%x00%s"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        self.quick_log_entries = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\0');
+                Some(QuickLogEntry {
+                    id: fields.next()?.to_string(),
+                    short_id: fields.next()?.to_string(),
+                    subject: fields.next().unwrap_or("").to_string(),
+                })
+            })
+            .collect();
+        self.quick_log_query.clear();
+        self.quick_log_selected = 0;
+        self.mode = AppMode::QuickLog;
+        Ok("Type to filter, Enter to jump".to_string())
+    }
+
+    /// Entries matching the current filter, with the matched subject char positions for highlighting
+    pub fn quick_log_matches(&self) -> Vec<(&QuickLogEntry, Vec<usize>)> {
+        self.quick_log_entries.iter()
+            .filter_map(|entry| fuzzy_match(&self.quick_log_query, &entry.subject).map(|positions| (entry, positions)))
+            .collect()
+    }
+
+    pub fn quick_log_input_char(&mut self, c: char) {
+        self.quick_log_query.push(c);
+        self.quick_log_selected = 0;
+    }
+
+    pub fn quick_log_backspace(&mut self) {
+        self.quick_log_query.pop();
+        self.quick_log_selected = 0;
+    }
+
+    pub fn quick_log_up(&mut self) {
+        if self.quick_log_selected > 0 {
+            self.quick_log_selected -= 1;
+        }
+    }
+
+    pub fn quick_log_down(&mut self) {
+        if self.quick_log_selected + 1 < self.quick_log_matches().len() {
+            self.quick_log_selected += 1;
+        }
+    }
+
+    pub fn close_quick_log(&mut self) {
+        self.quick_log_entries.clear();
+        self.quick_log_query.clear();
+        self.quick_log_selected = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Select the highlighted commit in the main graph
+    pub fn quick_log_jump(&mut self) {
+        let Some(commit_id) = self.quick_log_matches()
+            .get(self.quick_log_selected)
+            .map(|(entry, _)| entry.id.clone())
+        else {
+            return;
+        };
+
+        self.close_quick_log();
+
+        match self.graph_nodes.iter().position(|node| node.commit.id == commit_id) {
+            Some(idx) => {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+                self.focused_pane = FocusedPane::CommitGraph;
+            }
+            None => {
+                self.set_status_message(format!("✗ Commit {} is not loaded in the graph", &commit_id[..7]));
+            }
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use super::App;
+use super::conflict::{has_unmerged_files, stopped_on_conflict};
+use super::types::{AppMode, ConflictOperation, FocusedPane, GitCommand, RebaseAction, RebaseTodoEntry};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+/// Parse a rebase todo list, skipping comments, blank lines and non-commit commands (exec, label, ...)
+pub fn parse_rebase_todo(text: &str) -> Vec<RebaseTodoEntry> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let action = RebaseAction::from_keyword(fields.next()?)?;
+            Some(RebaseTodoEntry {
+                action,
+                hash: fields.next()?.to_string(),
+                message: fields.next().unwrap_or("").to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Commits in `base..HEAD`, oldest first, all as `pick` - the list git itself would start from
+pub(super) fn load_rebase_todo(base: &str) -> Result<Vec<RebaseTodoEntry>, String> {
+    let output = Command::new("git")
+        .args(&["log", "--reverse", "--format=pick %H %s", &format!("{}..HEAD", base)])
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    Ok(parse_rebase_todo(&String::from_utf8_lossy(&output.stdout)))
+}
+
+pub(super) fn write_rebase_todo(entries: &[RebaseTodoEntry]) -> Result<PathBuf, String> {
+    let path = std::env::temp_dir().join(format!("git_manager_rebase_todo_{}.txt", std::process::id()));
+    let text: String = entries.iter().map(|entry| entry.to_todo_line() + "\n").collect();
+    std::fs::write(&path, text).map_err(|e| format!("Failed to write rebase todo: {}", e))?;
+    Ok(path)
+}
+
+/// `git rebase -i <base>` whose sequence editor replaces git's generated list with `todo_path`
+pub(super) fn rebase_command(base: &str, todo_path: &Path) -> Command {
+    let mut command = Command::new("git");
+    command.args(&["rebase", "-i", base])
+        .env("GIT_SEQUENCE_EDITOR", format!("cp '{}'", todo_path.display()));
+    command
+}
+
+/// How far back the current branch is searched for `fixup!`/`squash!` commits and their targets
+const AUTOSQUASH_SCAN_LIMIT: usize = 500;
+
+/// The subject a `fixup!`, `squash!` or `amend!` commit refers to, with nested prefixes stripped
+fn autosquash_target(subject: &str) -> Option<&str> {
+    let mut target = None;
+    let mut rest = subject;
+    while let Some(stripped) = ["fixup! ", "squash! ", "amend! "].iter().find_map(|prefix| rest.strip_prefix(prefix)) {
+        rest = stripped;
+        target = Some(rest);
+    }
+    target
+}
+
+/// Where an autosquash rebase has to start: the parent of the oldest commit any fixup/squash on the
+/// current branch refers to (`None` when that is the root commit), plus how many fixups there are
+fn find_autosquash_base() -> Result<(Option<String>, usize), String> {
+    let output = Command::new("git")
+        .args(&["log", "--first-parent", &format!("--max-count={}", AUTOSQUASH_SCAN_LIMIT), "--format=%H%x1f%P%x1f%s", "HEAD"])
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let commits: Vec<(&str, Option<&str>, &str)> = stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\x1f');
+            Some((fields.next()?, fields.next()?.split_whitespace().next(), fields.next()?))
+        })
+        .collect();
+
+    let mut fixup_count = 0;
+    let mut oldest_target = None;
+
+    // Newest first, so each fixup's target can only be further down the list
+    for (idx, &(_, _, subject)) in commits.iter().enumerate() {
+        let Some(target) = autosquash_target(subject) else {
+            continue;
+        };
+        fixup_count += 1;
+
+        // Like git: an exact subject or hash prefix wins, a subject prefix is the fallback
+        let older = commits.iter().enumerate().skip(idx + 1);
+        let exact = older.clone().find(|(_, &(hash, _, subject))| {
+            subject == target || (target.len() >= 4 && target.chars().all(|c| c.is_ascii_hexdigit()) && hash.starts_with(target))
+        });
+        let Some((target_idx, _)) = exact.or_else(|| older.clone().find(|(_, &(_, _, subject))| subject.starts_with(target))) else {
+            return Err(format!("Could not find the commit for '{}' in the last {} commits", subject, AUTOSQUASH_SCAN_LIMIT));
+        };
+
+        oldest_target = oldest_target.max(Some(target_idx));
+    }
+
+    if fixup_count == 0 {
+        return Err("No fixup! or squash! commits on the current branch".to_string());
+    }
+
+    let base = oldest_target.and_then(|idx| commits[idx].1).map(str::to_string);
+    Ok((base, fixup_count))
+}
+
+fn rebase_in_progress() -> bool {
+    Command::new("git")
+        .args(&["rev-parse", "--git-path", "rebase-merge"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| Path::new(String::from_utf8_lossy(&output.stdout).trim()).exists())
+}
+
+impl App {
+    /// Open the todo editor for the selected commit and everything after it on the current branch
+    pub(super) fn cmd_interactive_rebase(&mut self, commit_id: &str) -> Result<String, String> {
+        let ancestor = Command::new("git")
+            .args(&["merge-base", "--is-ancestor", commit_id, "HEAD"])
+            .output()
+            .map_err(|e| format!("Failed to check commit ancestry: {}", e))?;
+
+        if !ancestor.status.success() {
+            let current_branch = self.current_branch.as_deref().unwrap_or("detached HEAD");
+            return Err(format!(
+                "Cannot rebase: commit {} is not on the current branch ({})",
+                &commit_id[..7],
+                current_branch
+            ));
+        }
+
+        let parent_output = Command::new("git")
+            .args(&["rev-parse", &format!("{}^", commit_id)])
+            .output()
+            .map_err(|e| format!("Failed to get parent: {}", e))?;
+
+        if !parent_output.status.success() {
+            return Err("Cannot rebase from the initial commit".to_string());
+        }
+
+        let base = String::from_utf8_lossy(&parent_output.stdout).trim().to_string();
+
+        // The todo list is linear; merges in the range would be flattened away
+        if let Some(merge) = self.find_merge_in_range(&[format!("{}..HEAD", base)]) {
+            return Err(format!("Cannot rebase across a merge commit ({}). Use interactive rebase manually.", merge));
+        }
+
+        self.rebase_todo_entries = load_rebase_todo(&base)?;
+        self.selected_rebase_todo_idx = 0;
+        self.rebase_todo_base = Some(base);
+        self.mode = AppMode::RebaseTodo;
+        Ok(format!("Rebasing {} commit(s) - Space to change action, Enter to start", self.rebase_todo_entries.len()))
+    }
+
+    pub fn rebase_todo_up(&mut self) {
+        if self.selected_rebase_todo_idx > 0 {
+            self.selected_rebase_todo_idx -= 1;
+        }
+    }
+
+    pub fn rebase_todo_down(&mut self) {
+        if self.selected_rebase_todo_idx + 1 < self.rebase_todo_entries.len() {
+            self.selected_rebase_todo_idx += 1;
+        }
+    }
+
+    pub fn cycle_rebase_todo_action(&mut self) {
+        if let Some(entry) = self.rebase_todo_entries.get_mut(self.selected_rebase_todo_idx) {
+            entry.action = entry.action.next();
+        }
+    }
+
+    pub fn cancel_rebase_todo(&mut self) {
+        self.rebase_todo_entries.clear();
+        self.selected_rebase_todo_idx = 0;
+        self.rebase_todo_base = None;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Enter: run the rebase with the edited list. git gets the terminal so reword and squash can open the editor.
+    pub fn submit_rebase_todo(&mut self) {
+        // squash/fixup fold into the commit before them, so the first kept commit can't use them
+        let first_kept = self.rebase_todo_entries.iter().find(|entry| entry.action != RebaseAction::Drop);
+        if let Some(entry) = first_kept.filter(|entry| matches!(entry.action, RebaseAction::Squash | RebaseAction::Fixup)) {
+            self.set_status_message(format!("✗ Cannot {} the first commit - there is nothing before it to fold into", entry.action.keyword()));
+            return;
+        }
+
+        let Some(base) = self.rebase_todo_base.clone() else {
+            self.cancel_rebase_todo();
+            return;
+        };
+        let entries = std::mem::take(&mut self.rebase_todo_entries);
+        self.cancel_rebase_todo();
+
+        let todo_path = match write_rebase_todo(&entries) {
+            Ok(path) => path,
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: {}", e));
+                return;
+            }
+        };
+
+        let status = self.suspend_for_subprocess(&mut rebase_command(&base, &todo_path));
+        let _ = std::fs::remove_file(&todo_path);
+        let _ = self.init();
+
+        match self.rebase_outcome(status, format!("Rebased {} commit(s)", entries.len())) {
+            Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+            Err(e) => self.set_status_message(format!("✗ {}", e)),
+        }
+    }
+
+    /// How a rebase that was handed the terminal ended: finished, stopped on conflicts, or paused for editing
+    fn rebase_outcome(&mut self, status: Result<std::io::Result<ExitStatus>, String>, done: String) -> Result<String, String> {
+        match status {
+            Ok(Ok(status)) if status.success() && !rebase_in_progress() => Ok(done),
+            Ok(Ok(_)) if has_unmerged_files() => self.enter_conflict_resolution(ConflictOperation::Rebase),
+            Ok(Ok(_)) if rebase_in_progress() => Ok("Rebase stopped for editing - amend, then run 'git rebase --continue'".to_string()),
+            Ok(Ok(status)) => Err(format!("Error: git rebase exited with {}", status)),
+            Ok(Err(e)) => Err(format!("Error: Failed to execute git: {}", e)),
+            Err(e) => Err(format!("Error: {}", e)),
+        }
+    }
+
+    /// Start `rebase --onto`: pick the new base in the graph first, then the upstream
+    pub(super) fn cmd_rebase_onto(&mut self) -> Result<String, String> {
+        if self.graph_nodes.is_empty() {
+            return Err("No commits loaded".to_string());
+        }
+
+        self.rebase_onto_new_base = None;
+        self.rebase_onto_upstream = None;
+        self.focused_pane = FocusedPane::CommitGraph;
+        self.mode = AppMode::RebaseOntoNewBase;
+        Ok("Select the new base commit and press Enter".to_string())
+    }
+
+    /// Enter while picking: take the highlighted commit for the current step
+    pub fn rebase_onto_pick(&mut self) {
+        let Some(commit_id) = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .map(|node| node.commit.id.clone()) else {
+            return;
+        };
+
+        match self.mode {
+            AppMode::RebaseOntoNewBase => {
+                self.rebase_onto_new_base = Some(commit_id);
+                self.mode = AppMode::RebaseOntoUpstream;
+            }
+            AppMode::RebaseOntoUpstream => {
+                let Some(new_base) = self.rebase_onto_new_base.clone() else {
+                    self.cancel_rebase_onto();
+                    return;
+                };
+                // A bad pick keeps the user on this step so they can choose again
+                match self.rebase_onto_confirmation_message(&new_base, &commit_id) {
+                    Ok(message) => {
+                        self.rebase_onto_upstream = Some(commit_id);
+                        self.pending_command = Some(GitCommand::RebaseOnto);
+                        self.pending_command_message = self.with_rebase_upstream_warning(message);
+                        self.mode = AppMode::Confirm;
+                    }
+                    Err(e) => self.set_status_message(format!("✗ {}", e)),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn cancel_rebase_onto(&mut self) {
+        self.rebase_onto_new_base = None;
+        self.rebase_onto_upstream = None;
+        self.mode = AppMode::Normal;
+    }
+
+    /// "branch (abc1234)" or "commit abc1234", as in the other rebase/merge confirmations
+    fn describe_commit(&self, commit_id: &str) -> String {
+        match self.get_branch_name_for_commit(commit_id) {
+            Some(branch) => format!("{} ({})", branch, &commit_id[..7]),
+            None => format!("commit {}", &commit_id[..7]),
+        }
+    }
+
+    /// List the commits in `upstream..HEAD` that will be replayed and where they land
+    fn rebase_onto_confirmation_message(&self, new_base: &str, upstream: &str) -> Result<String, String> {
+        const MAX_LISTED: usize = 10;
+
+        let output = Command::new("git")
+            .args(&["log", "--reverse", "--no-merges", "--format=%h %s", &format!("{}..HEAD", upstream)])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let replayed: Vec<&str> = stdout.lines().collect();
+        if replayed.is_empty() {
+            return Err(format!("Nothing to replay: HEAD has no commits after {}", &upstream[..7]));
+        }
+
+        let current_branch = self.current_branch.as_deref().unwrap_or("detached HEAD");
+        let base_desc = self.describe_commit(new_base);
+
+        let mut message = format!(
+            "Rebase {} onto {}?\n\nReplays {} commit(s) after {}:\n",
+            current_branch,
+            base_desc,
+            replayed.len(),
+            self.describe_commit(upstream)
+        );
+        for line in replayed.iter().take(MAX_LISTED) {
+            message.push_str(&format!("  {}\n", line));
+        }
+        if replayed.len() > MAX_LISTED {
+            message.push_str(&format!("  … and {} more\n", replayed.len() - MAX_LISTED));
+        }
+        message.push_str(&format!("\nThey will land on top of {}, and {} will point at the result.", base_desc, current_branch));
+
+        if self.find_merge_in_range(&[format!("{}..HEAD", upstream)]).is_some() {
+            message.push_str("\n\n⚠ Merge commits in the range will be dropped (the history is linearized).");
+        }
+
+        Ok(message)
+    }
+
+    /// `git rebase --onto <new base> <upstream>` on the current branch
+    pub(super) fn execute_rebase_onto(&mut self) -> Result<String, String> {
+        let (Some(new_base), Some(upstream)) = (self.rebase_onto_new_base.take(), self.rebase_onto_upstream.take()) else {
+            return Err("No rebase --onto selection".to_string());
+        };
+
+        let output = Command::new("git")
+            .args(&["rebase", "--onto", &new_base, &upstream])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok(format!("Rebased commits after {} onto {}", &upstream[..7], &new_base[..7]))
+        } else if stopped_on_conflict(&output) {
+            self.enter_conflict_resolution(ConflictOperation::Rebase)
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+
+    /// Confirmation for RebaseAutosquash: how many fixups fold in and how far back the rebase reaches
+    pub(super) fn autosquash_confirmation_message(&self) -> Option<String> {
+        let (base, fixup_count) = find_autosquash_base().ok()?;
+        let current_branch = self.current_branch.as_deref().unwrap_or("detached HEAD");
+        let start = match base {
+            Some(ref base) => format!("after {}", self.describe_commit(base)),
+            None => "from the root commit".to_string(),
+        };
+
+        self.with_rebase_upstream_warning(format!(
+            "Autosquash {} fixup/squash commit(s) on {}?\n\nThis rebases {} {} and folds each fixup into the commit it refers to.\nsquash! commits open the editor to combine their messages.",
+            fixup_count, current_branch, current_branch, start
+        ))
+    }
+
+    /// `git rebase -i --autosquash` from just before the oldest fixup target, accepting git's reordered todo list as is
+    pub(super) fn cmd_rebase_autosquash(&mut self) -> Result<String, String> {
+        let (base, fixup_count) = find_autosquash_base()?;
+
+        if let Some(ref base) = base {
+            if let Some(merge) = self.find_merge_in_range(&[format!("{}..HEAD", base)]) {
+                return Err(format!("Cannot autosquash across a merge commit ({}). Rebase manually.", merge));
+            }
+        }
+
+        let mut command = Command::new("git");
+        command.args(&["rebase", "-i", "--autosquash"])
+            .arg(base.as_deref().unwrap_or("--root"))
+            .env("GIT_SEQUENCE_EDITOR", "true");
+
+        // squash! commits open the message editor, so git gets the terminal
+        let status = self.suspend_for_subprocess(&mut command);
+        let _ = self.init();
+
+        self.rebase_outcome(status, format!("Autosquashed {} fixup commit(s)", fixup_count))
+    }
+}
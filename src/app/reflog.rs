@@ -0,0 +1,146 @@
+use super::App;
+use super::types::{AppMode, FocusedPane, ReflogEntry};
+use std::collections::HashSet;
+use std::process::Command;
+
+/// Of `hashes`, those no branch, remote-tracking branch or tag reaches
+fn unreachable_from_refs(hashes: &[String]) -> Result<HashSet<String>, String> {
+    let output = Command::new("git")
+        .arg("rev-list")
+        .args(hashes)
+        .args(&["--not", "--branches", "--tags", "--remotes"])
+        .output()
+        .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).lines().map(str::to_string).collect())
+}
+
+impl App {
+    /// `l`: list `git reflog` full screen
+    pub fn open_reflog(&mut self) {
+        self.reflog_orphans_only = false;
+        if self.load_reflog() {
+            self.mode = AppMode::Reflog;
+        }
+    }
+
+    /// The reflog narrowed to commits no ref reaches any more (lost to a reset, rebase or
+    /// branch deletion), one entry per commit, newest first
+    pub(super) fn cmd_recover_orphaned_commit(&mut self) -> Result<String, String> {
+        if !self.load_reflog() {
+            return Err("Could not read the reflog".to_string());
+        }
+
+        let mut seen = HashSet::new();
+        self.reflog_entries.retain(|entry| seen.insert(entry.hash.clone()));
+        let hashes: Vec<String> = self.reflog_entries.iter().map(|entry| entry.hash.clone()).collect();
+        let orphans = if hashes.is_empty() { HashSet::new() } else { unreachable_from_refs(&hashes)? };
+        self.reflog_entries.retain(|entry| orphans.contains(&entry.hash));
+
+        if self.reflog_entries.is_empty() {
+            return Ok("No orphaned commits in the reflog".to_string());
+        }
+        self.reflog_orphans_only = true;
+        self.mode = AppMode::Reflog;
+        Ok(format!("{} orphaned commits - press c to create a branch at one", self.reflog_entries.len()))
+    }
+
+    /// Fill `reflog_entries` from `git reflog`; false (with the error in the status bar) when git fails
+    fn load_reflog(&mut self) -> bool {
+        let output = Command::new("git")
+            .args(&["reflog", "--format=%H %gd %gs"])
+            .output();
+
+        let output = match output {
+            Ok(output) if output.status.success() => output,
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+                return false;
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+                return false;
+            }
+        };
+
+        // The hash and selector never contain spaces, the subject may
+        self.reflog_entries = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, ' ');
+                Some(ReflogEntry {
+                    hash: fields.next()?.to_string(),
+                    refname: fields.next()?.to_string(),
+                    description: fields.next().unwrap_or("").to_string(),
+                })
+            })
+            .collect();
+        self.selected_reflog_idx = 0;
+        true
+    }
+
+    pub fn reflog_up(&mut self) {
+        if self.selected_reflog_idx > 0 {
+            self.selected_reflog_idx -= 1;
+        }
+    }
+
+    pub fn reflog_down(&mut self) {
+        if self.selected_reflog_idx + 1 < self.reflog_entries.len() {
+            self.selected_reflog_idx += 1;
+        }
+    }
+
+    pub fn close_reflog(&mut self) {
+        self.reflog_entries.clear();
+        self.selected_reflog_idx = 0;
+        self.reflog_orphans_only = false;
+        self.mode = AppMode::Normal;
+    }
+
+    /// `c` in the reflog: keep the highlighted commit with a new `recovered-YYYYMMDD-<short hash>` branch
+    pub fn reflog_create_branch(&mut self) {
+        let Some(hash) = self.reflog_entries.get(self.selected_reflog_idx).map(|entry| entry.hash.clone()) else {
+            return;
+        };
+
+        let short = hash.get(..7).unwrap_or(&hash);
+        let name = format!("recovered-{}-{}", chrono::Local::now().format("%Y%m%d"), short);
+        match Command::new("git").args(&["branch", &name, &hash]).output() {
+            Ok(output) if output.status.success() => {
+                self.close_reflog();
+                let _ = self.init();
+                if let Some(idx) = self.graph_nodes.iter().position(|node| node.commit.id == hash) {
+                    self.selected_commit_idx = Some(idx);
+                    self.update_selection();
+                    self.focused_pane = FocusedPane::CommitGraph;
+                }
+                self.set_status_message(format!("✓ Created branch '{}' at {}", name, short));
+            }
+            Ok(output) => self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr).trim())),
+            Err(e) => self.set_status_message(format!("✗ Error: Failed to execute git: {}", e)),
+        }
+    }
+
+    /// Select the highlighted entry's commit in the main graph
+    pub fn reflog_jump(&mut self) {
+        let Some(hash) = self.reflog_entries.get(self.selected_reflog_idx).map(|entry| entry.hash.clone()) else {
+            return;
+        };
+
+        match self.graph_nodes.iter().position(|node| node.commit.id == hash) {
+            Some(idx) => {
+                self.close_reflog();
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+                self.focused_pane = FocusedPane::CommitGraph;
+            }
+            None => {
+                self.set_status_message("✗ Commit not loaded – scroll to the end of the graph or use Load More to load older history".to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use super::App;
+use super::types::{AppMode, GitCommand};
+use std::process::Command;
+
+fn remote_url(name: &str) -> Option<String> {
+    Command::new("git")
+        .args(&["remote", "get-url", name])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+impl App {
+    /// Names from `git remote`, for the remove/rename dialogs
+    pub(super) fn load_remotes(&mut self) {
+        self.available_remotes = Command::new("git")
+            .arg("remote")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).lines().map(str::to_string).collect())
+            .unwrap_or_default();
+    }
+
+    pub(super) fn cmd_add_remote(&mut self) -> Result<String, String> {
+        // The first remote is conventionally "origin"; a fork's parent is usually "upstream"
+        self.remote_name_input = if self.available_remotes.is_empty() { "origin" } else { "upstream" }.to_string();
+        self.remote_url_input.clear();
+        self.remote_editing_second = true;
+        self.mode = AppMode::AddRemote;
+        Ok("Enter remote name and URL...".to_string())
+    }
+
+    pub(super) fn cmd_remove_remote(&mut self) -> Result<String, String> {
+        self.load_remotes();
+        if self.available_remotes.is_empty() {
+            return Err("No remotes configured".to_string());
+        }
+
+        self.selected_remote_idx = 0;
+        self.mode = AppMode::SelectRemoteToRemove;
+        Ok("Select remote to remove...".to_string())
+    }
+
+    pub(super) fn cmd_rename_remote(&mut self) -> Result<String, String> {
+        self.load_remotes();
+        let remote = self.available_remotes.iter()
+            .find(|name| *name == "origin")
+            .or_else(|| self.available_remotes.first())
+            .cloned()
+            .ok_or("No remotes configured")?;
+
+        self.remote_name_input = remote;
+        self.remote_new_name_input.clear();
+        self.remote_editing_second = true;
+        self.mode = AppMode::RenameRemote;
+        Ok("Enter the new remote name...".to_string())
+    }
+
+    /// `git remote prune` for every remote, counting the `* [pruned] ...` lines
+    pub(super) fn cmd_prune_remote(&mut self) -> Result<String, String> {
+        self.load_remotes();
+        if self.available_remotes.is_empty() {
+            return Err("No remotes configured".to_string());
+        }
+
+        let mut pruned = 0;
+        for remote in self.available_remotes.clone() {
+            let output = Command::new("git")
+                .args(&["remote", "prune", &remote])
+                .output()
+                .map_err(|e| format!("Failed to execute git: {}", e))?;
+            if !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+            }
+            pruned += String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter(|line| line.trim_start().starts_with("* [pruned]"))
+                .count();
+        }
+
+        if pruned == 0 {
+            return Ok("No stale remote-tracking refs to prune".to_string());
+        }
+        let _ = self.init();
+        Ok(format!("Pruned {} stale remote-tracking ref{}", pruned, if pruned == 1 { "" } else { "s" }))
+    }
+
+    pub fn remote_input_char(&mut self, c: char) {
+        if !c.is_whitespace() {
+            self.input_insert(c);
+        }
+    }
+
+    pub fn remote_input_backspace(&mut self) {
+        self.input_backspace();
+    }
+
+    pub fn remote_switch_field(&mut self) {
+        self.remote_editing_second = !self.remote_editing_second;
+        self.input_cursor = 0;
+    }
+
+    pub fn cancel_remote_dialog(&mut self) {
+        self.remote_name_input.clear();
+        self.remote_url_input.clear();
+        self.remote_new_name_input.clear();
+        self.mode = AppMode::Normal;
+    }
+
+    pub fn submit_add_remote(&mut self) {
+        let name = self.remote_name_input.trim().to_string();
+        let url = self.remote_url_input.trim().to_string();
+
+        if name.is_empty() || url.is_empty() {
+            self.set_status_message("✗ Remote name and URL are both required".to_string());
+            return;
+        }
+
+        self.cancel_remote_dialog();
+
+        match Command::new("git").args(&["remote", "add", &name, &url]).output() {
+            Ok(output) if output.status.success() => {
+                self.load_remotes();
+                self.load_git_remote_host();
+                self.set_status_message(format!("✓ Added remote '{}' ({}) - fetch to load its branches", name, url));
+            }
+            Ok(output) => self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr).trim())),
+            Err(e) => self.set_status_message(format!("✗ Error: Failed to execute git: {}", e)),
+        }
+    }
+
+    pub fn submit_rename_remote(&mut self) {
+        let old_name = self.remote_name_input.trim().to_string();
+        let new_name = self.remote_new_name_input.trim().to_string();
+
+        if old_name.is_empty() || new_name.is_empty() {
+            self.set_status_message("✗ Remote and new name are both required".to_string());
+            return;
+        }
+        if !self.available_remotes.contains(&old_name) {
+            self.set_status_message(format!("✗ No remote named '{}' (remotes: {})", old_name, self.available_remotes.join(", ")));
+            return;
+        }
+
+        self.cancel_remote_dialog();
+
+        // Also moves the remote-tracking branches and every branch's tracking config
+        match Command::new("git").args(&["remote", "rename", &old_name, &new_name]).output() {
+            Ok(output) if output.status.success() => {
+                let _ = self.init();
+                self.set_status_message(format!("✓ Renamed remote '{}' to '{}'", old_name, new_name));
+            }
+            Ok(output) => self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr).trim())),
+            Err(e) => self.set_status_message(format!("✗ Error: Failed to execute git: {}", e)),
+        }
+    }
+
+    pub fn remote_selection_up(&mut self) {
+        if self.selected_remote_idx > 0 {
+            self.selected_remote_idx -= 1;
+        }
+    }
+
+    pub fn remote_selection_down(&mut self) {
+        if self.selected_remote_idx + 1 < self.available_remotes.len() {
+            self.selected_remote_idx += 1;
+        }
+    }
+
+    pub fn cancel_remote_selection(&mut self) {
+        self.selected_remote_idx = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Enter on the remove list: confirm, spelling out what goes with the remote
+    pub fn select_remote_to_remove(&mut self) {
+        let Some(name) = self.available_remotes.get(self.selected_remote_idx).cloned() else {
+            return;
+        };
+
+        let url = remote_url(&name).unwrap_or_default();
+        self.pending_command_message = Some(format!(
+            "Remove remote '{}'?\n\n{}\n\nThis runs 'git remote remove {}'.\nIts remote-tracking branches ({}/*) are deleted.\nLocal branches are kept but stop tracking it.",
+            name, url, name, name
+        ));
+        self.pending_remote = Some(name);
+        self.pending_command = Some(GitCommand::RemoveRemote);
+        self.mode = AppMode::Confirm;
+    }
+
+    pub(super) fn execute_remove_remote(&mut self) -> Result<String, String> {
+        let name = self.pending_remote.take().ok_or("No remote selected")?;
+
+        let output = Command::new("git")
+            .args(&["remote", "remove", &name])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            let _ = self.init();
+            Ok(format!("Removed remote '{}'", name))
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use super::App;
+use super::types::AppMode;
+
+impl App {
+    pub fn start_search(&mut self) {
+        self.search_query.clear();
+        self.update_search_filter();
+        self.search_editing = true;
+        self.mode = AppMode::Search;
+    }
+
+    pub fn search_input_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.update_search_filter();
+        self.jump_to_first_search_result();
+    }
+
+    pub fn search_backspace(&mut self) {
+        self.search_query.pop();
+        self.update_search_filter();
+        self.jump_to_first_search_result();
+    }
+
+    /// Stop typing but stay in search mode so n/N can walk the matches
+    pub fn finish_search_input(&mut self) {
+        self.search_editing = false;
+    }
+
+    pub fn cancel_search(&mut self) {
+        self.search_query.clear();
+        self.update_search_filter();
+        self.search_editing = false;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Byte ranges of every case-insensitive occurrence of the search query in `text`
+    pub fn search_match_ranges(&self, text: &str) -> Vec<(usize, usize)> {
+        let needle: Vec<char> = self.search_query.chars().flat_map(|c| c.to_lowercase()).collect();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let haystack: Vec<(usize, char)> = text.char_indices().collect();
+        let mut ranges = Vec::new();
+        let mut i = 0;
+
+        while i + needle.len() <= haystack.len() {
+            let matches = haystack[i..i + needle.len()].iter()
+                .zip(needle.iter())
+                .all(|((_, a), b)| a.to_lowercase().eq(std::iter::once(*b)));
+
+            if matches {
+                let start = haystack[i].0;
+                let end = haystack.get(i + needle.len()).map(|(pos, _)| *pos).unwrap_or(text.len());
+                ranges.push((start, end));
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+
+        ranges
+    }
+
+    /// Case-insensitive match against the full message, the author or the hash.
+    /// Reviewed commits never match while only unreviewed ones are shown.
+    fn commit_matches_search(&self, idx: usize) -> bool {
+        self.graph_nodes.get(idx)
+            .map(|node| {
+                let commit = &node.commit;
+                !self.commit_hidden_as_reviewed(&commit.id)
+                    && [&commit.message, &commit.author, &commit.id].iter()
+                        .any(|text| !self.search_match_ranges(text).is_empty())
+            })
+            .unwrap_or(false)
+    }
+
+    fn commit_hidden_as_reviewed(&self, commit_id: &str) -> bool {
+        self.show_unreviewed_only && self.reviewed_commits.contains(commit_id)
+    }
+
+    /// Narrow the graph to the commits matching the search query and, with unreviewed-only on, to
+    /// those not reviewed yet. This only filters the view: lanes stay laid out over every commit.
+    pub(super) fn update_search_filter(&mut self) {
+        self.filtered_graph_nodes = if !self.search_query.is_empty() {
+            Some((0..self.graph_nodes.len()).filter(|&idx| self.commit_matches_search(idx)).collect())
+        } else if self.show_unreviewed_only {
+            Some((0..self.graph_nodes.len())
+                .filter(|&idx| !self.commit_hidden_as_reviewed(&self.graph_nodes[idx].commit.id))
+                .collect())
+        } else {
+            None
+        };
+        self.scroll_offset = 0;
+    }
+
+    /// Move the selection off a commit the filter just hid: to the next shown commit, or the last one
+    pub(super) fn select_filtered_commit(&mut self) {
+        let Some(filtered) = &self.filtered_graph_nodes else {
+            return;
+        };
+        let current = self.selected_commit_idx.unwrap_or(0);
+        if filtered.binary_search(&current).is_ok() {
+            return;
+        }
+
+        let target = filtered.iter().find(|&&idx| idx > current).or(filtered.last()).copied();
+        if target.is_some() {
+            self.selected_commit_idx = target;
+            self.update_selection();
+        }
+    }
+
+    pub fn search_result_count(&self) -> usize {
+        self.filtered_graph_nodes.as_ref().map(|nodes| nodes.len()).unwrap_or(0)
+    }
+
+    /// While typing, keep the selection on the first match at or below the current commit
+    fn jump_to_first_search_result(&mut self) {
+        let start = self.selected_commit_idx.unwrap_or(0);
+        let len = self.graph_nodes.len();
+
+        if let Some(idx) = (0..len).map(|offset| (start + offset) % len).find(|&idx| self.commit_matches_search(idx)) {
+            if Some(idx) != self.selected_commit_idx {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+            }
+        }
+    }
+
+    pub fn next_search_result(&mut self) {
+        let len = self.graph_nodes.len();
+        if len == 0 {
+            return;
+        }
+
+        let start = self.selected_commit_idx.unwrap_or(len - 1);
+        match (1..=len).map(|offset| (start + offset) % len).find(|&idx| self.commit_matches_search(idx)) {
+            Some(idx) => {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+            }
+            None => self.set_status_message(format!("✗ No commits match '{}'", self.search_query)),
+        }
+    }
+
+    pub fn prev_search_result(&mut self) {
+        let len = self.graph_nodes.len();
+        if len == 0 {
+            return;
+        }
+
+        let start = self.selected_commit_idx.unwrap_or(0);
+        match (1..=len).map(|offset| (start + len - offset) % len).find(|&idx| self.commit_matches_search(idx)) {
+            Some(idx) => {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+            }
+            None => self.set_status_message(format!("✗ No commits match '{}'", self.search_query)),
+        }
+    }
+
+    pub fn start_diff_search(&mut self) {
+        self.diff_search_query.clear();
+        self.diff_search_results.clear();
+        self.diff_search_current = 0;
+        self.mode = AppMode::DiffSearch;
+    }
+
+    pub fn diff_search_input_char(&mut self, c: char) {
+        self.diff_search_query.push(c);
+        self.update_diff_search_results();
+    }
+
+    pub fn diff_search_backspace(&mut self) {
+        self.diff_search_query.pop();
+        self.update_diff_search_results();
+    }
+
+    /// Enter in the find bar: keep the matches highlighted and hand n/N over to stepping through them
+    pub fn finish_diff_search(&mut self) {
+        if self.diff_search_query.is_empty() {
+            self.close_diff_search();
+            return;
+        }
+
+        self.mode = AppMode::Normal;
+        if self.diff_search_results.is_empty() {
+            self.set_status_message(format!("✗ No diff lines match '{}'", self.diff_search_query));
+        } else {
+            self.set_status_message(format!(
+                "Match {} of {} – {}/{} next/previous, Esc clears",
+                self.diff_search_current + 1,
+                self.diff_search_results.len(),
+                self.config.keys.next_match,
+                self.config.keys.prev_match,
+            ));
+        }
+    }
+
+    pub fn close_diff_search(&mut self) {
+        self.diff_search_query.clear();
+        self.diff_search_results.clear();
+        self.diff_search_current = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Rescan the diff for the query and jump to the first matching line
+    pub(super) fn update_diff_search_results(&mut self) {
+        let query = self.diff_search_query.to_lowercase();
+
+        self.diff_search_results = match &self.current_diff {
+            Some(diff) if !query.is_empty() => diff.lines()
+                .enumerate()
+                .filter(|(_, line)| line.to_lowercase().contains(&query))
+                .map(|(idx, _)| idx)
+                .collect(),
+            _ => Vec::new(),
+        };
+        self.diff_search_current = 0;
+        self.scroll_to_diff_search_result();
+    }
+
+    pub fn next_diff_search_result(&mut self) {
+        if self.diff_search_results.is_empty() {
+            return;
+        }
+        self.diff_search_current = (self.diff_search_current + 1) % self.diff_search_results.len();
+        self.scroll_to_diff_search_result();
+    }
+
+    pub fn prev_diff_search_result(&mut self) {
+        if self.diff_search_results.is_empty() {
+            return;
+        }
+        let len = self.diff_search_results.len();
+        self.diff_search_current = (self.diff_search_current + len - 1) % len;
+        self.scroll_to_diff_search_result();
+    }
+
+    fn scroll_to_diff_search_result(&mut self) {
+        if let Some(&line_idx) = self.diff_search_results.get(self.diff_search_current) {
+            self.details_scroll_offset = self.details_diff_start + line_idx;
+        }
+    }
+}
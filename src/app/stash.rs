@@ -0,0 +1,241 @@
+use super::App;
+use super::types::{AppMode, GitCommand, StashEntry};
+use std::process::Command;
+
+impl App {
+    pub(super) fn cmd_show_stashes(&mut self) -> Result<String, String> {
+        self.load_stash_entries()?;
+        if self.stash_entries.is_empty() {
+            return Err("No stashes".to_string());
+        }
+
+        self.selected_stash_idx = 0;
+        self.stash_compare_idx = None;
+        self.stash_diff = None;
+        self.stash_diff_title = None;
+        self.stash_untracked_files.clear();
+        self.mode = AppMode::StashView;
+        Ok(format!("{} stash(es)", self.stash_entries.len()))
+    }
+
+    pub(super) fn load_stash_entries(&mut self) -> Result<(), String> {
+        let output = Command::new("git")
+            .args(&["stash", "list", "--format=%gd%x00%H%x00%gs"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        self.stash_entries = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let mut fields = line.splitn(3, '\0');
+                let _selector = fields.next()?;
+                let oid = fields.next()?.to_string();
+                let subject = fields.next().unwrap_or("").to_string();
+
+                // Subjects look like "WIP on main: abc1234 msg" or "On main: msg"
+                let (branch, message) = match subject.split_once(": ") {
+                    Some((prefix, rest)) => (
+                        prefix.trim_start_matches("WIP on ").trim_start_matches("On ").to_string(),
+                        rest.to_string(),
+                    ),
+                    None => (String::new(), subject.clone()),
+                };
+
+                Some(StashEntry { index, message, branch, oid })
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    pub fn stash_up(&mut self) {
+        if self.selected_stash_idx > 0 {
+            self.selected_stash_idx -= 1;
+        }
+    }
+
+    pub fn stash_down(&mut self) {
+        if self.selected_stash_idx + 1 < self.stash_entries.len() {
+            self.selected_stash_idx += 1;
+        }
+    }
+
+    pub fn close_stash_view(&mut self) {
+        self.stash_entries.clear();
+        self.selected_stash_idx = 0;
+        self.stash_compare_idx = None;
+        self.stash_diff = None;
+        self.stash_diff_title = None;
+        self.stash_untracked_files.clear();
+        self.details_scroll_offset = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// Show the selected stash's own changes in the details pane
+    pub fn show_selected_stash_diff(&mut self) {
+        let Some(entry) = self.stash_entries.get(self.selected_stash_idx) else {
+            return;
+        };
+        let name = entry.name();
+
+        match Self::run_stash_diff(&["stash", "show", "-p", &name]) {
+            Ok(diff) => {
+                self.stash_diff = Some(diff);
+                self.stash_diff_title = Some(format!("Stash: {}", name));
+                self.stash_untracked_files = Self::stash_untracked_files(&name);
+                self.details_scroll_offset = 0;
+            }
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    /// First press marks the selected stash, second press diffs it against the newly selected one
+    pub fn compare_selected_stash(&mut self) {
+        let selected = self.selected_stash_idx;
+
+        match self.stash_compare_idx {
+            None => {
+                if let Some(entry) = self.stash_entries.get(selected) {
+                    self.stash_compare_idx = Some(selected);
+                    self.set_status_message(format!("Marked {}; select another stash and press Ctrl+D", entry.name()));
+                }
+            }
+            Some(marked) if marked == selected => {
+                self.stash_compare_idx = None;
+                self.set_status_message("Comparison cancelled".to_string());
+            }
+            Some(marked) => {
+                let (Some(first), Some(second)) = (self.stash_entries.get(marked), self.stash_entries.get(selected)) else {
+                    self.stash_compare_idx = None;
+                    return;
+                };
+                let (first, second) = (first.name(), second.name());
+
+                match Self::run_stash_diff(&["diff", &first, &second]) {
+                    Ok(diff) => {
+                        self.stash_diff = Some(diff);
+                        self.stash_diff_title = Some(format!("Diff: {} vs {}", first, second));
+                        self.stash_untracked_files.clear();
+                        self.details_scroll_offset = 0;
+                    }
+                    Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+                }
+                self.stash_compare_idx = None;
+            }
+        }
+    }
+
+    /// Files in the stash's untracked-files commit (its third parent), if it was made with `-u`
+    fn stash_untracked_files(name: &str) -> Vec<String> {
+        // Fails when the stash has no third parent, which just means nothing untracked was stashed
+        Self::run_stash_diff(&["show", "--name-only", "--format=", &format!("{}^3", name)])
+            .map(|output| output.lines().filter(|line| !line.is_empty()).map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// `git stash apply`/`pop` for `name`; conflicts come back as git's own error text
+    pub(super) fn cmd_stash_apply(&mut self, name: &str, pop: bool) -> Result<String, String> {
+        let action = if pop { "pop" } else { "apply" };
+        Self::run_stash_diff(&["stash", action, name])?;
+        self.load_git_status();
+
+        if pop {
+            Ok(format!("Popped {}", name))
+        } else {
+            Ok(format!("Applied {}", name))
+        }
+    }
+
+    pub(super) fn cmd_stash_drop(&mut self) -> Result<String, String> {
+        let from_list = self.pending_stash_drop.is_some();
+        let name = self.pending_stash_drop.take().unwrap_or_else(|| "stash@{0}".to_string());
+        let result = Self::run_stash_diff(&["stash", "drop", &name]);
+
+        if from_list {
+            self.reload_stash_list();
+        }
+
+        result.map(|_| format!("Dropped {}", name))
+    }
+
+    pub(super) fn stash_drop_confirmation_message(&self, name: &str) -> Option<String> {
+        let entry = self.stash_entries.iter().find(|entry| entry.name() == name);
+        let description = match entry {
+            Some(entry) => format!("{} ({}: {})", name, entry.branch, entry.message),
+            None => name.to_string(),
+        };
+        Some(format!("Drop {}?\n\nIts changes will be lost. This cannot be undone.", description))
+    }
+
+    /// Space in the stash list
+    pub fn apply_selected_stash(&mut self) {
+        self.run_selected_stash_action(false);
+    }
+
+    /// Enter in the stash list
+    pub fn pop_selected_stash(&mut self) {
+        self.run_selected_stash_action(true);
+    }
+
+    fn run_selected_stash_action(&mut self, pop: bool) {
+        let Some(name) = self.stash_entries.get(self.selected_stash_idx).map(|entry| entry.name()) else {
+            return;
+        };
+
+        match self.cmd_stash_apply(&name, pop) {
+            Ok(msg) => {
+                if pop {
+                    self.reload_stash_list();
+                }
+                self.set_status_message(format!("✓ {}", msg));
+            }
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    /// `D` in the stash list: confirm, then drop the highlighted stash
+    pub fn request_drop_selected_stash(&mut self) {
+        let Some(name) = self.stash_entries.get(self.selected_stash_idx).map(|entry| entry.name()) else {
+            return;
+        };
+
+        self.pending_command_message = self.stash_drop_confirmation_message(&name);
+        self.pending_stash_drop = Some(name);
+        self.pending_command = Some(GitCommand::StashDrop);
+        self.mode = AppMode::Confirm;
+    }
+
+    /// Re-read the list after a pop/drop; indices shift, so the old diff and compare mark are stale
+    fn reload_stash_list(&mut self) {
+        let _ = self.load_stash_entries();
+        self.stash_compare_idx = None;
+        self.stash_diff = None;
+        self.stash_diff_title = None;
+        self.stash_untracked_files.clear();
+
+        if self.stash_entries.is_empty() {
+            self.close_stash_view();
+        } else {
+            self.selected_stash_idx = self.selected_stash_idx.min(self.stash_entries.len() - 1);
+            self.mode = AppMode::StashView;
+        }
+    }
+
+    fn run_stash_diff(args: &[&str]) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
+}
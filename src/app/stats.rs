@@ -0,0 +1,73 @@
+use super::App;
+use super::types::{AppMode, RepoStats};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// stdout of a git command, or None if it couldn't run or failed
+fn git_stdout(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        // shortlog reads a log from stdin when it isn't a terminal
+        .stdin(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+impl App {
+    /// `i`: repository statistics overlay. Each git command runs on its own thread and
+    /// fills in its part of the panel when it finishes
+    pub fn open_repo_stats(&mut self) {
+        let result = Arc::new(Mutex::new(RepoStats::default()));
+        self.repo_stats_result = Arc::clone(&result);
+        self.repo_stats = RepoStats::default();
+        self.repo_stats.git_version = self.git_validation.lock().ok()
+            .and_then(|guard| guard.as_ref().and_then(|validation| validation.git_version.clone()));
+        self.mode = AppMode::RepoStats;
+
+        let objects_slot = Arc::clone(&result);
+        thread::spawn(move || {
+            // -H: sizes as "1.21 MiB" rather than KiB counts
+            let objects: Vec<(String, String)> = git_stdout(&["count-objects", "-vH"])
+                .unwrap_or_default()
+                .lines()
+                .filter_map(|line| line.split_once(": "))
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect();
+            if let Ok(mut stats) = objects_slot.lock() {
+                stats.objects = Some(objects);
+            }
+        });
+
+        thread::spawn(move || {
+            let contributors: Vec<(usize, String)> = git_stdout(&["shortlog", "-sn", "HEAD"])
+                .unwrap_or_default()
+                .lines()
+                .filter_map(|line| {
+                    let (count, name) = line.trim_start().split_once('\t')?;
+                    Some((count.trim().parse().ok()?, name.to_string()))
+                })
+                .collect();
+            if let Ok(mut stats) = result.lock() {
+                stats.contributors = Some(contributors);
+            }
+        });
+    }
+
+    /// Copy whatever the stats threads have finished into the panel
+    pub fn check_repo_stats(&mut self) {
+        if self.mode != AppMode::RepoStats {
+            return;
+        }
+        if let Ok(stats) = self.repo_stats_result.lock() {
+            self.repo_stats.objects = stats.objects.clone();
+            self.repo_stats.contributors = stats.contributors.clone();
+        }
+    }
+
+    pub fn close_repo_stats(&mut self) {
+        self.mode = AppMode::Normal;
+    }
+}
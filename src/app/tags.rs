@@ -0,0 +1,320 @@
+use super::App;
+use super::types::{AppMode, DeleteSelection, FocusedPane, GitCommand, GpgTagStatus, TagEntry, TagSort};
+use std::collections::{HashMap, HashSet};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+impl App {
+    pub(super) fn sort_tag_entries(&mut self) {
+        match self.tag_sort {
+            TagSort::Name => self.tag_entries.sort_by(|a, b| a.name.cmp(&b.name)),
+            // Newest first, like the commit graph
+            TagSort::Date => self.tag_entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| a.name.cmp(&b.name))),
+        }
+
+        if self.selected_tag_idx >= self.tag_entries.len() {
+            self.selected_tag_idx = self.tag_entries.len().saturating_sub(1);
+        }
+    }
+
+    /// Verify every annotated tag's signature in the background; results land in `tag_verification_cache`
+    pub(super) fn start_tag_verification(&mut self) {
+        let names: Vec<String> = self.tag_entries.iter()
+            .filter(|tag| tag.annotated)
+            .map(|tag| tag.name.clone())
+            .collect();
+
+        // A fresh slot per run so an older verification can't overwrite a newer one
+        let result = Arc::new(Mutex::new(None));
+        self.tag_verification_result = Arc::clone(&result);
+
+        if names.is_empty() {
+            self.tag_verification_cache.clear();
+            return;
+        }
+
+        thread::spawn(move || {
+            let statuses: HashMap<String, GpgTagStatus> = names.into_iter()
+                .filter_map(|name| {
+                    let output = Command::new("git")
+                        .args(&["verify-tag", "--raw", &name])
+                        .output()
+                        .ok()?;
+                    // --raw writes the GnuPG status lines to stderr
+                    let status = GpgTagStatus::from_raw(&String::from_utf8_lossy(&output.stderr));
+                    Some((name, status))
+                })
+                .collect();
+
+            if let Ok(mut guard) = result.lock() {
+                *guard = Some(statuses);
+            }
+        });
+    }
+
+    pub(super) fn cmd_create_tag(&mut self, commit_id: &str) -> Result<String, String> {
+        self.tag_name_input.clear();
+        self.pending_tag_commit_id = Some(commit_id.to_string());
+        self.mode = AppMode::TagName;
+        Ok("Enter tag name...".to_string())
+    }
+
+    pub fn tag_name_input_char(&mut self, c: char) {
+        if !c.is_whitespace() {
+            self.input_insert(c);
+        }
+    }
+
+    pub fn tag_name_backspace(&mut self) {
+        self.input_backspace();
+    }
+
+    pub fn cancel_tag_name(&mut self) {
+        self.tag_name_input.clear();
+        self.pending_tag_commit_id = None;
+        self.mode = AppMode::Normal;
+    }
+
+    pub fn submit_tag_name(&mut self) {
+        let name = self.tag_name_input.trim().to_string();
+        if name.is_empty() {
+            self.set_status_message("✗ Tag name cannot be empty".to_string());
+            return;
+        }
+
+        let Some(commit_id) = self.pending_tag_commit_id.take() else {
+            self.cancel_tag_name();
+            return;
+        };
+        self.tag_name_input.clear();
+        self.mode = AppMode::Normal;
+
+        match self.execute_create_tag(&name, &commit_id) {
+            Ok(msg) => self.set_status_message(format!("✓ {}", msg)),
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    fn execute_create_tag(&mut self, name: &str, commit_id: &str) -> Result<String, String> {
+        let output = Command::new("git")
+            .args(&["tag", name, commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        // Only this commit's tags changed, so skip enumerating every tag again
+        self.invalidate_tag_cache(commit_id);
+        let timestamp = self.graph_nodes.iter()
+            .find(|node| node.commit.id == commit_id)
+            .map(|node| node.commit.timestamp)
+            .unwrap_or_default();
+        self.tag_entries.push(TagEntry {
+            name: name.to_string(),
+            commit_id: commit_id.to_string(),
+            annotated: false,
+            timestamp,
+        });
+        self.sort_tag_entries();
+
+        Ok(format!("Created tag '{}' at {}", name, &commit_id[..7.min(commit_id.len())]))
+    }
+
+    /// Pick which tag(s) at the commit to delete; a lone tag goes straight to confirmation
+    pub(super) fn cmd_delete_tag(&mut self, commit_id: &str) -> Result<String, String> {
+        // Ask the remote while the user picks, for the "exists on origin" warning
+        self.start_origin_tag_lookup();
+
+        let output = Command::new("git")
+            .args(&["tag", "--points-at", commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        let tags: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        if tags.is_empty() {
+            return Err("No tags at this commit".to_string());
+        }
+
+        let single = tags.len() == 1;
+        self.available_branches = tags;
+        self.selected_branch_idx = 0;
+        self.branches_marked_for_delete.clear();
+        self.delete_selection = DeleteSelection::Tags;
+
+        if single {
+            self.select_branch_to_delete();
+            Ok("Confirm to delete tag...".to_string())
+        } else {
+            self.mode = AppMode::SelectBranchToDelete;
+            Ok("Select tag to delete...".to_string())
+        }
+    }
+
+    pub(super) fn tag_delete_confirmation_message(&self, tag_names: &[String]) -> String {
+        let mut message = if let [name] = tag_names {
+            format!("Delete tag '{}'?\n\nThis runs 'git tag -d' and only removes the local tag.", name)
+        } else {
+            format!(
+                "Delete {} tags?\n\n{}\n\nThis runs 'git tag -d' and only removes the local tags.",
+                tag_names.len(),
+                tag_names.iter().map(|t| format!("  - {}", t)).collect::<Vec<_>>().join("\n")
+            )
+        };
+
+        match &self.origin_tags {
+            None => message.push_str("\n\nChecking whether remote 'origin' has these tags..."),
+            Some(Err(e)) => message.push_str(&format!("\n\nCould not check remote 'origin' for these tags: {}", e)),
+            Some(Ok(origin_tags)) => {
+                for name in tag_names.iter().filter(|name| origin_tags.contains(*name)) {
+                    message.push_str(&format!(
+                        "\n\n⚠️  This tag exists on remote 'origin'. Delete from remote separately with 'git push origin :refs/tags/{}'.",
+                        name
+                    ));
+                }
+            }
+        }
+
+        message
+    }
+
+    /// One `git ls-remote --tags origin` in the background. It runs while the TUI owns the terminal,
+    /// so credential prompts are switched off for HTTPS and SSH alike.
+    fn start_origin_tag_lookup(&mut self) {
+        let result = Arc::new(Mutex::new(None));
+        self.origin_tags_result = Arc::clone(&result);
+        self.origin_tags = None;
+
+        thread::spawn(move || {
+            // Keep the user's ssh command, but make it fail instead of asking for a passphrase
+            let ssh_command = std::env::var("GIT_SSH_COMMAND").ok()
+                .or_else(|| {
+                    Command::new("git").args(&["config", "core.sshCommand"]).output().ok()
+                        .filter(|output| output.status.success())
+                        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+                })
+                .unwrap_or_else(|| "ssh".to_string());
+
+            let tags = Command::new("git")
+                .args(&["ls-remote", "--tags", "--refs", "origin"])
+                .env("GIT_TERMINAL_PROMPT", "0")
+                .env("GIT_SSH_COMMAND", format!("{} -o BatchMode=yes", ssh_command))
+                .stdin(Stdio::null())
+                .output()
+                .map_err(|e| format!("Failed to execute git: {}", e))
+                .and_then(|output| {
+                    if output.status.success() {
+                        Ok(String::from_utf8_lossy(&output.stdout)
+                            .lines()
+                            .filter_map(|line| line.split_once("refs/tags/").map(|(_, name)| name.to_string()))
+                            .collect::<HashSet<String>>())
+                    } else {
+                        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+                    }
+                });
+
+            if let Ok(mut guard) = result.lock() {
+                *guard = Some(tags);
+            }
+        });
+    }
+
+    /// Store the remote tag list and, if the delete confirmation is already up, add its warnings
+    pub fn check_origin_tags(&mut self) {
+        let tags = self.origin_tags_result.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(tags) = tags {
+            self.origin_tags = Some(tags);
+            if self.mode == AppMode::Confirm && matches!(self.pending_command, Some(GitCommand::DeleteTag)) {
+                self.pending_command_message = Some(self.tag_delete_confirmation_message(&self.branches_marked_for_delete));
+            }
+        }
+    }
+
+    pub(super) fn execute_delete_tags(&mut self, tag_names: &[String]) -> Result<String, String> {
+        if tag_names.is_empty() {
+            return Err("No tag selected".to_string());
+        }
+
+        let output = Command::new("git")
+            .arg("tag")
+            .arg("-d")
+            .args(tag_names)
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let commit_ids: Vec<String> = self.tag_entries.iter()
+            .filter(|tag| tag_names.contains(&tag.name))
+            .map(|tag| tag.commit_id.clone())
+            .collect();
+        for commit_id in &commit_ids {
+            self.invalidate_tag_cache(commit_id);
+        }
+        self.tag_entries.retain(|tag| !tag_names.contains(&tag.name));
+        self.sort_tag_entries();
+
+        if let [name] = tag_names {
+            Ok(format!("Deleted tag '{}'", name))
+        } else {
+            Ok(format!("Deleted {} tags", tag_names.len()))
+        }
+    }
+
+    pub fn check_tag_verification(&mut self) {
+        let statuses = self.tag_verification_result.lock().ok().and_then(|mut guard| guard.take());
+        if let Some(statuses) = statuses {
+            self.tag_verification_cache = statuses;
+        }
+    }
+
+    pub fn toggle_tag_sort(&mut self) {
+        self.tag_sort = match self.tag_sort {
+            TagSort::Name => TagSort::Date,
+            TagSort::Date => TagSort::Name,
+        };
+        self.selected_tag_idx = 0;
+        self.sort_tag_entries();
+        self.set_status_message(format!("✓ Sorting tags by {}", self.tag_sort.label()));
+    }
+
+    pub fn tag_up(&mut self) {
+        if self.selected_tag_idx > 0 {
+            self.selected_tag_idx -= 1;
+        }
+    }
+
+    pub fn tag_down(&mut self) {
+        if self.selected_tag_idx + 1 < self.tag_entries.len() {
+            self.selected_tag_idx += 1;
+        }
+    }
+
+    /// Select the commit the highlighted tag points at and focus the graph
+    pub fn goto_selected_tag(&mut self) {
+        let Some(tag) = self.tag_entries.get(self.selected_tag_idx) else {
+            return;
+        };
+
+        match self.graph_nodes.iter().position(|node| node.commit.id == tag.commit_id) {
+            Some(idx) => {
+                self.selected_commit_idx = Some(idx);
+                self.update_selection();
+                self.focused_pane = FocusedPane::CommitGraph;
+            }
+            None => {
+                let message = format!("✗ Tag {} points at a commit not loaded in the graph", tag.name);
+                self.set_status_message(message);
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::App;
+use super::types::{AppMode, CommitTreeEntry};
+use std::process::Command;
+
+impl App {
+    pub fn open_commit_tree(&mut self) {
+        let Some(commit_id) = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .map(|node| node.commit.id.clone())
+        else {
+            return;
+        };
+
+        match Self::load_commit_tree(&commit_id) {
+            Ok(entries) if entries.is_empty() => {
+                self.set_status_message("No files changed in this commit".to_string());
+            }
+            Ok(entries) => {
+                self.commit_tree_entries = entries;
+                // Start on the first file rather than its directory
+                self.selected_tree_idx = self.commit_tree_entries.iter()
+                    .position(|e| e.path.is_some())
+                    .unwrap_or(0);
+                self.commit_tree_commit_id = Some(commit_id);
+                self.mode = AppMode::CommitTree;
+            }
+            Err(e) => self.set_status_message(format!("✗ Error: {}", e)),
+        }
+    }
+
+    pub fn close_commit_tree(&mut self) {
+        self.commit_tree_entries.clear();
+        self.selected_tree_idx = 0;
+        self.commit_tree_commit_id = None;
+        self.mode = AppMode::Normal;
+        // Opening a file replaced the commit diff; bring it back
+        self.load_current_diff();
+    }
+
+    pub fn tree_up(&mut self) {
+        if self.selected_tree_idx > 0 {
+            self.selected_tree_idx -= 1;
+        }
+    }
+
+    pub fn tree_down(&mut self) {
+        if self.selected_tree_idx + 1 < self.commit_tree_entries.len() {
+            self.selected_tree_idx += 1;
+        }
+    }
+
+    /// Show the selected file's part of the commit in the fullscreen diff view
+    pub fn open_tree_file_diff(&mut self) {
+        let (Some(commit_id), Some(path)) = (
+            self.commit_tree_commit_id.clone(),
+            self.commit_tree_entries.get(self.selected_tree_idx).and_then(|e| e.path.clone()),
+        ) else {
+            return;
+        };
+
+        let output = Command::new("git")
+            .args(&["show", "--format=", &commit_id, "--", &path])
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                self.current_diff = Some(String::from_utf8_lossy(&output.stdout).to_string());
+                self.details_scroll_offset = 0;
+                self.details_horizontal_offset = 0;
+                self.mode = AppMode::FileDiffView;
+            }
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+            }
+        }
+    }
+
+    /// Changed files of a commit as a flattened directory tree, directories before their contents
+    fn load_commit_tree(commit_id: &str) -> Result<Vec<CommitTreeEntry>, String> {
+        // --numstat gives the same paths as --name-only plus per-file line counts
+        let output = Command::new("git")
+            .args(&["diff-tree", "-r", "--root", "--no-commit-id", "--numstat", commit_id])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let mut files: Vec<(String, Option<usize>, Option<usize>)> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                // Binary files report "-" for both counts
+                let insertions = fields.next()?.parse().ok();
+                let deletions = fields.next()?.parse().ok();
+                let path = fields.next()?.to_string();
+                Some((path, insertions, deletions))
+            })
+            .collect();
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut entries = Vec::new();
+        let mut open_dirs: Vec<&str> = Vec::new();
+
+        for (path, insertions, deletions) in &files {
+            let components: Vec<&str> = path.split('/').collect();
+            let (dirs, file_name) = components.split_at(components.len() - 1);
+
+            // Close directories this path is no longer inside of
+            let shared = open_dirs.iter().zip(dirs.iter()).take_while(|(a, b)| a == b).count();
+            open_dirs.truncate(shared);
+
+            for dir in &dirs[shared..] {
+                entries.push(CommitTreeEntry {
+                    depth: open_dirs.len(),
+                    name: format!("{}/", dir),
+                    path: None,
+                    insertions: None,
+                    deletions: None,
+                });
+                open_dirs.push(*dir);
+            }
+
+            entries.push(CommitTreeEntry {
+                depth: dirs.len(),
+                name: file_name[0].to_string(),
+                path: Some(path.clone()),
+                insertions: *insertions,
+                deletions: *deletions,
+            });
+        }
+
+        Ok(entries)
+    }
+}
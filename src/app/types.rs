@@ -1,3 +1,8 @@
+use serde::Deserialize;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
 #[derive(Debug, Clone)]
 pub struct GitValidationResult {
     pub git_version: Option<String>,
@@ -46,38 +51,153 @@ impl GitValidationResult {
     }
 }
 
+/// Background diff load backing the hover preview of the selected commit.
+/// Each selection change gets its own job so a stale load can never land on the wrong commit.
+pub struct DiffPreview {
+    pub commit_id: String,
+    pub started: Instant,
+    pub lines: Option<Vec<String>>,
+    pub cancelled: Arc<AtomicBool>,
+    pub result: Arc<Mutex<Option<Vec<String>>>>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AppMode {
     Normal,
     Confirm,
     CommitMessage,
+    MergeMessage,
     BranchName,
+    RenameBranch,
     SelectBranch,
     SelectBranchToDelete,
     SetUserName,
     SetUserEmail,
     SetRemoteHost,
+    AddRemote,
+    SelectRemoteToRemove,
+    RenameRemote,
     SquashCountInput,
     RewordMessage,
     SelectCommitsForBranch,
     AssignBranchName,
     FileDiffView,
+    Search,
+    DiffSearch,
+    HooksView,
+    StashView,
+    CommitTree,
+    WorkingTreeSummary,
+    RepoStats,
+    QuickLog,
+    WorktreeView,
+    WorktreeCreate,
+    GoToHash,
+    TagName,
+    PushTarget,
+    SetUpstream,
+    ConflictResolution,
+    Reflog,
+    BlameView,
+    FileHistory,
+    RebaseTodo,
+    RebaseOntoNewBase,
+    RebaseOntoUpstream,
+    YankedHash,
     Help,
 }
 
+impl AppMode {
+    /// Short name shown in the window title while a dialog is open; `None` for the main view
+    pub fn title_name(&self) -> Option<&str> {
+        match self {
+            AppMode::Normal => None,
+            AppMode::Confirm => Some("confirm"),
+            AppMode::CommitMessage => Some("commit message"),
+            AppMode::MergeMessage => Some("merge message"),
+            AppMode::BranchName => Some("branch name"),
+            AppMode::RenameBranch => Some("rename branch"),
+            AppMode::SelectBranch => Some("select branch"),
+            AppMode::SelectBranchToDelete => Some("delete branch"),
+            AppMode::SetUserName => Some("user name"),
+            AppMode::SetUserEmail => Some("user email"),
+            AppMode::SetRemoteHost => Some("remote host"),
+            AppMode::AddRemote => Some("add remote"),
+            AppMode::SelectRemoteToRemove => Some("remove remote"),
+            AppMode::RenameRemote => Some("rename remote"),
+            AppMode::SquashCountInput => Some("squash"),
+            AppMode::RewordMessage => Some("reword"),
+            AppMode::SelectCommitsForBranch => Some("select commits"),
+            AppMode::AssignBranchName => Some("assign branch"),
+            AppMode::FileDiffView => Some("file diff"),
+            AppMode::Search => Some("search"),
+            AppMode::DiffSearch => Some("diff search"),
+            AppMode::HooksView => Some("hooks"),
+            AppMode::StashView => Some("stashes"),
+            AppMode::CommitTree => Some("changed files"),
+            AppMode::WorkingTreeSummary => Some("summary"),
+            AppMode::RepoStats => Some("repository stats"),
+            AppMode::QuickLog => Some("quick log"),
+            AppMode::WorktreeView => Some("worktrees"),
+            AppMode::WorktreeCreate => Some("new worktree"),
+            AppMode::GoToHash => Some("go to commit"),
+            AppMode::TagName => Some("tag name"),
+            AppMode::PushTarget => Some("push to"),
+            AppMode::SetUpstream => Some("set upstream"),
+            AppMode::ConflictResolution => Some("conflicts"),
+            AppMode::Reflog => Some("reflog"),
+            AppMode::BlameView => Some("blame"),
+            AppMode::FileHistory => Some("file history"),
+            AppMode::RebaseTodo => Some("interactive rebase"),
+            AppMode::RebaseOntoNewBase => Some("rebase onto: new base"),
+            AppMode::RebaseOntoUpstream => Some("rebase onto: upstream"),
+            AppMode::YankedHash => Some("commit hash"),
+            AppMode::Help => Some("help"),
+        }
+    }
+}
+
+/// What the commit details pane shows: the selected commit, or one side of the selected git status file's changes
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileDiffMode {
+    Commit,
+    Staged,
+    Unstaged,
+}
+
+/// A pane of the main screen; named in snake_case in the config file's `pane_order`
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FocusedPane {
     CommitGraph,
     GitActions,
     CommitDetails,
     GitStatus,
+    TagList,
+}
+
+impl FocusedPane {
+    /// Name used for the pane in the config file
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            FocusedPane::CommitGraph => "commit_graph",
+            FocusedPane::GitActions => "git_actions",
+            FocusedPane::CommitDetails => "commit_details",
+            FocusedPane::GitStatus => "git_status",
+            FocusedPane::TagList => "tag_list",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum GitCommand {
     Checkout,
     CreateBranch,
+    RenameBranch,
+    CreateTag,
+    DeleteTag,
     ForceDeleteBranch,
+    DeleteMergedBranches,
     Reset,
     ResetSoft,
     ResetHard,
@@ -85,17 +205,49 @@ pub enum GitCommand {
     Revert,
     Rebase,
     Merge,
+    MergeNoFF,
+    MergeSquash,
     SquashCommits,
     Reword,
     AssignToBranch,
     Add,
+    StageAll,
+    UnstageAll,
     Commit,
+    AmendKeepMessage,
+    Fixup,
     Push,
+    PushForceWithLease,
+    PushTo,
+    SetUpstream,
+    DeleteRemoteBranch,
+    InteractiveRebase,
+    RebaseOnto,
+    RebaseAutosquash,
+    LoadMore,
+    DiscardFileChanges,
     Pull,
+    Fetch,
     PullAll,
     SetUserName,
     SetUserEmail,
+    EditRepoConfig,
     SetRemoteHost,
+    AddRemote,
+    RemoveRemote,
+    RenameRemote,
+    PruneRemote,
+    RecoverOrphanedCommit,
+    RepeatLastCommand,
+    ShowHooks,
+    Stash,
+    StashApply,
+    StashPop,
+    StashDrop,
+    ShowWorkingTreeSummary,
+    ShowUnreviewed,
+    QuickLog,
+    ShowWorktrees,
 }
 
 impl GitCommand {
@@ -103,7 +255,11 @@ impl GitCommand {
         match self {
             GitCommand::Checkout => "checkout",
             GitCommand::CreateBranch => "create branch",
+            GitCommand::RenameBranch => "rename branch",
+            GitCommand::CreateTag => "create tag",
+            GitCommand::DeleteTag => "delete tag",
             GitCommand::ForceDeleteBranch => "force delete branch",
+            GitCommand::DeleteMergedBranches => "delete merged branches",
             GitCommand::Reset => "reset --mixed",
             GitCommand::ResetSoft => "reset --soft",
             GitCommand::ResetHard => "reset --hard",
@@ -111,27 +267,67 @@ impl GitCommand {
             GitCommand::Revert => "revert",
             GitCommand::Rebase => "rebase",
             GitCommand::Merge => "merge",
+            GitCommand::MergeNoFF => "merge --no-ff (preserve branch)",
+            GitCommand::MergeSquash => "merge --squash (collapse branch)",
             GitCommand::SquashCommits => "squash last N commits",
             GitCommand::Reword => "reword commit message",
             GitCommand::AssignToBranch => "assign commits to branch",
             GitCommand::Add => "add -A",
+            GitCommand::StageAll => "stage all changes",
+            GitCommand::UnstageAll => "unstage all changes",
             GitCommand::Commit => "commit",
+            GitCommand::AmendKeepMessage => "amend HEAD (keep message)",
+            GitCommand::Fixup => "commit --fixup (selected commit)",
             GitCommand::Push => "push",
+            GitCommand::PushForceWithLease => "push --force-with-lease",
+            GitCommand::PushTo => "push to remote / refspec",
+            GitCommand::SetUpstream => "set upstream (tracking branch)",
+            GitCommand::DeleteRemoteBranch => "delete remote branch",
+            GitCommand::InteractiveRebase => "interactive rebase from here",
+            GitCommand::RebaseOnto => "rebase --onto (pick new base, then upstream)",
+            GitCommand::RebaseAutosquash => "rebase --autosquash (fold in fixups)",
+            GitCommand::LoadMore => "load more commits",
+            GitCommand::DiscardFileChanges => "discard file changes",
             GitCommand::Pull => "pull",
+            GitCommand::Fetch => "fetch --all (no merge)",
             GitCommand::PullAll => "fetch and sync all branches from remote",
             GitCommand::SetUserName => "config user.name",
             GitCommand::SetUserEmail => "config user.email",
+            GitCommand::EditRepoConfig => "edit repository config (.git_manager.toml)",
             GitCommand::SetRemoteHost => "set remote url",
+            GitCommand::AddRemote => "add remote",
+            GitCommand::RemoveRemote => "remove remote",
+            GitCommand::RenameRemote => "rename remote",
+            GitCommand::PruneRemote => "prune stale remote-tracking refs",
+            GitCommand::RecoverOrphanedCommit => "recover orphaned commit (reflog)",
+            GitCommand::RepeatLastCommand => "repeat last command",
+            GitCommand::ShowHooks => "show git hooks",
+            GitCommand::Stash => "stash list (apply / pop / drop)",
+            GitCommand::StashApply => "stash apply (latest)",
+            GitCommand::StashPop => "stash pop (latest)",
+            GitCommand::StashDrop => "stash drop (latest)",
+            GitCommand::ShowWorkingTreeSummary => "summarize uncommitted changes",
+            GitCommand::ShowUnreviewed => "toggle unreviewed commits only",
+            GitCommand::QuickLog => "quick log (last 50 commits)",
+            GitCommand::ShowWorktrees => "show worktrees",
         }
     }
 
     pub fn needs_confirmation(&self) -> bool {
         match self {
-            GitCommand::Checkout | GitCommand::Reset | GitCommand::ResetSoft | GitCommand::ResetHard | GitCommand::Rebase | GitCommand::Merge | GitCommand::CherryPick | GitCommand::Revert | GitCommand::Push => true,
+            GitCommand::Checkout | GitCommand::Reset | GitCommand::ResetSoft | GitCommand::ResetHard | GitCommand::Rebase | GitCommand::Merge | GitCommand::MergeNoFF | GitCommand::MergeSquash | GitCommand::CherryPick | GitCommand::Revert | GitCommand::Push | GitCommand::PushForceWithLease | GitCommand::StageAll | GitCommand::UnstageAll | GitCommand::StashDrop | GitCommand::Fixup | GitCommand::RebaseAutosquash | GitCommand::AmendKeepMessage | GitCommand::PruneRemote => true,
             _ => false,
         }
     }
 
+    /// Commands that act on the selected commit and can be re-run with `.`
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            GitCommand::Checkout | GitCommand::Reset | GitCommand::ResetSoft | GitCommand::ResetHard | GitCommand::CherryPick | GitCommand::Revert | GitCommand::Rebase | GitCommand::Merge | GitCommand::MergeNoFF | GitCommand::MergeSquash
+        )
+    }
+
     pub fn confirmation_message(&self) -> &str {
         match self {
             GitCommand::Checkout => "Checkout this commit. Continue?",
@@ -141,10 +337,20 @@ impl GitCommand {
             GitCommand::ResetHard => "WARNING: Reset HEAD and DISCARD ALL CHANGES. Continue?",
             GitCommand::Rebase => "Rebase can rewrite history. Continue?",
             GitCommand::Merge => "Merge the selected commit into current branch. Continue?",
+            GitCommand::MergeNoFF => "Merge the selected commit with a merge commit, even if it could fast-forward. Continue?",
+            GitCommand::MergeSquash => "Squash the selected commit's changes into the index. This will NOT create a merge commit; the branch topology will be lost. Continue?",
             GitCommand::CherryPick => "Cherry-pick the selected commit onto current branch. Continue?",
             GitCommand::Revert => "Revert the selected commit on current branch. Continue?",
             GitCommand::SquashCommits => "Squash commits. This will combine multiple commits into one. Continue?",
             GitCommand::Push => "Push changes to remote repository. Continue?",
+            GitCommand::PushForceWithLease => "Force push with lease. This overwrites the remote branch, but only if nobody has pushed to it since your last fetch. Continue?",
+            GitCommand::StageAll => "Stage all changes. Continue?",
+            GitCommand::UnstageAll => "Unstage all changes. Continue?",
+            GitCommand::StashDrop => "Drop the stash. Its changes will be lost. Continue?",
+            GitCommand::PruneRemote => "This will remove remote-tracking branches that no longer exist on the remote. Continue?",
+            GitCommand::AmendKeepMessage => "Amend HEAD with the staged changes, keeping its message. Continue?",
+            GitCommand::RebaseAutosquash => "Autosquash rewrites history to fold fixup commits into their targets. Continue?",
+            GitCommand::Fixup => "Commit the staged changes as a fixup for the selected commit. Continue?",
             _ => "Are you sure?",
         }
     }
@@ -158,6 +364,276 @@ pub enum FileStatus {
     Deleted,
 }
 
+/// Before/after status flashed on a file row for a couple of frames after it is (un)staged
+#[derive(Debug, Clone, Copy)]
+pub struct StagingAnimState {
+    pub from: FileStatus,
+    pub to: FileStatus,
+    pub ticks_remaining: u8,
+}
+
+impl FileStatus {
+    pub fn label(&self) -> &str {
+        match self {
+            FileStatus::Staged => "staged",
+            FileStatus::Modified => "modified",
+            FileStatus::Untracked => "untracked",
+            FileStatus::Deleted => "deleted",
+        }
+    }
+}
+
+/// Totals for all staged and unstaged changes against HEAD
+#[derive(Debug, Clone)]
+pub struct WorkingTreeSummary {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+    /// Most changed files as (path, insertions, deletions), largest first
+    pub top_files: Vec<(String, usize, usize)>,
+}
+
+/// The repository statistics panel; `None` parts are still loading
+#[derive(Debug, Clone, Default)]
+pub struct RepoStats {
+    pub git_version: Option<String>,
+    /// `git count-objects -vH` as (key, value), e.g. ("size-pack", "1.21 MiB")
+    pub objects: Option<Vec<(String, String)>>,
+    /// (commit count, author) from `git shortlog -sn HEAD`, most commits first; one entry per unique author
+    pub contributors: Option<Vec<(usize, String)>>,
+}
+
+/// One row of the changed-files tree: a directory (`path` is None) or a file
+#[derive(Debug, Clone)]
+pub struct CommitTreeEntry {
+    pub depth: usize,
+    pub name: String,
+    pub path: Option<String>,
+    pub insertions: Option<usize>,
+    pub deletions: Option<usize>,
+}
+
+/// One entry of `git stash list`
+#[derive(Debug, Clone)]
+pub struct StashEntry {
+    pub index: usize,
+    pub message: String,
+    pub branch: String,
+    pub oid: String,
+}
+
+impl StashEntry {
+    /// Revision name git understands, e.g. `stash@{2}`
+    pub fn name(&self) -> String {
+        format!("stash@{{{}}}", self.index)
+    }
+}
+
+/// A file left unmerged by a conflicted merge, cherry-pick or rebase
+#[derive(Debug, Clone)]
+pub struct ConflictFile {
+    pub path: String,
+    /// Staged with `a` in the resolution assistant
+    pub resolved: bool,
+}
+
+/// The operation that stopped on conflicts, so the assistant knows how to continue it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConflictOperation {
+    Merge,
+    CherryPick,
+    Rebase,
+}
+
+impl ConflictOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictOperation::Merge => "Merge",
+            ConflictOperation::CherryPick => "Cherry-pick",
+            ConflictOperation::Rebase => "Rebase",
+        }
+    }
+
+    pub fn continue_args(&self) -> &'static [&'static str] {
+        match self {
+            ConflictOperation::Merge => &["merge", "--continue"],
+            ConflictOperation::CherryPick => &["cherry-pick", "--continue"],
+            ConflictOperation::Rebase => &["rebase", "--continue"],
+        }
+    }
+}
+
+/// One entry of `git worktree list`
+#[derive(Debug, Clone)]
+pub struct WorktreeEntry {
+    pub path: String,
+    pub head: String,
+    /// `None` for a detached HEAD
+    pub branch: Option<String>,
+}
+
+/// One commit in the quick log popup
+#[derive(Debug, Clone)]
+pub struct QuickLogEntry {
+    pub id: String,
+    pub short_id: String,
+    pub subject: String,
+}
+
+/// A commit action in an interactive rebase todo list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RebaseAction {
+    Pick,
+    Reword,
+    Edit,
+    Squash,
+    Fixup,
+    Drop,
+}
+
+impl RebaseAction {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            RebaseAction::Pick => "pick",
+            RebaseAction::Reword => "reword",
+            RebaseAction::Edit => "edit",
+            RebaseAction::Squash => "squash",
+            RebaseAction::Fixup => "fixup",
+            RebaseAction::Drop => "drop",
+        }
+    }
+
+    /// Accepts the long and single-letter forms git writes and reads
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "pick" | "p" => Some(RebaseAction::Pick),
+            "reword" | "r" => Some(RebaseAction::Reword),
+            "edit" | "e" => Some(RebaseAction::Edit),
+            "squash" | "s" => Some(RebaseAction::Squash),
+            "fixup" | "f" => Some(RebaseAction::Fixup),
+            "drop" | "d" => Some(RebaseAction::Drop),
+            _ => None,
+        }
+    }
+
+    /// Space in the todo editor cycles through the actions in this order
+    pub fn next(&self) -> Self {
+        match self {
+            RebaseAction::Pick => RebaseAction::Reword,
+            RebaseAction::Reword => RebaseAction::Edit,
+            RebaseAction::Edit => RebaseAction::Squash,
+            RebaseAction::Squash => RebaseAction::Fixup,
+            RebaseAction::Fixup => RebaseAction::Drop,
+            RebaseAction::Drop => RebaseAction::Pick,
+        }
+    }
+}
+
+/// One commit line of an interactive rebase todo list
+#[derive(Debug, Clone)]
+pub struct RebaseTodoEntry {
+    pub action: RebaseAction,
+    pub hash: String,
+    pub message: String,
+}
+
+impl RebaseTodoEntry {
+    pub fn to_todo_line(&self) -> String {
+        format!("{} {} {}", self.action.keyword(), self.hash, self.message)
+    }
+}
+
+/// One line of `git reflog`
+#[derive(Debug, Clone)]
+pub struct ReflogEntry {
+    pub hash: String,
+    /// Selector such as `HEAD@{3}`
+    pub refname: String,
+    pub description: String,
+}
+
+/// One line of `git blame`
+#[derive(Debug, Clone)]
+pub struct BlameLine {
+    pub commit_hash: String,
+    pub author: String,
+    /// Author date as YYYY-MM-DD
+    pub date: String,
+    pub content: String,
+}
+
+/// One tag as shown in the tag list pane
+#[derive(Debug, Clone)]
+pub struct TagEntry {
+    pub name: String,
+    pub commit_id: String,
+    pub annotated: bool,
+    /// Tagger date for annotated tags, commit date for lightweight ones
+    pub timestamp: i64,
+}
+
+/// Tag names `git ls-remote --tags origin` listed, or why it failed
+pub type OriginTags = Result<std::collections::HashSet<String>, String>;
+
+/// Result of `git verify-tag` for an annotated tag
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GpgTagStatus {
+    Good,
+    Bad,
+    NoPubkey,
+    Unsigned,
+}
+
+impl GpgTagStatus {
+    /// Classify `git verify-tag --raw` status output
+    pub fn from_raw(raw: &str) -> Self {
+        if raw.contains("[GNUPG:] BADSIG") {
+            GpgTagStatus::Bad
+        } else if raw.contains("[GNUPG:] GOODSIG") {
+            GpgTagStatus::Good
+        } else if raw.contains("[GNUPG:] NO_PUBKEY") || raw.contains("[GNUPG:] ERRSIG") {
+            GpgTagStatus::NoPubkey
+        } else {
+            GpgTagStatus::Unsigned
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TagSort {
+    Name,
+    Date,
+}
+
+impl TagSort {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TagSort::Name => "name",
+            TagSort::Date => "date",
+        }
+    }
+}
+
+/// What the delete picker (`SelectBranchToDelete`) is listing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeleteSelection {
+    Branches,
+    Tags,
+    RemoteBranches,
+    /// Local branches fully merged into HEAD (`git branch -d`)
+    MergedBranches,
+}
+
+/// A file in the repository's hooks directory
+#[derive(Debug, Clone)]
+pub struct HookInfo {
+    pub name: String,
+    pub path: String,
+    pub enabled: bool,
+    pub is_sample: bool,
+    pub preview: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct StatusFile {
     pub path: String,
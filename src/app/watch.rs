@@ -0,0 +1,143 @@
+use super::App;
+use super::types::AppMode;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Newest modification time and file count among the watched paths; a count change catches deleted refs
+type Fingerprint = (Option<SystemTime>, usize);
+
+impl App {
+    pub fn toggle_watch_mode(&mut self) {
+        if self.watch_mode {
+            self.stop_watcher();
+            self.watch_mode = false;
+            self.set_status_message("✓ Watch mode off".to_string());
+        } else {
+            self.start_watcher();
+            self.watch_mode = true;
+            self.set_status_message("✓ Watch mode on - refreshing on repository changes".to_string());
+        }
+    }
+
+    /// Called from `init`, which also runs after every command; only the first call spawns a thread
+    pub(super) fn ensure_watcher(&mut self) {
+        if self.watch_mode && self.watch_stop.is_none() {
+            self.start_watcher();
+        }
+    }
+
+    /// Pick up a changed `auto_refresh_ms` by replacing a running watcher
+    pub(super) fn restart_watcher(&mut self) {
+        if self.watch_stop.is_some() {
+            self.start_watcher();
+        }
+    }
+
+    /// Poll the `.git` directory in the background and raise `pending_refresh` whenever it changes.
+    /// There is no file system notification dependency, so "watching" compares file modification times.
+    fn start_watcher(&mut self) {
+        self.stop_watcher();
+
+        let Some(watched) = Self::watched_paths() else {
+            return;
+        };
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let pending_refresh = Arc::clone(&self.pending_refresh);
+        let interval = Duration::from_millis(self.config.auto_refresh_ms);
+
+        thread::spawn(move || {
+            let mut last_fingerprint = Self::repo_fingerprint(&watched);
+
+            while !thread_stop.load(Ordering::Relaxed) {
+                thread::sleep(interval);
+
+                let fingerprint = Self::repo_fingerprint(&watched);
+                if fingerprint != last_fingerprint {
+                    pending_refresh.store(true, Ordering::Relaxed);
+                    last_fingerprint = fingerprint;
+                }
+            }
+        });
+
+        self.watch_stop = Some(stop);
+    }
+
+    fn stop_watcher(&mut self) {
+        if let Some(stop) = self.watch_stop.take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+        self.pending_refresh.store(false, Ordering::Relaxed);
+    }
+
+    /// HEAD and the index live in the (per-worktree) git dir; refs are shared through the common dir
+    fn watched_paths() -> Option<Vec<PathBuf>> {
+        let output = Command::new("git")
+            .args(&["rev-parse", "--path-format=absolute", "--git-dir", "--git-common-dir"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut lines = stdout.lines();
+        let git_dir = PathBuf::from(lines.next()?);
+        let common_dir = lines.next().map(PathBuf::from).unwrap_or_else(|| git_dir.clone());
+
+        Some(vec![
+            git_dir.join("HEAD"),
+            git_dir.join("index"),
+            common_dir.join("packed-refs"),
+            common_dir.join("refs"),
+        ])
+    }
+
+    fn repo_fingerprint(paths: &[PathBuf]) -> Fingerprint {
+        let mut fingerprint = (None, 0);
+        for path in paths {
+            Self::add_to_fingerprint(path, &mut fingerprint);
+        }
+        fingerprint
+    }
+
+    /// Walks directories (the refs tree) recursively; missing paths are skipped
+    fn add_to_fingerprint(path: &Path, fingerprint: &mut Fingerprint) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+
+        if let Ok(modified) = metadata.modified() {
+            fingerprint.0 = fingerprint.0.max(Some(modified));
+        }
+        fingerprint.1 += 1;
+
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(path) {
+                for entry in entries.flatten() {
+                    Self::add_to_fingerprint(&entry.path(), fingerprint);
+                }
+            }
+        }
+    }
+
+    /// Called every tick; refreshes once per batch of detected changes.
+    /// Waits while a dialog is open so a reload doesn't pull state out from under it.
+    pub fn check_pending_refresh(&mut self) {
+        if self.watch_mode && self.mode == AppMode::Normal && self.pending_refresh.swap(false, Ordering::Relaxed) {
+            // The app's own commands change .git too; don't replace the result they just reported
+            let shown_message = self.status_message.take().zip(self.status_message_time);
+            self.refresh();
+            match shown_message {
+                Some((message, time)) => {
+                    self.status_message = Some(message);
+                    self.status_message_time = Some(time);
+                }
+                None => self.set_status_message("(auto-refreshed)".to_string()),
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use super::App;
+use super::types::{AppMode, WorktreeEntry};
+use std::process::{Command, Stdio};
+
+impl App {
+    pub(super) fn cmd_show_worktrees(&mut self) -> Result<String, String> {
+        self.load_worktrees()?;
+        self.selected_worktree_idx = 0;
+        self.mode = AppMode::WorktreeView;
+        Ok(format!("{} worktree(s)", self.worktrees.len()))
+    }
+
+    fn load_worktrees(&mut self) -> Result<(), String> {
+        let output = Command::new("git")
+            .args(&["worktree", "list", "--porcelain"])
+            .output()
+            .map_err(|e| format!("Failed to execute git: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        // Records are blank-line separated: "worktree <path>", "HEAD <sha>", "branch refs/heads/<name>" or "detached"
+        self.worktrees = String::from_utf8_lossy(&output.stdout)
+            .split("\n\n")
+            .filter_map(|record| {
+                let mut entry = WorktreeEntry { path: String::new(), head: String::new(), branch: None };
+                for line in record.lines() {
+                    if let Some(path) = line.strip_prefix("worktree ") {
+                        entry.path = path.to_string();
+                    } else if let Some(head) = line.strip_prefix("HEAD ") {
+                        entry.head = head.to_string();
+                    } else if let Some(branch) = line.strip_prefix("branch ") {
+                        entry.branch = Some(branch.trim_start_matches("refs/heads/").to_string());
+                    }
+                }
+                (!entry.path.is_empty()).then_some(entry)
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    pub fn worktree_up(&mut self) {
+        if self.selected_worktree_idx > 0 {
+            self.selected_worktree_idx -= 1;
+        }
+    }
+
+    pub fn worktree_down(&mut self) {
+        if self.selected_worktree_idx + 1 < self.worktrees.len() {
+            self.selected_worktree_idx += 1;
+        }
+    }
+
+    pub fn close_worktree_view(&mut self) {
+        self.worktrees.clear();
+        self.selected_worktree_idx = 0;
+        self.mode = AppMode::Normal;
+    }
+
+    /// `n`: open the create form, pre-filled from the branch at the selected graph commit
+    pub fn start_create_worktree(&mut self) {
+        let branch = self.selected_commit_idx
+            .and_then(|idx| self.graph_nodes.get(idx))
+            .and_then(|node| self.branch_labels.get(&node.commit.id))
+            .and_then(|names| names.first().cloned())
+            .unwrap_or_default();
+
+        self.worktree_path_input = if branch.is_empty() {
+            String::new()
+        } else {
+            let repo = self.repo_name.as_deref().unwrap_or("repo");
+            format!("../{}-{}", repo, branch.replace('/', "-"))
+        };
+        self.worktree_branch_input = branch;
+        self.worktree_editing_path = false;
+        self.mode = AppMode::WorktreeCreate;
+    }
+
+    pub fn worktree_input_char(&mut self, c: char) {
+        self.input_insert(c);
+    }
+
+    pub fn worktree_input_backspace(&mut self) {
+        self.input_backspace();
+    }
+
+    pub fn worktree_switch_field(&mut self) {
+        self.worktree_editing_path = !self.worktree_editing_path;
+        self.input_cursor = 0;
+    }
+
+    pub fn cancel_create_worktree(&mut self) {
+        self.worktree_branch_input.clear();
+        self.worktree_path_input.clear();
+        self.mode = AppMode::WorktreeView;
+    }
+
+    pub fn submit_create_worktree(&mut self) {
+        let branch = self.worktree_branch_input.trim().to_string();
+        let path = self.worktree_path_input.trim().to_string();
+
+        if branch.is_empty() || path.is_empty() {
+            self.set_status_message("✗ Branch and path are both required".to_string());
+            return;
+        }
+
+        let output = Command::new("git")
+            .args(&["worktree", "add", &path, &branch])
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                self.cancel_create_worktree();
+                let _ = self.load_worktrees();
+                // Select the new worktree so `o` opens it straight away
+                let new_name = std::path::Path::new(&path).file_name().map(|n| n.to_string_lossy().to_string());
+                if let Some(idx) = self.worktrees.iter().position(|w| new_name.as_deref().is_some_and(|n| w.path.ends_with(n))) {
+                    self.selected_worktree_idx = idx;
+                }
+                self.set_status_message(format!("✓ Created worktree at {}", path));
+            }
+            Ok(output) => {
+                self.set_status_message(format!("✗ Error: {}", String::from_utf8_lossy(&output.stderr)));
+            }
+            Err(e) => {
+                self.set_status_message(format!("✗ Error: Failed to execute git: {}", e));
+            }
+        }
+    }
+
+    /// `o`: open the selected worktree in the platform's file manager
+    pub fn open_selected_worktree(&mut self) {
+        let Some(path) = self.worktrees.get(self.selected_worktree_idx).map(|w| w.path.clone()) else {
+            return;
+        };
+
+        let opener = if cfg!(target_os = "macos") {
+            "open"
+        } else if cfg!(windows) {
+            "explorer"
+        } else {
+            "xdg-open"
+        };
+
+        // Detached so the TUI keeps running and the opener's output doesn't land on the screen
+        let spawned = Command::new(opener)
+            .arg(&path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+
+        match spawned {
+            Ok(_) => self.set_status_message(format!("✓ Opened {}", path)),
+            Err(e) => self.set_status_message(format!("✗ Error: Failed to run {}: {}", opener, e)),
+        }
+    }
+}
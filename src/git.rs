@@ -1,10 +1,63 @@
-use git2::{Repository, Oid, Commit as GitCommit, DiffOptions, DiffFormat};
-use crate::graph::{Commit, CommitGraph, SyncStatus};
+use git2::{Repository, Oid, Commit as GitCommit, DiffOptions, DiffFindOptions, DiffFormat};
+use crate::graph::{Commit, CommitGraph, SignatureStatus, SyncStatus};
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 pub struct GitRepo {
     pub repo: Repository,
 }
 
+/// The next commits of the history for the graph, or why they couldn't be loaded
+pub type CommitBatch = Result<CommitGraph, String>;
+
+/// A `load_commits` walk kept open in a background thread, so each batch carries on where the
+/// previous one stopped instead of walking the earlier history again. Dropping it ends the thread.
+pub struct HistoryWalker {
+    requests: mpsc::Sender<(usize, Arc<Mutex<Option<CommitBatch>>>)>,
+}
+
+impl HistoryWalker {
+    /// Walk the history `load_commits(all_refs, first_parent, ..)` walks, past its first `offset` commits
+    pub fn start(all_refs: bool, first_parent: bool, offset: usize) -> Self {
+        let (requests, receiver) = mpsc::channel::<(usize, Arc<Mutex<Option<CommitBatch>>>)>();
+
+        thread::spawn(move || {
+            let repo = GitRepo::open_current_dir().map_err(|e| e.to_string());
+            let mut walk = repo.as_ref()
+                .map_err(Clone::clone)
+                .and_then(|repo| repo.history_walk(all_refs, first_parent).map_err(|e| e.to_string()));
+            let mut skip = offset;
+
+            for (limit, slot) in receiver {
+                let batch = match (&repo, &mut walk) {
+                    (Ok(repo), Ok(walk)) => repo.next_commits(walk, std::mem::take(&mut skip), Some(limit))
+                        .map_err(|e| e.to_string()),
+                    (Err(e), _) => Err(e.clone()),
+                    (_, Err(e)) => Err(e.clone()),
+                };
+                if let Ok(mut slot) = slot.lock() {
+                    *slot = Some(batch);
+                }
+            }
+        });
+
+        Self { requests }
+    }
+
+    /// Load the next `limit` commits into `slot`
+    pub fn request(&self, limit: usize, slot: Arc<Mutex<Option<CommitBatch>>>) {
+        if let Err(mpsc::SendError((_, slot))) = self.requests.send((limit, slot)) {
+            if let Ok(mut slot) = slot.lock() {
+                *slot = Some(Err("the history walk stopped".to_string()));
+            }
+        }
+    }
+}
+
 impl GitRepo {
     pub fn open(path: &str) -> Result<Self, git2::Error> {
         let repo = Repository::open(path)?;
@@ -16,17 +69,31 @@ impl GitRepo {
         Ok(Self { repo })
     }
 
-    pub fn load_commits(&self) -> Result<CommitGraph, git2::Error> {
-        let mut graph = CommitGraph::new();
+    /// Load the commit history. With `all_refs` every branch, remote and tag is walked
+    /// (like `git log --all`); otherwise only commits reachable from HEAD.
+    /// `first_parent` follows only first parents from HEAD (like `git log --first-parent`), ignoring `all_refs`.
+    /// Returns the first `limit` commits of the walk, newest first; a `None` limit walks to the end
+    /// of the history. `HistoryWalker` continues the same walk for later batches.
+    pub fn load_commits(&self, all_refs: bool, first_parent: bool, limit: Option<usize>) -> Result<CommitGraph, git2::Error> {
+        let mut revwalk = self.history_walk(all_refs, first_parent)?;
+        self.next_commits(&mut revwalk, 0, limit)
+    }
+
+    /// The walk behind `load_commits`. Its order is fixed, so batches taken from it line up without overlap.
+    fn history_walk(&self, all_refs: bool, first_parent: bool) -> Result<git2::Revwalk<'_>, git2::Error> {
         let mut revwalk = self.repo.revwalk()?;
 
-        // Walk all references (branches, tags, etc.) - this ensures we see all commits
-        // regardless of which branch is currently checked out
-        revwalk.push_glob("refs/heads/*")?;  // All local branches
-        revwalk.push_glob("refs/remotes/*")?;  // All remote branches
-        revwalk.push_glob("refs/tags/*")?;  // All tags
+        if first_parent {
+            revwalk.simplify_first_parent()?;
+        } else if all_refs {
+            // Walk all references (branches, tags, etc.) - this ensures we see all commits
+            // regardless of which branch is currently checked out
+            revwalk.push_glob("refs/heads/*")?;  // All local branches
+            revwalk.push_glob("refs/remotes/*")?;  // All remote branches
+            revwalk.push_glob("refs/tags/*")?;  // All tags
+        }
 
-        // Also push HEAD to ensure current position is included even if detached
+        // Always push HEAD to ensure current position is included even if detached
         if let Ok(head) = self.repo.head() {
             if let Some(target) = head.target() {
                 let _ = revwalk.push(target);
@@ -34,13 +101,17 @@ impl GitRepo {
         }
 
         revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+        Ok(revwalk)
+    }
 
-        for oid in revwalk {
+    /// The next `limit` commits of `revwalk` after skipping `skip`, as a graph of their own
+    fn next_commits(&self, revwalk: &mut git2::Revwalk<'_>, skip: usize, limit: Option<usize>) -> Result<CommitGraph, git2::Error> {
+        let mut graph = CommitGraph::new();
+
+        for oid in revwalk.skip(skip).take(limit.unwrap_or(usize::MAX)) {
             let oid = oid?;
             let git_commit = self.repo.find_commit(oid)?;
-
-            let commit = self.git_commit_to_commit(&git_commit)?;
-            graph.add_commit(commit);
+            graph.add_commit(self.git_commit_to_commit(&git_commit)?);
         }
 
         graph.build_graph();
@@ -59,6 +130,7 @@ impl GitRepo {
         let message = git_commit.message().unwrap_or("").to_string();
         let author = git_commit.author().name().unwrap_or("Unknown").to_string();
         let timestamp = git_commit.time().seconds();
+        let author_timestamp = git_commit.author().when().seconds();
 
         Ok(Commit {
             id,
@@ -68,9 +140,42 @@ impl GitRepo {
             message,
             author,
             timestamp,
+            author_timestamp,
         })
     }
 
+    /// `%G?` for every commit in `ids` (one per line) in one `git log` call, since git2 can't verify
+    /// signatures. Slow with many signed commits (gpg runs for each), so callers run it off the UI thread.
+    /// Empty if git can't be run, so the commits just show no badge.
+    pub fn load_signature_statuses(ids: &str) -> HashMap<String, SignatureStatus> {
+        let child = Command::new("git")
+            .args(&["log", "--no-walk=unsorted", "--stdin", "--format=%H %G?"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn();
+        let Ok(mut child) = child else {
+            return HashMap::new();
+        };
+
+        // git reads all of stdin before it starts writing, so this can't deadlock
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(ids.as_bytes());
+        }
+
+        let Ok(output) = child.wait_with_output() else {
+            return HashMap::new();
+        };
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let (id, code) = line.split_once(' ')?;
+                Some((id.to_string(), SignatureStatus::from_placeholder(code)?))
+            })
+            .collect()
+    }
+
     pub fn get_head_commit_id(&self) -> Result<String, git2::Error> {
         let head = self.repo.head()?;
         let commit = head.peel_to_commit()?;
@@ -87,6 +192,12 @@ impl GitRepo {
         }
     }
 
+    /// Basename of the working directory, or of the git dir for bare repositories
+    pub fn get_repo_name(&self) -> Option<String> {
+        let root = self.repo.workdir().unwrap_or_else(|| self.repo.path());
+        root.file_name().map(|name| name.to_string_lossy().to_string())
+    }
+
     pub fn get_branch_commit_id(&self, branch_name: &str) -> Result<String, git2::Error> {
         let reference = self.repo.find_reference(&format!("refs/heads/{}", branch_name))?;
         let commit = reference.peel_to_commit()?;
@@ -165,7 +276,8 @@ impl GitRepo {
         Ok("(unknown)".to_string())
     }
 
-    pub fn get_commit_diff(&self, commit_id: &str) -> Result<String, git2::Error> {
+    /// Diff of a commit against its first parent, with renames at or above `rename_threshold`% similarity detected
+    fn diff_against_first_parent(&self, commit_id: &str, rename_threshold: u8, context_lines: u32, ignore_whitespace: bool) -> Result<git2::Diff<'_>, git2::Error> {
         let oid = Oid::from_str(commit_id).map_err(|_| {
             git2::Error::from_str("Invalid commit ID")
         })?;
@@ -179,15 +291,28 @@ impl GitRepo {
             None
         };
 
-        let mut diff_output = Vec::new();
+        // Equivalent of `git diff -U<context_lines>` (plus `-w` when ignoring whitespace)
         let mut diff_opts = DiffOptions::new();
+        diff_opts.context_lines(context_lines).ignore_whitespace(ignore_whitespace);
 
-        let diff = if let Some(parent_tree) = parent_tree {
+        let mut diff = if let Some(parent_tree) = parent_tree {
             self.repo.diff_tree_to_tree(Some(&parent_tree), Some(&tree), Some(&mut diff_opts))?
         } else {
             self.repo.diff_tree_to_tree(None, Some(&tree), Some(&mut diff_opts))?
         };
 
+        // Equivalent of `git diff --find-renames=<threshold>%`
+        let mut find_opts = DiffFindOptions::new();
+        find_opts.renames(true).rename_threshold(rename_threshold.into());
+        diff.find_similar(Some(&mut find_opts))?;
+
+        Ok(diff)
+    }
+
+    pub fn get_commit_diff(&self, commit_id: &str, rename_threshold: u8, context_lines: u32, ignore_whitespace: bool) -> Result<String, git2::Error> {
+        let diff = self.diff_against_first_parent(commit_id, rename_threshold, context_lines, ignore_whitespace)?;
+        let mut diff_output = Vec::new();
+
         diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
             diff_output.extend_from_slice(line.content());
             true
@@ -196,6 +321,31 @@ impl GitRepo {
         Ok(String::from_utf8_lossy(&diff_output).to_string())
     }
 
+    /// Collects the first `max_lines` lines of a commit's diff, stopping early
+    /// (and returning what was gathered so far) once `cancelled` is set.
+    pub fn get_commit_diff_preview(&self, commit_id: &str, max_lines: usize, rename_threshold: u8, context_lines: u32, ignore_whitespace: bool, cancelled: &AtomicBool) -> Result<Vec<String>, git2::Error> {
+        let diff = self.diff_against_first_parent(commit_id, rename_threshold, context_lines, ignore_whitespace)?;
+        let mut lines = Vec::new();
+
+        // Returning false from the callback aborts the walk, which git2 reports as an error
+        let _ = diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
+            if cancelled.load(Ordering::Relaxed) || lines.len() >= max_lines {
+                return false;
+            }
+
+            let content = String::from_utf8_lossy(line.content());
+            let content = content.trim_end_matches('\n');
+            match line.origin() {
+                '+' | '-' | ' ' => lines.push(format!("{}{}", line.origin(), content)),
+                _ => lines.extend(content.lines().map(|l| l.to_string())),
+            }
+            true
+        });
+
+        lines.truncate(max_lines);
+        Ok(lines)
+    }
+
     pub fn get_sync_status(&self, _commit_id: &str) -> SyncStatus {
         // For now, return Synced as default
         // TODO: Implement actual local/remote comparison
@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
@@ -9,6 +10,30 @@ pub struct Commit {
     pub message: String,
     pub author: String,
     pub timestamp: i64,
+    pub author_timestamp: i64,
+}
+
+/// A commit's GPG signature, from git's `%G?` placeholder
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SignatureStatus {
+    Good,
+    Bad,
+    UnknownKey,
+    Unsigned,
+}
+
+impl SignatureStatus {
+    /// `G` good; `U`/`E`/`X`/`Y` valid or uncheckable but not trusted (unknown/missing/expired key);
+    /// `B`/`R` bad or revoked; `N` no signature
+    pub fn from_placeholder(code: &str) -> Option<Self> {
+        match code {
+            "G" => Some(SignatureStatus::Good),
+            "U" | "E" | "X" | "Y" => Some(SignatureStatus::UnknownKey),
+            "B" | "R" => Some(SignatureStatus::Bad),
+            "N" => Some(SignatureStatus::Unsigned),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +42,16 @@ pub struct GraphNode {
     pub column: usize,
     pub connections: Vec<Connection>,
     pub in_current_branch: bool,
+    /// Remote-tracking branches pointing here, e.g. `origin/main`
+    pub remote_refs: Vec<String>,
+}
+
+/// Two nodes are the same graph position when they show the same commit in the same lane;
+/// connections and ancestry flags are derived from that and ignored here.
+impl PartialEq for GraphNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.commit.id == other.commit.id && self.column == other.column
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -94,6 +129,92 @@ impl RailwayLayout {
     }
 }
 
+/// Order in which commits are laid out in the graph; named in snake_case in the config file
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitSort {
+    /// Keep each line of history together (like `git log --topo-order`)
+    Topological,
+    /// Newest author date first, parents never before children (like `--author-date-order`)
+    AuthorDate,
+    /// Newest committer date first, parents never before children (like `--date-order`)
+    CommitterDate,
+}
+
+impl CommitSort {
+    pub fn label(&self) -> &str {
+        match self {
+            CommitSort::Topological => "topo",
+            CommitSort::AuthorDate => "author-date",
+            CommitSort::CommitterDate => "committer-date",
+        }
+    }
+
+    /// Name used for the sort order in the config file
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            CommitSort::Topological => "topological",
+            CommitSort::AuthorDate => "author_date",
+            CommitSort::CommitterDate => "committer_date",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            CommitSort::Topological => CommitSort::AuthorDate,
+            CommitSort::AuthorDate => CommitSort::CommitterDate,
+            CommitSort::CommitterDate => CommitSort::Topological,
+        }
+    }
+}
+
+/// Period whose boundaries get a date separator line in the commit graph; named in snake_case in the config file
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateGrouping {
+    Off,
+    Day,
+    /// Weeks start on Monday
+    Week,
+    Month,
+}
+
+impl DateGrouping {
+    pub fn label(&self) -> &str {
+        match self {
+            DateGrouping::Off => "off",
+            DateGrouping::Day => "day",
+            DateGrouping::Week => "week",
+            DateGrouping::Month => "month",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            DateGrouping::Off => DateGrouping::Day,
+            DateGrouping::Day => DateGrouping::Week,
+            DateGrouping::Week => DateGrouping::Month,
+            DateGrouping::Month => DateGrouping::Off,
+        }
+    }
+
+    /// Separator text for the local-time period containing `timestamp`; equal labels mean the same period
+    pub fn period_label(&self, timestamp: i64) -> Option<String> {
+        use chrono::{Datelike, Local, TimeZone};
+
+        let date = Local.timestamp_opt(timestamp, 0).single()?.date_naive();
+        match self {
+            DateGrouping::Off => None,
+            DateGrouping::Day => Some(date.format("%a %d %b %Y").to_string()),
+            DateGrouping::Week => {
+                let monday = date - chrono::Duration::days(date.weekday().num_days_from_monday().into());
+                Some(monday.format("Week of %d %b %Y").to_string())
+            }
+            DateGrouping::Month => Some(date.format("%B %Y").to_string()),
+        }
+    }
+}
+
 pub struct CommitGraph {
     pub commits: HashMap<String, Commit>,
     pub nodes: Vec<GraphNode>,
@@ -113,6 +234,12 @@ impl CommitGraph {
         self.commits.insert(commit.id.clone(), commit);
     }
 
+    /// Add a later batch of commits (see `GitRepo::load_commits`) and link them to the ones already here
+    pub fn extend(&mut self, batch: CommitGraph) {
+        self.commits.extend(batch.commits);
+        self.build_graph();
+    }
+
     pub fn build_graph(&mut self) {
         // Build parent-child relationships
         let commit_ids: Vec<String> = self.commits.keys().cloned().collect();
@@ -131,6 +258,55 @@ impl CommitGraph {
         }
     }
 
+    /// Commits in the requested order, oldest-to-newest like `topological_sort`
+    pub fn sorted_commits(&self, sort: CommitSort) -> Vec<String> {
+        match sort {
+            CommitSort::Topological => self.topological_sort(),
+            CommitSort::AuthorDate => self.date_order_sort(|c| c.author_timestamp),
+            CommitSort::CommitterDate => self.date_order_sort(|c| c.timestamp),
+        }
+    }
+
+    /// Kahn's algorithm from the tips down, always emitting the newest ready commit.
+    /// A commit becomes ready once all of its children have been emitted.
+    fn date_order_sort(&self, date: impl Fn(&Commit) -> i64) -> Vec<String> {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        let mut remaining_children: HashMap<&str, usize> = self.commits.values()
+            .map(|c| (c.id.as_str(), c.children.iter().filter(|id| self.commits.contains_key(*id)).count()))
+            .collect();
+
+        // Newest date wins; on ties the smaller commit ID goes first for determinism
+        let mut ready: BinaryHeap<(i64, Reverse<&str>)> = self.commits.values()
+            .filter(|c| remaining_children.get(c.id.as_str()) == Some(&0))
+            .map(|c| (date(c), Reverse(c.id.as_str())))
+            .collect();
+
+        let mut result = Vec::with_capacity(self.commits.len());
+
+        while let Some((_, Reverse(commit_id))) = ready.pop() {
+            result.push(commit_id.to_string());
+
+            if let Some(commit) = self.commits.get(commit_id) {
+                for parent_id in &commit.parents {
+                    if let Some(count) = remaining_children.get_mut(parent_id.as_str()) {
+                        *count -= 1;
+                        if *count == 0 {
+                            if let Some(parent) = self.commits.get(parent_id) {
+                                ready.push((date(parent), Reverse(parent.id.as_str())));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Built newest-first; callers expect oldest-to-newest
+        result.reverse();
+        result
+    }
+
     pub fn topological_sort(&self) -> Vec<String> {
         // Kahn's algorithm for topological sort
         let mut in_degree: HashMap<String, usize> = HashMap::new();
@@ -2,4 +2,5 @@ pub mod app;
 pub mod git;
 pub mod graph;
 pub mod renderer;
+pub mod theme;
 pub mod ui;
@@ -2,13 +2,17 @@ mod app;
 mod git;
 mod graph;
 mod renderer;
+mod theme;
 mod ui;
 
-use app::App;
+use app::{App, Config};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers,
+        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen, LeaveAlternateScreen, SetTitle},
 };
 use ratatui::{
     backend::CrosstermBackend,
@@ -16,19 +20,71 @@ use ratatui::{
 };
 use std::io;
 
+/// `--limit <N>` / `--limit=<N>`: load at most N commits at first, LoadMore adds N more each time
+fn parse_commit_limit(args: impl Iterator<Item = String>) -> Result<Option<usize>, String> {
+    let mut args = args.skip(1);
+    let mut limit = None;
+
+    while let Some(arg) = args.next() {
+        let value = if arg == "--limit" {
+            args.next().ok_or("--limit needs a number of commits")?
+        } else if let Some(value) = arg.strip_prefix("--limit=") {
+            value.to_string()
+        } else {
+            return Err(format!("Unknown argument: {}", arg));
+        };
+
+        match value.parse::<usize>() {
+            Ok(n) if n > 0 => limit = Some(n),
+            _ => return Err(format!("Invalid --limit value: {}", value)),
+        }
+    }
+
+    Ok(limit)
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Parse arguments before taking over the terminal so errors print normally
+    let commit_limit = match parse_commit_limit(std::env::args()) {
+        Ok(limit) => limit,
+        Err(e) => {
+            eprintln!("Error: {}\nUsage: git_manager [--limit <N>]", e);
+            std::process::exit(2);
+        }
+    };
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+    };
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Lets terminals that support it report Ctrl+Enter (commit message submit) apart from Enter
+    let keyboard_enhancement = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhancement {
+        execute!(stdout, PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES))?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run
-    let mut app = App::new();
+    let mut app = App::new(config);
+    // --limit takes precedence over the config file's commit_limit
+    if commit_limit.is_some() {
+        app.commit_limit = commit_limit;
+        app.commit_limit_step = commit_limit;
+    }
     let res = run_app(&mut terminal, &mut app);
 
     // Restore terminal
+    if keyboard_enhancement {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -51,6 +107,8 @@ fn run_app<B: ratatui::backend::Backend>(
     // Initialize app
     app.init()?;
 
+    let mut window_title = String::new();
+
     loop {
         // Clear expired status messages
         app.clear_expired_status_message();
@@ -58,45 +116,222 @@ fn run_app<B: ratatui::backend::Backend>(
         // Check git validation results (runs once when complete)
         app.check_validation_results();
 
+        // Pick up the background diff load for the hover preview
+        app.check_diff_preview();
+
+        // Pick up the background total commit count
+        app.check_commit_count();
+
+        // Fetch the next batch of history when scrolled near the end, and merge finished ones
+        app.check_load_more_commits();
+
+        // Pick up the background per-commit changed file counts
+        app.check_file_counts();
+
+        // Pick up background commit signature checks
+        app.check_signature_statuses();
+
+        // Pick up the statistics panel's background results
+        app.check_repo_stats();
+
+        // Pick up background tag signature checks
+        app.check_tag_verification();
+
+        // Pick up the remote tag list for the delete-tag warning
+        app.check_origin_tags();
+
+        // Reload when the watcher saw the repository change
+        app.check_pending_refresh();
+
+        // Only touch the title when branch, repo or dialog changed
+        let title = app.window_title();
+        if title != window_title {
+            execute!(io::stdout(), SetTitle(&title))?;
+            window_title = title;
+        }
+
+        // An external editor drew over the screen; make ratatui repaint everything
+        if app.needs_full_redraw {
+            terminal.clear()?;
+            app.needs_full_redraw = false;
+        }
+
         terminal.draw(|f| ui::draw(f, app))?;
 
-        if event::poll(std::time::Duration::from_millis(100))? {
+        // Each drawn frame is one tick of the staging flash
+        app.tick_staging_animation();
+
+        // Watch mode polls less eagerly since refreshes are driven by the watcher
+        let poll_timeout = if app.watch_mode { 500 } else { 100 };
+        if event::poll(std::time::Duration::from_millis(poll_timeout))? {
             if let Event::Key(key) = event::read()? {
                 use app::AppMode;
 
+                let mode_before = app.mode;
+
                 match app.mode {
                     AppMode::Normal => {
                         use app::FocusedPane;
 
+                        let keys = app.config.keys.clone();
                         match key.code {
-                            KeyCode::F(1) => {
+                            _ if keys.help.matches(&key) => {
                                 app.toggle_help();
                             }
-                            KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            _ if keys.quit.matches(&key) => {
                                 app.quit();
                             }
-                            KeyCode::Char('r') => {
+                            _ if keys.toggle_watch.matches(&key) => {
+                                app.toggle_watch_mode();
+                            }
+                            _ if keys.refresh_status.matches(&key) => {
+                                app.refresh_status();
+                            }
+                            _ if keys.goto_hash.matches(&key) => {
+                                app.open_goto_hash_dialog();
+                            }
+                            _ if keys.refresh.matches(&key) => {
                                 app.refresh();
                             }
-                            KeyCode::Tab => {
+                            _ if keys.repeat_command.matches(&key) => {
+                                app.repeat_last_command();
+                            }
+                            // `/` searches whichever of the graph or the diff is focused
+                            _ if (keys.diff_search.matches(&key) || keys.search.matches(&key)) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.start_diff_search();
+                            }
+                            _ if keys.next_match.matches(&key) && app.focused_pane == FocusedPane::CommitDetails && !app.diff_search_results.is_empty() => {
+                                app.next_diff_search_result();
+                            }
+                            _ if keys.prev_match.matches(&key) && app.focused_pane == FocusedPane::CommitDetails && !app.diff_search_results.is_empty() => {
+                                app.prev_diff_search_result();
+                            }
+                            _ if keys.commit_tree.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.open_commit_tree();
+                            }
+                            _ if keys.cycle_parent.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.cycle_details_parent();
+                            }
+                            _ if keys.prev_file.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.jump_to_diff_file(false);
+                            }
+                            _ if keys.next_file.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.jump_to_diff_file(true);
+                            }
+                            _ if keys.toggle_file_list.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.toggle_details_files();
+                            }
+                            _ if keys.staged_diff.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.toggle_status_file_diff(app::FileDiffMode::Staged);
+                            }
+                            _ if keys.unstaged_diff.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.toggle_status_file_diff(app::FileDiffMode::Unstaged);
+                            }
+                            _ if keys.more_context.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.change_diff_context(true);
+                            }
+                            _ if keys.less_context.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.change_diff_context(false);
+                            }
+                            _ if keys.word_diff.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.toggle_word_diff();
+                            }
+                            _ if keys.ignore_whitespace.matches(&key) && app.focused_pane == FocusedPane::CommitDetails => {
+                                app.toggle_ignore_whitespace();
+                            }
+                            _ if keys.discard_file.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.request_discard_file_changes();
+                            }
+                            _ if keys.file_history.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.open_file_history();
+                            }
+                            _ if keys.blame_file.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.open_blame_view();
+                            }
+                            _ if keys.edit_file.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.edit_selected_file();
+                            }
+                            _ if keys.stage_patch.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.stage_selected_file_patch();
+                            }
+                            _ if keys.stage_all.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.request_stage_all(app::GitCommand::StageAll);
+                            }
+                            _ if keys.unstage_all.matches(&key) && app.focused_pane == FocusedPane::GitStatus => {
+                                app.request_stage_all(app::GitCommand::UnstageAll);
+                            }
+                            _ if keys.show_all_branches.matches(&key) => {
+                                app.toggle_show_all_branches();
+                            }
+                            _ if keys.first_parent.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.toggle_first_parent_only();
+                            }
+                            _ if keys.cycle_sort.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.cycle_commit_sort();
+                            }
+                            _ if keys.search.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.start_search();
+                            }
+                            _ if keys.tag_sort.matches(&key) && app.focused_pane == FocusedPane::TagList => {
+                                app.toggle_tag_sort();
+                            }
+                            _ if keys.toggle_compact.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.toggle_compact_graph();
+                            }
+                            _ if keys.date_grouping.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.cycle_date_grouping();
+                            }
+                            _ if keys.toggle_reviewed.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.toggle_selected_reviewed();
+                            }
+                            _ if keys.jump_to_head.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.jump_to_head();
+                            }
+                            _ if keys.yank_hash.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.yank_commit_hash();
+                            }
+                            _ if keys.fixup.matches(&key) && app.focused_pane == FocusedPane::CommitGraph => {
+                                app.request_fixup();
+                            }
+                            _ if keys.reflog.matches(&key) => {
+                                app.open_reflog();
+                            }
+                            _ if keys.repo_stats.matches(&key) => {
+                                app.open_repo_stats();
+                            }
+                            _ if keys.next_pane.matches(&key) => {
                                 app.next_pane();
                             }
-                            KeyCode::Up => {
+                            _ if keys.move_up.matches(&key) => {
                                 match app.focused_pane {
                                     FocusedPane::CommitGraph => app.move_selection_up(),
                                     FocusedPane::GitActions => app.command_up(),
                                     FocusedPane::CommitDetails => app.details_scroll_up(),
                                     FocusedPane::GitStatus => app.file_up(),
+                                    FocusedPane::TagList => app.tag_up(),
                                 }
                             }
-                            KeyCode::Down => {
+                            _ if keys.move_down.matches(&key) => {
                                 match app.focused_pane {
                                     FocusedPane::CommitGraph => app.move_selection_down(),
                                     FocusedPane::GitActions => app.command_down(),
                                     FocusedPane::CommitDetails => app.details_scroll_down(),
                                     FocusedPane::GitStatus => app.file_down(),
+                                    FocusedPane::TagList => app.tag_down(),
                                 }
                             }
+                            _ if keys.page_up.matches(&key) => {
+                                app.page_scroll(false);
+                            }
+                            _ if keys.page_down.matches(&key) => {
+                                app.page_scroll(true);
+                            }
+                            KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) => {
+                                app.move_focused_pane(-1);
+                            }
+                            KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) => {
+                                app.move_focused_pane(1);
+                            }
                             KeyCode::Left => {
                                 if app.focused_pane == FocusedPane::CommitDetails {
                                     app.details_scroll_left();
@@ -107,24 +342,33 @@ fn run_app<B: ratatui::backend::Backend>(
                                     app.details_scroll_right();
                                 }
                             }
-                            KeyCode::Enter => {
+                            _ if keys.select.matches(&key) => {
                                 match app.focused_pane {
-                                    FocusedPane::CommitGraph => app.load_current_diff(),
+                                    FocusedPane::CommitGraph => {
+                                        app.cancel_diff_preview();
+                                        app.force_load_current_diff();
+                                    }
                                     FocusedPane::GitActions => app.execute_selected_command(),
                                     FocusedPane::CommitDetails => {
-                                        // Toggle fullscreen details pane
-                                        app.details_expanded = !app.details_expanded;
+                                        // Jump to a highlighted merge parent, otherwise toggle fullscreen details pane
+                                        if !app.goto_details_parent() {
+                                            app.details_expanded = !app.details_expanded;
+                                        }
                                     }
                                     FocusedPane::GitStatus => {
                                         app.open_file_diff_view();
                                     }
+                                    FocusedPane::TagList => app.goto_selected_tag(),
                                 }
                             }
-                            KeyCode::Char(' ') => {
+                            _ if keys.toggle_stage.matches(&key) => {
                                 if app.focused_pane == FocusedPane::GitStatus {
                                     app.toggle_file_staging();
                                 }
                             }
+                            KeyCode::Esc if app.focused_pane == FocusedPane::CommitDetails && !app.diff_search_query.is_empty() => {
+                                app.close_diff_search();
+                            }
                             KeyCode::Esc => {
                                 // Exit fullscreen mode if active
                                 if app.details_expanded {
@@ -136,10 +380,16 @@ fn run_app<B: ratatui::backend::Backend>(
                     }
                     AppMode::Confirm => {
                         match key.code {
-                            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                            KeyCode::Char(c) if c.eq_ignore_ascii_case(&app.config.confirm_key) => {
                                 app.confirm_command();
                             }
-                            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                            KeyCode::Char(c) if c.eq_ignore_ascii_case(&app.config.cancel_key) => {
+                                app.cancel_command();
+                            }
+                            KeyCode::Char('e') | KeyCode::Char('E') => {
+                                app.toggle_cherry_pick_edit();
+                            }
+                            KeyCode::Esc => {
                                 app.cancel_command();
                             }
                             _ => {}
@@ -147,9 +397,16 @@ fn run_app<B: ratatui::backend::Backend>(
                     }
                     AppMode::CommitMessage => {
                         match key.code {
-                            KeyCode::Enter => {
+                            // Alt+Enter for terminals that can't report Ctrl+Enter
+                            KeyCode::Enter if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
                                 app.submit_commit_message();
                             }
+                            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.toggle_commit_sign_off();
+                            }
+                            KeyCode::Enter => {
+                                app.commit_message_newline();
+                            }
                             KeyCode::Esc => {
                                 app.cancel_commit_message();
                             }
@@ -159,6 +416,35 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.commit_message_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::MergeMessage => {
+                        match key.code {
+                            KeyCode::Enter if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.submit_merge_message();
+                            }
+                            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.submit_merge_message();
+                            }
+                            KeyCode::Enter => {
+                                app.commit_message_newline();
+                            }
+                            KeyCode::Esc => {
+                                app.abort_merge_message();
+                            }
+                            KeyCode::Backspace => {
+                                app.commit_message_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.commit_message_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -176,6 +462,69 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.branch_name_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::RenameBranch => {
+                        match key.code {
+                            KeyCode::Enter => {
+                                app.submit_rename_branch();
+                            }
+                            KeyCode::Esc => {
+                                app.cancel_rename_branch();
+                            }
+                            KeyCode::Backspace => {
+                                app.branch_name_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.branch_name_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::TagName => {
+                        match key.code {
+                            KeyCode::Enter => {
+                                app.submit_tag_name();
+                            }
+                            KeyCode::Esc => {
+                                app.cancel_tag_name();
+                            }
+                            KeyCode::Backspace => {
+                                app.tag_name_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.tag_name_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::GoToHash => {
+                        match key.code {
+                            KeyCode::Enter => {
+                                app.submit_goto_hash();
+                            }
+                            KeyCode::Esc => {
+                                app.cancel_goto_hash();
+                            }
+                            KeyCode::Backspace => {
+                                app.goto_hash_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.goto_hash_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -204,6 +553,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Down => {
                                 app.delete_branch_selection_down();
                             }
+                            KeyCode::Char(' ') => {
+                                app.toggle_delete_branch_mark();
+                            }
                             KeyCode::Enter => {
                                 app.select_branch_to_delete();
                             }
@@ -227,6 +579,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.config_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -244,6 +599,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.config_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -261,6 +619,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.remote_host_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -278,6 +639,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.squash_count_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -295,6 +659,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.reword_message_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -332,6 +699,9 @@ fn run_app<B: ratatui::backend::Backend>(
                             KeyCode::Char(c) => {
                                 app.assign_branch_name_input_char(c);
                             }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
                             _ => {}
                         }
                     }
@@ -355,9 +725,435 @@ fn run_app<B: ratatui::backend::Backend>(
                             _ => {}
                         }
                     }
+                    AppMode::Search => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_search();
+                            }
+                            KeyCode::Enter if app.search_editing => {
+                                app.finish_search_input();
+                            }
+                            KeyCode::Backspace if app.search_editing => {
+                                app.search_backspace();
+                            }
+                            KeyCode::Char(c) if app.search_editing => {
+                                app.search_input_char(c);
+                            }
+                            KeyCode::Char('n') => {
+                                app.next_search_result();
+                            }
+                            KeyCode::Char('N') => {
+                                app.prev_search_result();
+                            }
+                            KeyCode::Char('/') => {
+                                app.start_search();
+                            }
+                            KeyCode::Up => {
+                                app.move_selection_up();
+                            }
+                            KeyCode::Down => {
+                                app.move_selection_down();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::DiffSearch => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.close_diff_search();
+                            }
+                            KeyCode::Enter => {
+                                app.finish_diff_search();
+                            }
+                            KeyCode::Backspace => {
+                                app.diff_search_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.diff_search_input_char(c);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::YankedHash => {
+                        if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                            app.close_yanked_hash();
+                        }
+                    }
+                    AppMode::RebaseTodo => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_rebase_todo();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.rebase_todo_up();
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.rebase_todo_down();
+                            }
+                            KeyCode::Char(' ') => {
+                                app.cycle_rebase_todo_action();
+                            }
+                            KeyCode::Enter => {
+                                app.submit_rebase_todo();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::RebaseOntoNewBase | AppMode::RebaseOntoUpstream => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_rebase_onto();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.move_selection_up();
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.move_selection_down();
+                            }
+                            KeyCode::PageUp => {
+                                app.page_scroll(false);
+                            }
+                            KeyCode::PageDown => {
+                                app.page_scroll(true);
+                            }
+                            KeyCode::Enter => {
+                                app.rebase_onto_pick();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::Reflog => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_reflog();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.reflog_up();
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.reflog_down();
+                            }
+                            KeyCode::Enter => {
+                                app.reflog_jump();
+                            }
+                            KeyCode::Char('c') => {
+                                app.reflog_create_branch();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::BlameView => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_blame_view();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.blame_up();
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.blame_down();
+                            }
+                            KeyCode::PageUp => {
+                                app.blame_page(false, terminal.size()?.height.saturating_sub(4) as usize);
+                            }
+                            KeyCode::PageDown => {
+                                app.blame_page(true, terminal.size()?.height.saturating_sub(4) as usize);
+                            }
+                            KeyCode::Enter => {
+                                app.blame_jump();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::FileHistory => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_file_history();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.file_history_up();
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.file_history_down();
+                            }
+                            KeyCode::PageUp => {
+                                app.file_history_scroll_diff(false, 10);
+                            }
+                            KeyCode::PageDown => {
+                                app.file_history_scroll_diff(true, 10);
+                            }
+                            KeyCode::Enter => {
+                                app.file_history_jump();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::ConflictResolution => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_conflict_resolution();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.conflict_up();
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.conflict_down();
+                            }
+                            KeyCode::Char('e') => {
+                                app.edit_selected_conflict();
+                            }
+                            KeyCode::Char('a') => {
+                                app.stage_selected_conflict();
+                            }
+                            KeyCode::Char('c') => {
+                                app.continue_conflict_operation();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::WorktreeView => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_worktree_view();
+                            }
+                            KeyCode::Up => {
+                                app.worktree_up();
+                            }
+                            KeyCode::Down => {
+                                app.worktree_down();
+                            }
+                            KeyCode::Char('n') => {
+                                app.start_create_worktree();
+                            }
+                            KeyCode::Char('o') => {
+                                app.open_selected_worktree();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::WorktreeCreate => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_create_worktree();
+                            }
+                            KeyCode::Enter => {
+                                app.submit_create_worktree();
+                            }
+                            KeyCode::Tab => {
+                                app.worktree_switch_field();
+                            }
+                            KeyCode::Backspace => {
+                                app.worktree_input_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.worktree_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::PushTarget => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_push_target();
+                            }
+                            KeyCode::Enter => {
+                                app.submit_push_target();
+                            }
+                            KeyCode::Tab => {
+                                app.push_target_switch_field();
+                            }
+                            KeyCode::Backspace => {
+                                app.push_target_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.push_target_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::AddRemote | AppMode::RenameRemote => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_remote_dialog();
+                            }
+                            KeyCode::Enter if app.mode == AppMode::AddRemote => {
+                                app.submit_add_remote();
+                            }
+                            KeyCode::Enter => {
+                                app.submit_rename_remote();
+                            }
+                            KeyCode::Tab => {
+                                app.remote_switch_field();
+                            }
+                            KeyCode::Backspace => {
+                                app.remote_input_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.remote_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::SelectRemoteToRemove => {
+                        match key.code {
+                            KeyCode::Up => {
+                                app.remote_selection_up();
+                            }
+                            KeyCode::Down => {
+                                app.remote_selection_down();
+                            }
+                            KeyCode::Enter => {
+                                app.select_remote_to_remove();
+                            }
+                            KeyCode::Esc => {
+                                app.cancel_remote_selection();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::SetUpstream => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_set_upstream();
+                            }
+                            KeyCode::Enter => {
+                                app.submit_set_upstream();
+                            }
+                            KeyCode::Tab => {
+                                app.set_upstream_switch_field();
+                            }
+                            KeyCode::Backspace => {
+                                app.set_upstream_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.set_upstream_input_char(c);
+                            }
+                            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End | KeyCode::Delete => {
+                                app.input_cursor_key(key.code);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::QuickLog => {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.close_quick_log();
+                            }
+                            KeyCode::Enter => {
+                                app.quick_log_jump();
+                            }
+                            KeyCode::Up => {
+                                app.quick_log_up();
+                            }
+                            KeyCode::Down => {
+                                app.quick_log_down();
+                            }
+                            KeyCode::Backspace => {
+                                app.quick_log_backspace();
+                            }
+                            KeyCode::Char(c) => {
+                                app.quick_log_input_char(c);
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::HooksView => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_hooks_view();
+                            }
+                            KeyCode::Up => {
+                                app.hook_up();
+                            }
+                            KeyCode::Down => {
+                                app.hook_down();
+                            }
+                            KeyCode::Char(' ') => {
+                                app.toggle_selected_hook();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::StashView => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_stash_view();
+                            }
+                            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.compare_selected_stash();
+                            }
+                            KeyCode::Up => {
+                                app.stash_up();
+                            }
+                            KeyCode::Down => {
+                                app.stash_down();
+                            }
+                            KeyCode::Char('v') => {
+                                app.show_selected_stash_diff();
+                            }
+                            KeyCode::Char(' ') => {
+                                app.apply_selected_stash();
+                            }
+                            KeyCode::Enter => {
+                                app.pop_selected_stash();
+                            }
+                            KeyCode::Char('D') => {
+                                app.request_drop_selected_stash();
+                            }
+                            KeyCode::PageUp => {
+                                app.details_scroll_up();
+                            }
+                            KeyCode::PageDown => {
+                                app.details_scroll_down();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::CommitTree => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => {
+                                app.close_commit_tree();
+                            }
+                            KeyCode::Up => {
+                                app.tree_up();
+                            }
+                            KeyCode::Down => {
+                                app.tree_down();
+                            }
+                            KeyCode::Enter => {
+                                app.open_tree_file_diff();
+                            }
+                            _ => {}
+                        }
+                    }
+                    AppMode::RepoStats => {
+                        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                            app.close_repo_stats();
+                        }
+                    }
+                    AppMode::WorkingTreeSummary => {
+                        match key.code {
+                            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                                app.close_working_tree_summary();
+                            }
+                            _ => {}
+                        }
+                    }
                     AppMode::Help => {
                         match key.code {
-                            KeyCode::F(1) | KeyCode::Esc | KeyCode::Char('q') => {
+                            _ if app.config.keys.help.matches(&key) => {
+                                app.toggle_help();
+                            }
+                            KeyCode::Esc | KeyCode::Char('q') => {
                                 app.toggle_help();
                             }
                             KeyCode::Up => {
@@ -370,6 +1166,11 @@ fn run_app<B: ratatui::backend::Backend>(
                         }
                     }
                 }
+
+                // Every text input opens with the cursor at the end
+                if app.mode != mode_before {
+                    app.input_cursor = 0;
+                }
             }
         }
 
@@ -1,6 +1,8 @@
 use crate::graph::{GraphNode, Connection, SyncStatus};
+use crate::theme::Theme;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GlyphType {
@@ -17,14 +19,39 @@ pub enum GlyphType {
     Cross,
 }
 
+/// Heatmap color for a commit's age: green for today, yellow this week, orange this month, gray after that
+pub fn age_to_color(age_secs: i64, theme: &Theme) -> Color {
+    const DAY: i64 = 24 * 60 * 60;
+
+    match age_secs {
+        a if a < DAY => theme.age_day_fg,
+        a if a < 7 * DAY => theme.age_week_fg,
+        a if a < 28 * DAY => theme.age_month_fg,
+        _ => theme.age_old_fg,
+    }
+}
+
+/// Fixed palette cycled by column so a lane keeps the same color wherever it is referenced
+pub fn lane_color(column: usize, theme: &Theme) -> Color {
+    theme.lane_colors[column % theme.lane_colors.len()]
+}
+
 pub struct Renderer {
     pub head_commit_id: Option<String>,
+    theme: Theme,
+    /// Lane line color per commit author (empty: lines use the sync status color)
+    pub author_colors: HashMap<String, Color>,
+    /// Color of the author owning each lane on the row being rendered
+    lane_colors: HashMap<usize, Color>,
 }
 
 impl Renderer {
-    pub fn new() -> Self {
+    pub fn new(theme: Theme) -> Self {
         Self {
             head_commit_id: None,
+            theme,
+            author_colors: HashMap::new(),
+            lane_colors: HashMap::new(),
         }
     }
 
@@ -72,19 +99,42 @@ impl Renderer {
     pub fn commit_style(&self, sync: SyncStatus, _on_ancestry_path: bool, not_in_current_branch: bool) -> Style {
         // Grey out commits not in current branch's history
         if not_in_current_branch {
-            return Style::default().fg(Color::DarkGray);
+            return Style::default().fg(self.theme.dim_fg);
         }
 
         let base_color = match sync {
-            SyncStatus::Synced => Color::White,
-            SyncStatus::LocalOnly => Color::Green,
-            SyncStatus::RemoteOnly => Color::Red,
-            SyncStatus::Diverged => Color::Yellow,
+            SyncStatus::Synced => self.theme.commit_synced_fg,
+            SyncStatus::LocalOnly => self.theme.commit_local_fg,
+            SyncStatus::RemoteOnly => self.theme.commit_remote_fg,
+            SyncStatus::Diverged => self.theme.commit_diverged_fg,
         };
 
         Style::default().fg(base_color)
     }
 
+    /// Lane colors for the next row rendered, keyed by column
+    pub fn set_lane_colors(&mut self, lane_colors: HashMap<usize, Color>) {
+        self.lane_colors = lane_colors;
+    }
+
+    /// `commit_style` in the color of the node's author; commits off the current branch stay dimmed
+    fn node_style(&self, node: &GraphNode, sync: SyncStatus, on_ancestry_path: bool, not_in_current_branch: bool) -> Style {
+        let style = self.commit_style(sync, on_ancestry_path, not_in_current_branch);
+        match self.author_colors.get(&node.commit.author) {
+            Some(&color) if !not_in_current_branch => style.fg(color),
+            _ => style,
+        }
+    }
+
+    /// `commit_style` in the color of the author owning `column` on this row
+    fn lane_style(&self, column: usize, sync: SyncStatus, on_ancestry_path: bool, not_in_current_branch: bool) -> Style {
+        let style = self.commit_style(sync, on_ancestry_path, not_in_current_branch);
+        match self.lane_colors.get(&column) {
+            Some(&color) if !not_in_current_branch => style.fg(color),
+            _ => style,
+        }
+    }
+
     pub fn render_node_row(
         &self,
         node: &GraphNode,
@@ -98,7 +148,15 @@ impl Renderer {
         let mut spans = Vec::new();
         let is_head = self.head_commit_id.as_ref() == Some(&node.commit.id);
 
-        let current_node_style = self.commit_style(sync_status, on_ancestry_path, not_in_current_branch);
+        let current_node_style = self.node_style(node, sync_status, on_ancestry_path, not_in_current_branch);
+
+        // The commit marker itself carries the age heatmap; commits off the current branch stay dimmed
+        let glyph_style = if not_in_current_branch {
+            current_node_style
+        } else {
+            let age_secs = chrono::Utc::now().timestamp() - node.commit.timestamp;
+            current_node_style.fg(age_to_color(age_secs, &self.theme))
+        };
 
         // Get columns being merged in (for merge commits)
         let merge_sources: Vec<usize> = node.connections.iter()
@@ -106,6 +164,8 @@ impl Renderer {
             .collect();
 
         let is_merge = !merge_sources.is_empty();
+        let nearest_merge_source = merge_sources.iter().copied().min().unwrap_or(node.column).max(node.column);
+        let merge_crossing = self.render_crossing(node.column, nearest_merge_source, active_columns);
 
         for col in 0..width {
             // Determine the style for this column
@@ -114,7 +174,7 @@ impl Renderer {
                 current_node_style
             } else if let Some(&in_current_branch) = col_to_in_current_branch.get(&col) {
                 // Use the pre-calculated ancestry status for this column
-                self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                self.lane_style(col, sync_status, on_ancestry_path, !in_current_branch)
             } else {
                 // Default to current node's style for non-active columns
                 current_node_style
@@ -130,22 +190,19 @@ impl Renderer {
 
                 // If this is a merge commit, add merge indicator after the commit node
                 if is_merge {
-                    spans.push(Span::styled(format!("{}", glyph), current_node_style));
+                    spans.push(Span::styled(format!("{}", glyph), glyph_style));
                     spans.push(Span::styled("─".to_string(), current_node_style));
                 } else {
-                    spans.push(Span::styled(format!("{} ", glyph), current_node_style));
+                    spans.push(Span::styled(glyph.to_string(), glyph_style));
+                    spans.push(Span::raw(" "));
                 }
             } else if is_merge && merge_sources.contains(&col) {
                 // For merge commits, show the merge connection on the node row
                 spans.push(Span::styled("╮ ".to_string(), current_node_style));
-            } else if is_merge && col > node.column && col < *merge_sources.iter().min().unwrap_or(&col) {
-                // Horizontal line connecting to merge source
-                // Check if there's a vertical line at this position - if so, use cross
-                if active_columns.contains(&col) && !merge_sources.contains(&col) {
-                    spans.push(Span::styled("│─".to_string(), current_node_style));
-                } else {
-                    spans.push(Span::styled("──".to_string(), current_node_style));
-                }
+            } else if is_merge && col > node.column && col < nearest_merge_source {
+                // Horizontal line connecting to merge source, crossing any active lanes on the way
+                let crossing_span = merge_crossing[col - node.column - 1].clone();
+                spans.push(crossing_span.style(current_node_style));
             } else if active_columns.contains(&col) && !merge_sources.contains(&col) {
                 // Render vertical line for active lane with per-column styling
                 let glyph = self.select_glyph(GlyphType::Vertical, false, false);
@@ -158,6 +215,22 @@ impl Renderer {
         Line::from(spans)
     }
 
+    /// Horizontal run for the columns strictly between `from_col` and `to_col`.
+    /// Active vertical lanes on the way are drawn as crossings (`┼`) rather than being overwritten.
+    pub fn render_crossing(&self, from_col: usize, to_col: usize, active_cols: &[usize]) -> Vec<Span<'static>> {
+        let (start, end) = if from_col < to_col { (from_col, to_col) } else { (to_col, from_col) };
+
+        ((start + 1)..end)
+            .map(|col| {
+                if active_cols.contains(&col) {
+                    Span::raw("┼─")
+                } else {
+                    Span::raw("──")
+                }
+            })
+            .collect()
+    }
+
     pub fn render_edge_row(
         &self,
         current_node: &GraphNode,
@@ -171,7 +244,7 @@ impl Renderer {
         not_in_current_branch: bool,
     ) -> Line<'static> {
         let mut spans = Vec::new();
-        let current_node_style = self.commit_style(sync_status, on_ancestry_path, not_in_current_branch);
+        let current_node_style = self.node_style(current_node, sync_status, on_ancestry_path, not_in_current_branch);
 
         // Check connections from both nodes:
         // - next_node's BranchTo: next branches from current to its own column
@@ -268,7 +341,7 @@ impl Renderer {
                     for c in (current_node.column + 1)..target_col {
                         // Check if there's a vertical line or bend - if so, connect through it
                         if col_chars[c] == "│ " {
-                            col_chars[c] = "│─".to_string();
+                            col_chars[c] = "┼─".to_string();
                         } else if col_chars[c] == "╯ " {
                             col_chars[c] = "╯─".to_string();
                         } else {
@@ -304,7 +377,7 @@ impl Renderer {
                             // Between target and current
                             if col_chars[c] == "│ " {
                                 // Vertical line crossing - use cross
-                                col_chars[c] = "│─".to_string();
+                                col_chars[c] = "┼─".to_string();
                             } else if col_chars[c] == "╯ " {
                                 // Bend from another merge - connect through it
                                 col_chars[c] = "╯─".to_string();
@@ -348,14 +421,14 @@ impl Renderer {
                 // Merging from right to left
                 for c in (next_node.column + 1)..merge_col {
                     if col_chars[c] == "│ " {
-                        col_chars[c] = "│─".to_string();
+                        col_chars[c] = "┼─".to_string();
                     } else if col_chars[c] == "╯ " {
                         col_chars[c] = "╯─".to_string();
                     } else if col_chars[c].is_empty() {
                         col_chars[c] = "──".to_string();
                     }
                     merge_branch_cols.insert(c, merge_col);
-                    // Also track for ╯─ and │─ horizontal styling
+                    // Also track for ╯─ and ┼─ horizontal styling
                     leftward_branch_sources.insert(c, merge_col);
                 }
                 // Preserve any horizontal line passing through
@@ -390,14 +463,14 @@ impl Renderer {
                 // Merging from left to right
                 for c in (merge_col + 1)..next_node.column {
                     if col_chars[c] == "│ " {
-                        col_chars[c] = "│─".to_string();
+                        col_chars[c] = "┼─".to_string();
                     } else if col_chars[c] == "╯ " {
                         col_chars[c] = "╯─".to_string();
                     } else if col_chars[c].is_empty() {
                         col_chars[c] = "──".to_string();
                     }
                     merge_branch_cols.insert(c, merge_col);
-                    // Also track for ╯─ and │─ horizontal styling
+                    // Also track for ╯─ and ┼─ horizontal styling
                     leftward_branch_sources.insert(c, merge_col);
                 }
                 // The tee at merge_col is styled based on merge_col (source branch)
@@ -431,13 +504,13 @@ impl Renderer {
             } else if let Some(&source_col) = merge_branch_cols.get(&col) {
                 // Columns with horizontal lines from other merging branches use their branch's style
                 if let Some(&in_current_branch) = col_to_in_current_branch.get(&source_col) {
-                    self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                    self.lane_style(source_col, sync_status, on_ancestry_path, !in_current_branch)
                 } else {
                     current_node_style
                 }
             } else if let Some(&in_current_branch) = col_to_in_current_branch.get(&col) {
                 // Use the pre-calculated ancestry status for passthrough vertical lines
-                self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                self.lane_style(col, sync_status, on_ancestry_path, !in_current_branch)
             } else {
                 // Default to current node's style for non-active columns
                 current_node_style
@@ -457,7 +530,7 @@ impl Renderer {
                 let tee_style = if leftward_merge_targets.contains(&col) {
                     // This is a leftward merge target - use next_node's style
                     let next_in_current_branch = next_node.in_current_branch;
-                    self.commit_style(sync_status, on_ancestry_path, !next_in_current_branch)
+                    self.node_style(next_node, sync_status, on_ancestry_path, !next_in_current_branch)
                 } else {
                     // Otherwise use the column's passthrough style
                     col_style
@@ -474,12 +547,12 @@ impl Renderer {
                         current_node_style
                     } else if let Some(&merge_source) = merge_branch_cols.get(&source_col) {
                         if let Some(&in_current_branch) = col_to_in_current_branch.get(&merge_source) {
-                            self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                            self.lane_style(merge_source, sync_status, on_ancestry_path, !in_current_branch)
                         } else {
                             current_node_style
                         }
                     } else if let Some(&in_current_branch) = col_to_in_current_branch.get(&source_col) {
-                        self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                        self.lane_style(source_col, sync_status, on_ancestry_path, !in_current_branch)
                     } else {
                         current_node_style
                     }
@@ -489,19 +562,19 @@ impl Renderer {
 
                 // Render horizontal with source column style
                 spans.push(Span::styled(horizontal_char.to_string(), horizontal_style));
-            } else if char_str == "╯─" || char_str == "│─" {
-                // Special case: split ╯─ or │─ into two parts with different styles
+            } else if char_str == "╯─" || char_str == "┼─" {
+                // Special case: split ╯─ or ┼─ into two parts with different styles
                 // The vertical/bend part uses the column's passthrough style
                 // The horizontal part uses the branch line style (similar to ├─)
                 let (vertical_char, horizontal_char) = if char_str == "╯─" {
                     ("╯", "─")
                 } else {
-                    ("│", "─")
+                    ("┼", "─")
                 };
 
                 // The vertical/bend part uses the column's own passthrough style
                 let vertical_style = if let Some(&in_current_branch) = col_to_in_current_branch.get(&col) {
-                    self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                    self.lane_style(col, sync_status, on_ancestry_path, !in_current_branch)
                 } else {
                     col_style  // Fall back to col_style if not in map
                 };
@@ -514,12 +587,12 @@ impl Renderer {
                         current_node_style
                     } else if let Some(&merge_source) = merge_branch_cols.get(&source_col) {
                         if let Some(&in_current_branch) = col_to_in_current_branch.get(&merge_source) {
-                            self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                            self.lane_style(merge_source, sync_status, on_ancestry_path, !in_current_branch)
                         } else {
                             current_node_style
                         }
                     } else if let Some(&in_current_branch) = col_to_in_current_branch.get(&source_col) {
-                        self.commit_style(sync_status, on_ancestry_path, !in_current_branch)
+                        self.lane_style(source_col, sync_status, on_ancestry_path, !in_current_branch)
                     } else {
                         current_node_style
                     }
@@ -0,0 +1,257 @@
+use ratatui::style::Color;
+use std::str::FromStr;
+
+/// Named colors used by the UI, chosen from a preset and optionally overridden in the config file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    // Panes and dialogs
+    pub focused_pane_border: Color,
+    pub pane_border: Color,
+    pub dialog_border: Color,
+    pub input_dialog_border: Color,
+    pub alert_border: Color,
+    pub overlay_bg: Color,
+    pub status_bar_fg: Color,
+    pub status_bar_bg: Color,
+    // Text
+    pub text_fg: Color,
+    pub muted_fg: Color,
+    pub dim_fg: Color,
+    pub label_fg: Color,
+    pub accent_fg: Color,
+    pub help_key_fg: Color,
+    pub selected_fg: Color,
+    pub selected_commit_fg: Color,
+    pub marked_commit_fg: Color,
+    pub match_fg: Color,
+    pub success_fg: Color,
+    pub warning_fg: Color,
+    pub error_fg: Color,
+    // Refs
+    pub branch_label_fg: Color,
+    pub remote_branch_fg: Color,
+    pub tag_fg: Color,
+    // Diffs
+    pub diff_add_fg: Color,
+    pub diff_del_fg: Color,
+    pub diff_hunk_fg: Color,
+    pub diff_header_fg: Color,
+    // Commit graph
+    pub commit_synced_fg: Color,
+    pub commit_local_fg: Color,
+    pub commit_remote_fg: Color,
+    pub commit_diverged_fg: Color,
+    pub age_day_fg: Color,
+    pub age_week_fg: Color,
+    pub age_month_fg: Color,
+    pub age_old_fg: Color,
+    pub lane_colors: [Color; 6],
+    /// Draw all text bold
+    pub bold: bool,
+}
+
+impl Theme {
+    pub const PRESETS: [&'static str; 3] = ["dark", "light", "high-contrast"];
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// The original colors, for dark terminal backgrounds
+    pub fn dark() -> Self {
+        Self {
+            focused_pane_border: Color::Yellow,
+            pane_border: Color::DarkGray,
+            dialog_border: Color::Cyan,
+            input_dialog_border: Color::Green,
+            alert_border: Color::Red,
+            overlay_bg: Color::Rgb(40, 40, 40),
+            status_bar_fg: Color::Rgb(185, 177, 160),
+            status_bar_bg: Color::Rgb(90, 90, 90),
+            text_fg: Color::White,
+            muted_fg: Color::Gray,
+            dim_fg: Color::DarkGray,
+            label_fg: Color::Yellow,
+            accent_fg: Color::Cyan,
+            help_key_fg: Color::Green,
+            selected_fg: Color::Yellow,
+            selected_commit_fg: Color::Cyan,
+            marked_commit_fg: Color::Green,
+            match_fg: Color::Magenta,
+            success_fg: Color::Green,
+            warning_fg: Color::Yellow,
+            error_fg: Color::Red,
+            branch_label_fg: Color::Green,
+            remote_branch_fg: Color::Blue,
+            tag_fg: Color::Magenta,
+            diff_add_fg: Color::Green,
+            diff_del_fg: Color::Red,
+            diff_hunk_fg: Color::Cyan,
+            diff_header_fg: Color::Magenta,
+            commit_synced_fg: Color::White,
+            commit_local_fg: Color::Green,
+            commit_remote_fg: Color::Red,
+            commit_diverged_fg: Color::Yellow,
+            age_day_fg: Color::LightGreen,
+            age_week_fg: Color::Yellow,
+            age_month_fg: Color::Rgb(255, 165, 0),
+            age_old_fg: Color::Gray,
+            lane_colors: [Color::Cyan, Color::Magenta, Color::Green, Color::Yellow, Color::Blue, Color::Red],
+            bold: false,
+        }
+    }
+
+    /// For light backgrounds: dark text, and no yellow/white that would wash out
+    pub fn light() -> Self {
+        Self {
+            focused_pane_border: Color::Blue,
+            pane_border: Color::Gray,
+            dialog_border: Color::Blue,
+            input_dialog_border: Color::Green,
+            alert_border: Color::Red,
+            overlay_bg: Color::Rgb(235, 235, 235),
+            status_bar_fg: Color::Rgb(40, 40, 40),
+            status_bar_bg: Color::Rgb(200, 200, 200),
+            text_fg: Color::Black,
+            muted_fg: Color::DarkGray,
+            dim_fg: Color::Gray,
+            label_fg: Color::Rgb(150, 90, 0),
+            accent_fg: Color::Blue,
+            help_key_fg: Color::Green,
+            selected_fg: Color::Magenta,
+            selected_commit_fg: Color::Blue,
+            marked_commit_fg: Color::Green,
+            match_fg: Color::Magenta,
+            success_fg: Color::Green,
+            warning_fg: Color::Rgb(150, 90, 0),
+            error_fg: Color::Red,
+            branch_label_fg: Color::Green,
+            remote_branch_fg: Color::Blue,
+            tag_fg: Color::Magenta,
+            diff_add_fg: Color::Green,
+            diff_del_fg: Color::Red,
+            diff_hunk_fg: Color::Blue,
+            diff_header_fg: Color::Magenta,
+            commit_synced_fg: Color::Black,
+            commit_local_fg: Color::Green,
+            commit_remote_fg: Color::Red,
+            commit_diverged_fg: Color::Rgb(150, 90, 0),
+            age_day_fg: Color::Green,
+            age_week_fg: Color::Rgb(150, 90, 0),
+            age_month_fg: Color::Rgb(200, 80, 0),
+            age_old_fg: Color::DarkGray,
+            lane_colors: [Color::Blue, Color::Magenta, Color::Green, Color::Rgb(150, 90, 0), Color::Cyan, Color::Red],
+            bold: false,
+        }
+    }
+
+    /// Bold text in the 16 ANSI colors only, for low-color terminals and low vision
+    pub fn high_contrast() -> Self {
+        Self {
+            focused_pane_border: Color::LightYellow,
+            pane_border: Color::White,
+            dialog_border: Color::LightCyan,
+            input_dialog_border: Color::LightGreen,
+            alert_border: Color::LightRed,
+            overlay_bg: Color::Black,
+            status_bar_fg: Color::Black,
+            status_bar_bg: Color::White,
+            text_fg: Color::White,
+            muted_fg: Color::White,
+            dim_fg: Color::Gray,
+            label_fg: Color::LightYellow,
+            accent_fg: Color::LightCyan,
+            help_key_fg: Color::LightGreen,
+            selected_fg: Color::LightYellow,
+            selected_commit_fg: Color::LightCyan,
+            marked_commit_fg: Color::LightGreen,
+            match_fg: Color::LightMagenta,
+            success_fg: Color::LightGreen,
+            warning_fg: Color::LightYellow,
+            error_fg: Color::LightRed,
+            branch_label_fg: Color::LightGreen,
+            remote_branch_fg: Color::LightBlue,
+            tag_fg: Color::LightMagenta,
+            diff_add_fg: Color::LightGreen,
+            diff_del_fg: Color::LightRed,
+            diff_hunk_fg: Color::LightCyan,
+            diff_header_fg: Color::LightMagenta,
+            commit_synced_fg: Color::White,
+            commit_local_fg: Color::LightGreen,
+            commit_remote_fg: Color::LightRed,
+            commit_diverged_fg: Color::LightYellow,
+            age_day_fg: Color::LightGreen,
+            age_week_fg: Color::LightYellow,
+            age_month_fg: Color::LightRed,
+            age_old_fg: Color::Gray,
+            lane_colors: [
+                Color::LightCyan,
+                Color::LightMagenta,
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightBlue,
+                Color::LightRed,
+            ],
+            bold: true,
+        }
+    }
+
+    /// Override one named color from the config file (`diff_add_fg = "#00ff00"`, `tag_fg = "magenta"`)
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let color = Color::from_str(value).map_err(|_| format!("invalid color '{}' for {}", value, name))?;
+
+        let field = match name {
+            "focused_pane_border" => &mut self.focused_pane_border,
+            "pane_border" => &mut self.pane_border,
+            "dialog_border" => &mut self.dialog_border,
+            "input_dialog_border" => &mut self.input_dialog_border,
+            "alert_border" => &mut self.alert_border,
+            "overlay_bg" => &mut self.overlay_bg,
+            "status_bar_fg" => &mut self.status_bar_fg,
+            "status_bar_bg" => &mut self.status_bar_bg,
+            "text_fg" => &mut self.text_fg,
+            "muted_fg" => &mut self.muted_fg,
+            "dim_fg" => &mut self.dim_fg,
+            "label_fg" => &mut self.label_fg,
+            "accent_fg" => &mut self.accent_fg,
+            "help_key_fg" => &mut self.help_key_fg,
+            "selected_fg" => &mut self.selected_fg,
+            "selected_commit_fg" => &mut self.selected_commit_fg,
+            "marked_commit_fg" => &mut self.marked_commit_fg,
+            "match_fg" => &mut self.match_fg,
+            "success_fg" => &mut self.success_fg,
+            "warning_fg" => &mut self.warning_fg,
+            "error_fg" => &mut self.error_fg,
+            "branch_label_fg" => &mut self.branch_label_fg,
+            "remote_branch_fg" => &mut self.remote_branch_fg,
+            "tag_fg" => &mut self.tag_fg,
+            "diff_add_fg" => &mut self.diff_add_fg,
+            "diff_del_fg" => &mut self.diff_del_fg,
+            "diff_hunk_fg" => &mut self.diff_hunk_fg,
+            "diff_header_fg" => &mut self.diff_header_fg,
+            "commit_synced_fg" => &mut self.commit_synced_fg,
+            "commit_local_fg" => &mut self.commit_local_fg,
+            "commit_remote_fg" => &mut self.commit_remote_fg,
+            "commit_diverged_fg" => &mut self.commit_diverged_fg,
+            "age_day_fg" => &mut self.age_day_fg,
+            "age_week_fg" => &mut self.age_week_fg,
+            "age_month_fg" => &mut self.age_month_fg,
+            "age_old_fg" => &mut self.age_old_fg,
+            _ => return Err(format!("unknown theme color '{}'", name)),
+        };
+        *field = color;
+
+        Ok(())
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
@@ -6,10 +6,23 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{cursor_byte_offset, App, GraphColumn};
+use crate::graph::SignatureStatus;
 use crate::renderer::Renderer;
+use crate::theme::Theme;
 
 pub fn draw(f: &mut Frame, app: &mut App) {
+    let theme = app.config.theme;
+    draw_screen(f, app, &theme);
+
+    if theme.bold {
+        for cell in f.buffer_mut().content.iter_mut() {
+            cell.modifier.insert(Modifier::BOLD);
+        }
+    }
+}
+
+fn draw_screen(f: &mut Frame, app: &mut App, theme: &Theme) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -19,23 +32,41 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         .split(f.area());
 
     // If help screen is active, show it fullscreen
-    use crate::app::AppMode;
+    use crate::app::{AppMode, FocusedPane};
     if app.mode == AppMode::Help {
-        draw_help_screen(f, app, f.area());
+        draw_help_screen(f, app, f.area(), theme);
         return;
     }
 
     // If file diff view is active, show it fullscreen
     if app.mode == AppMode::FileDiffView {
-        draw_file_diff_fullscreen(f, app, chunks[0]);
-        draw_status_bar(f, app, chunks[1]);
+        draw_file_diff_fullscreen(f, app, chunks[0], theme);
+        draw_status_bar(f, app, chunks[1], theme);
+        return;
+    }
+
+    if app.mode == AppMode::Reflog {
+        draw_reflog_fullscreen(f, app, chunks[0], theme);
+        draw_status_bar(f, app, chunks[1], theme);
+        return;
+    }
+
+    if app.mode == AppMode::FileHistory {
+        draw_file_history(f, app, chunks[0], theme);
+        draw_status_bar(f, app, chunks[1], theme);
+        return;
+    }
+
+    if app.mode == AppMode::BlameView {
+        draw_blame_view(f, app, chunks[0], theme);
+        draw_status_bar(f, app, chunks[1], theme);
         return;
     }
 
     // If details pane is expanded, show it fullscreen
     if app.details_expanded {
-        draw_commit_details(f, app, chunks[0]);
-        draw_status_bar(f, app, chunks[1]);
+        draw_commit_details(f, app, chunks[0], theme);
+        draw_status_bar(f, app, chunks[1], theme);
         return;
     }
 
@@ -63,59 +94,140 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         ])
         .split(main_chunks[1]);
 
-    // Draw commit graph/list
-    draw_commit_graph(f, app, left_chunks[0]);
-
-    // Draw git actions/commands
-    draw_git_actions(f, app, left_chunks[1]);
-
-    // Draw git status
-    draw_git_status(f, app, right_chunks[1]);
-
-    // Draw commit details
-    draw_commit_details(f, app, right_chunks[0]);
+    // The tag list takes the right side of the left-bottom slot
+    let left_bottom_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(60),
+            Constraint::Percentage(40),
+        ])
+        .split(left_chunks[1]);
+    draw_tag_list(f, app, left_bottom_chunks[1], theme);
+
+    // Place each pane in the slot given by the configured pane order
+    let slots = [left_chunks[0], left_bottom_chunks[0], right_chunks[0], right_chunks[1]];
+    let mut graph_area = slots[0];
+
+    for (slot, pane) in slots.into_iter().zip(app.config.pane_order) {
+        match pane {
+            FocusedPane::CommitGraph => {
+                graph_area = slot;
+                // The stash list takes the graph's place while browsing stashes
+                if app.mode == AppMode::StashView {
+                    draw_stash_list(f, app, slot, theme);
+                } else {
+                    draw_commit_graph(f, app, slot, theme);
+                }
+            }
+            FocusedPane::GitActions => draw_git_actions(f, app, slot, theme),
+            FocusedPane::CommitDetails => {
+                // ...and the stash diff or changed-files tree take the details pane's place
+                if app.mode == AppMode::StashView {
+                    draw_stash_diff(f, app, slot, theme);
+                } else if app.mode == AppMode::CommitTree {
+                    draw_commit_tree(f, app, slot, theme);
+                } else {
+                    draw_commit_details(f, app, slot, theme);
+                }
+            }
+            FocusedPane::GitStatus => draw_git_status(f, app, slot, theme),
+            // The tag list has its own fixed slot (drawn above) and is never in `pane_order`
+            FocusedPane::TagList => {}
+        }
+    }
 
     // Draw status bar
-    draw_status_bar(f, app, chunks[1]);
+    draw_status_bar(f, app, chunks[1], theme);
+
+    // Draw hover diff preview on top of the panes
+    draw_diff_preview(f, app, graph_area, theme);
 
     // Draw dialogs based on mode
     if app.mode == AppMode::Confirm {
-        draw_confirmation_dialog(f, app);
-    } else if app.mode == AppMode::CommitMessage {
-        draw_commit_message_dialog(f, app);
+        draw_confirmation_dialog(f, app, theme);
+    } else if app.mode == AppMode::CommitMessage || app.mode == AppMode::MergeMessage {
+        draw_commit_message_dialog(f, app, theme);
     } else if app.mode == AppMode::BranchName {
-        draw_branch_name_dialog(f, app);
+        draw_branch_name_dialog(f, app, theme);
+    } else if app.mode == AppMode::RenameBranch {
+        draw_rename_branch_dialog(f, app, theme);
+    } else if app.mode == AppMode::GoToHash {
+        draw_goto_hash_dialog(f, app, theme);
+    } else if app.mode == AppMode::TagName {
+        draw_tag_name_dialog(f, app, theme);
     } else if app.mode == AppMode::SelectBranch {
-        draw_branch_selection_dialog(f, app);
+        draw_branch_selection_dialog(f, app, theme);
     } else if app.mode == AppMode::SelectBranchToDelete {
-        draw_delete_branch_selection_dialog(f, app);
+        draw_delete_branch_selection_dialog(f, app, theme);
     } else if app.mode == AppMode::SetUserName {
-        draw_config_input_dialog(f, app, "Set Git User Name", &app.config_input);
+        draw_config_input_dialog(f, app, "Set Git User Name", &app.config_input, theme);
     } else if app.mode == AppMode::SetUserEmail {
-        draw_config_input_dialog(f, app, "Set Git User Email", &app.config_input);
+        draw_config_input_dialog(f, app, "Set Git User Email", &app.config_input, theme);
     } else if app.mode == AppMode::SetRemoteHost {
-        draw_config_input_dialog(f, app, "Set Remote URL", &app.remote_host_input);
+        draw_config_input_dialog(f, app, "Set Remote URL", &app.remote_host_input, theme);
+    } else if app.mode == AppMode::AddRemote || app.mode == AppMode::RenameRemote {
+        draw_remote_dialog(f, app, theme);
+    } else if app.mode == AppMode::SelectRemoteToRemove {
+        draw_remote_selection_dialog(f, app, theme);
     } else if app.mode == AppMode::SquashCountInput {
-        draw_squash_count_dialog(f, app);
+        draw_squash_count_dialog(f, app, theme);
     } else if app.mode == AppMode::RewordMessage {
-        draw_reword_message_dialog(f, app);
+        draw_reword_message_dialog(f, app, theme);
     } else if app.mode == AppMode::AssignBranchName {
-        draw_assign_branch_name_dialog(f, app);
+        draw_assign_branch_name_dialog(f, app, theme);
+    } else if app.mode == AppMode::HooksView {
+        draw_hooks_dialog(f, app, theme);
+    } else if app.mode == AppMode::WorkingTreeSummary {
+        draw_working_tree_summary_dialog(f, app, theme);
+    } else if app.mode == AppMode::RepoStats {
+        draw_repo_stats_dialog(f, app, theme);
+    } else if app.mode == AppMode::QuickLog {
+        draw_quick_log_dialog(f, app, theme);
+    } else if app.mode == AppMode::WorktreeView {
+        draw_worktree_dialog(f, app, theme);
+    } else if app.mode == AppMode::WorktreeCreate {
+        draw_worktree_dialog(f, app, theme);
+        draw_create_worktree_dialog(f, app, theme);
+    } else if app.mode == AppMode::PushTarget {
+        draw_push_target_dialog(f, app, theme);
+    } else if app.mode == AppMode::SetUpstream {
+        draw_set_upstream_dialog(f, app, theme);
+    } else if app.mode == AppMode::RebaseTodo {
+        draw_rebase_todo_dialog(f, app, theme);
+    } else if app.mode == AppMode::YankedHash {
+        draw_yanked_hash_dialog(f, app, theme);
+    } else if matches!(app.mode, AppMode::RebaseOntoNewBase | AppMode::RebaseOntoUpstream) {
+        draw_rebase_onto_banner(f, app, graph_area, theme);
     }
 }
 
-fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
+fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     use crate::app::FocusedPane;
 
     let is_focused = app.focused_pane == FocusedPane::CommitGraph;
 
+    // Once the background count is in, say when more history exists than is loaded
+    let loaded = app.graph_nodes.len();
+    let showing = if app.total_commit_count > loaded {
+        format!(" [showing {} of {}]", loaded, app.total_commit_count)
+    } else {
+        String::new()
+    };
+
     let block = Block::default()
-        .title("Commit Graph")
+        .title(format!(
+            "Commit Graph [{}] [sort: {}]{}{}{}",
+            if app.first_parent_only { "first-parent" } else if app.show_all_branches { "all" } else { "HEAD" },
+            app.config.commit_sort.label(),
+            if app.config.compact_graph { " [compact]" } else { "" },
+            if app.show_unreviewed_only { " [unreviewed]" } else { "" },
+            showing
+        ))
         .borders(Borders::ALL)
         .style(Style::default().fg(if is_focused {
-            Color::Yellow
+            theme.focused_pane_border
         } else {
-            Color::DarkGray
+            theme.pane_border
         }));
 
     let inner_area = block.inner(area);
@@ -124,33 +236,41 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
     // Guard against terminal being too small
     if inner_area.height < 2 || inner_area.width < 10 {
         let msg = Paragraph::new("Terminal too small")
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.dim_fg));
         f.render_widget(msg, inner_area);
         return;
     }
 
     if app.graph_nodes.is_empty() {
         let msg = Paragraph::new("No commits found or not in a git repository")
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.dim_fg));
         f.render_widget(msg, inner_area);
         return;
     }
 
     // Adjust scroll to keep selection visible
+    app.graph_viewport_height = inner_area.height as usize;
     app.adjust_scroll(inner_area.height as usize);
 
     // Render commit graph
-    let renderer = Renderer::new();
+    let mut renderer = Renderer::new(*theme);
+    renderer.author_colors = app.author_colors.clone();
+    let author_color = |node: &crate::graph::GraphNode| app.author_colors.get(&node.commit.author).copied();
+    // Reserve room for signature badges only in repositories that sign commits
+    let has_signed_commits = app.commit_signatures.values().any(|&status| status != SignatureStatus::Unsigned);
     let mut all_lines = Vec::new();
 
     // Calculate active columns at each row
     // Track which commits are "live" (have unprocessed parents)
     let mut active_at_row: Vec<Vec<usize>> = Vec::new();
     let mut col_ancestry_at_row: Vec<std::collections::HashMap<usize, bool>> = Vec::new();
+    // Lane colors from the author of the commit owning each column
+    let mut col_colors_at_row: Vec<std::collections::HashMap<usize, Color>> = Vec::new();
 
     for i in 0..app.graph_nodes.len() {
         let mut active: Vec<usize> = Vec::new();
         let mut col_to_in_current_branch: std::collections::HashMap<usize, bool> = std::collections::HashMap::new();
+        let mut col_colors: std::collections::HashMap<usize, Color> = std::collections::HashMap::new();
 
         // A column is active if there's a "live" commit in that column
         // A commit is live if we've seen it but haven't seen all its parents yet
@@ -169,6 +289,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
             if has_unprocessed_parents && !active.contains(&node.column) {
                 active.push(node.column);
                 col_to_in_current_branch.insert(node.column, node.in_current_branch);
+                col_colors.extend(author_color(node).map(|color| (node.column, color)));
             }
 
             // Also include MergeFrom source columns for commits that have merge connections
@@ -192,6 +313,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
                             if parent_not_yet_seen && !active.contains(source_col) {
                                 active.push(*source_col);
                                 col_to_in_current_branch.insert(*source_col, node.in_current_branch);
+                                col_colors.extend(author_color(node).map(|color| (*source_col, color)));
                             }
                         }
                     }
@@ -206,6 +328,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
                 active.push(current_node.column);
             }
             col_to_in_current_branch.insert(current_node.column, current_node.in_current_branch);
+            col_colors.extend(author_color(current_node).map(|color| (current_node.column, color)));
         }
 
         // Include all columns up to the maximum for commits that haven't reached parents
@@ -223,6 +346,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
                     active.push(col);
                     let node = &app.graph_nodes[j];
                     col_to_in_current_branch.insert(col, node.in_current_branch);
+                    col_colors.extend(author_color(node).map(|color| (col, color)));
                 }
             }
         }
@@ -231,19 +355,44 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
         active.dedup();
         active_at_row.push(active);
         col_ancestry_at_row.push(col_to_in_current_branch);
+        col_colors_at_row.push(col_colors);
     }
 
+    // Width of the padded text columns in front of the graph characters
+    let graph_chars_offset: usize = app.config.graph_row_format.iter()
+        .take_while(|spec| spec.column != GraphColumn::GraphChars)
+        .map(|spec| spec.max_width + 1)
+        .sum();
+
     for (idx, node) in app.graph_nodes.iter().enumerate() {
+        // A search or unreviewed-only filter shows only the matching node rows
+        if app.filtered_graph_nodes.as_ref().is_some_and(|filtered| filtered.binary_search(&idx).is_err()) {
+            continue;
+        }
+
         let on_ancestry_path = app.graph.is_on_ancestry_path(&node.commit.id);
         let sync_status = crate::graph::SyncStatus::Synced;
         let active_cols = active_at_row.get(idx).map(|v| v.as_slice()).unwrap_or(&[]);
         let col_ancestry = col_ancestry_at_row.get(idx).cloned().unwrap_or_default();
         let is_selected = Some(idx) == app.selected_commit_idx;
 
+        // Date separator above the first commit of a new day/week/month
+        if app.filtered_graph_nodes.is_none() {
+            if let Ok(pos) = app.graph_separators.binary_search_by_key(&idx, |(node_idx, _)| *node_idx) {
+                let label = format!("── {} ", app.graph_separators[pos].1);
+                let fill = (inner_area.width as usize).saturating_sub(label.chars().count());
+                all_lines.push(Line::from(Span::styled(
+                    format!("{}{}", label, "─".repeat(fill)),
+                    Style::default().fg(theme.dim_fg),
+                )));
+            }
+        }
+
         // Check if commit is not in current branch's history
         let not_in_current_branch = app.is_commit_not_in_current_branch(&node.commit.id);
 
         // Node row
+        renderer.set_lane_colors(col_colors_at_row.get(idx).cloned().unwrap_or_default());
         let node_row = renderer.render_node_row(
             node,
             app.graph_width,
@@ -254,43 +403,106 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
             not_in_current_branch,
         );
 
-        // Add commit message next to the graph
-        let mut node_line_spans = node_row.spans;
-        node_line_spans.push(Span::raw(" "));
-
         // Highlight selected commit or commits selected for branch assignment
         let is_marked_for_branch = app.is_commit_selected(&node.commit.id);
+        let is_reviewed = app.reviewed_commits.contains(&node.commit.id);
         let message_style = if Some(idx) == app.selected_commit_idx {
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            Style::default().fg(theme.selected_commit_fg).add_modifier(Modifier::BOLD)
         } else if is_marked_for_branch {
-            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
-        } else if not_in_current_branch {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(theme.marked_commit_fg).add_modifier(Modifier::BOLD)
+        } else if not_in_current_branch || is_reviewed {
+            Style::default().fg(theme.dim_fg)
         } else {
             Style::default()
         };
 
-        let short_msg = node.commit.message.lines().next().unwrap_or("");
-        let short_msg = if short_msg.len() > 50 {
-            // Use char_indices to avoid UTF-8 boundary issues
-            let truncate_pos = short_msg.char_indices()
-                .nth(47)
-                .map(|(idx, _)| idx)
-                .unwrap_or(short_msg.len());
-            format!("{}...", &short_msg[..truncate_pos])
-        } else {
-            short_msg.to_string()
-        };
+        // Lay out the row in the configured column order
+        let mut node_line_spans = Vec::new();
+        let mut graph_node_spans = Some(node_row.spans);
+        let column_count = app.config.graph_row_format.len();
+
+        for (col_idx, spec) in app.config.graph_row_format.iter().enumerate() {
+            if col_idx > 0 {
+                node_line_spans.push(Span::raw(" "));
+            }
+
+            if spec.column == GraphColumn::GraphChars {
+                node_line_spans.extend(graph_node_spans.take().unwrap_or_default());
+                continue;
+            }
+
+            let mut text = graph_column_text(app, node, spec.column);
+            if spec.column == GraphColumn::Subject && is_reviewed {
+                text.insert_str(0, "✓ ");
+            }
+            let mut text = truncate_with_ellipsis(&text, spec.max_width);
+
+            // Pad every column but the last so the following ones line up
+            if col_idx + 1 < column_count {
+                let len = text.chars().count();
+                text.push_str(&" ".repeat(spec.max_width.saturating_sub(len)));
+            }
+
+            let column_style = match spec.column {
+                GraphColumn::BranchLabel => message_style.fg(theme.branch_label_fg),
+                GraphColumn::Author | GraphColumn::RelativeDate | GraphColumn::AbsoluteDate if message_style == Style::default() => {
+                    Style::default().fg(theme.muted_fg)
+                }
+                _ => message_style,
+            };
+
+            // Highlight every occurrence of the search query in the subject
+            let mut last_end = 0;
+            if spec.column == GraphColumn::Subject && app.mode == crate::app::AppMode::Search {
+                for (start, end) in app.search_match_ranges(&text) {
+                    node_line_spans.push(Span::styled(text[last_end..start].to_string(), column_style));
+                    node_line_spans.push(Span::styled(
+                        text[start..end].to_string(),
+                        column_style.add_modifier(Modifier::REVERSED),
+                    ));
+                    last_end = end;
+                }
+            }
+            node_line_spans.push(Span::styled(text[last_end..].to_string(), column_style));
+
+            if has_signed_commits && matches!(spec.column, GraphColumn::ShortHash | GraphColumn::FullHash) {
+                node_line_spans.push(Span::raw(" "));
+                node_line_spans.push(signature_badge(app.signature_status(&node.commit.id), theme)
+                    .unwrap_or_else(|| Span::raw("  ")));
+            }
+        }
+
+        let tags = app.get_commit_tags(&node.commit.id);
+        if !tags.is_empty() {
+            node_line_spans.push(Span::styled(
+                format!(" [{}]", tags.join(", ")),
+                Style::default().fg(theme.tag_fg),
+            ));
+        }
+
+        // Where the remotes are, so local/remote divergence is visible at a glance
+        if !node.remote_refs.is_empty() {
+            node_line_spans.push(Span::styled(
+                format!(" ({})", node.remote_refs.join(", ")),
+                Style::default().fg(theme.remote_branch_fg),
+            ));
+        }
 
-        node_line_spans.push(Span::styled(
-            format!("{} {}", node.commit.short_id, short_msg),
-            message_style,
-        ));
+        // Right-aligned changed file count, when it fits after the row's text
+        if let Some(&count) = app.commit_file_counts.get(&node.commit.id).filter(|&&count| count > 0) {
+            let badge = format!("[{}±]", count.min(99));
+            let used = Line::from(node_line_spans.clone()).width();
+            let free = (inner_area.width as usize).saturating_sub(used);
+            if free > badge.chars().count() {
+                node_line_spans.push(Span::raw(" ".repeat(free - badge.chars().count())));
+                node_line_spans.push(Span::styled(badge, Style::default().fg(theme.dim_fg)));
+            }
+        }
 
         all_lines.push(Line::from(node_line_spans));
 
-        // Edge row with proper connection rendering
-        if idx < app.graph_nodes.len() - 1 {
+        // Edge row with proper connection rendering (compact mode relies on the node rows' lanes alone)
+        if !app.config.compact_graph && app.filtered_graph_nodes.is_none() && idx < app.graph_nodes.len() - 1 {
             let next_node = &app.graph_nodes[idx + 1];
 
             // For edge rows, only show vertical lines for commits that are PASSING THROUGH this edge
@@ -298,6 +510,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
             let mut edge_active_cols: Vec<usize> = Vec::new();
             // Track which column is owned by which commit (for ancestry-based styling)
             let mut col_to_in_current_branch: std::collections::HashMap<usize, bool> = std::collections::HashMap::new();
+            let mut col_colors: std::collections::HashMap<usize, Color> = std::collections::HashMap::new();
 
             // Only include columns where commits from current row or earlier still have
             // unprocessed parents at the next row or beyond
@@ -325,6 +538,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
                     edge_active_cols.push(node.column);
                     // Track the ancestry status of the commit in this column
                     col_to_in_current_branch.insert(node.column, node.in_current_branch);
+                    col_colors.extend(author_color(node).map(|color| (node.column, color)));
                 }
 
                 // Also track MergeFrom source columns that haven't reached their source commit yet
@@ -347,6 +561,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
                                 edge_active_cols.push(*source_col);
                                 // Track ancestry for merge source column too
                                 col_to_in_current_branch.insert(*source_col, node.in_current_branch);
+                                col_colors.extend(author_color(node).map(|color| (*source_col, color)));
                             }
                         }
                     }
@@ -355,6 +570,7 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
 
             edge_active_cols.sort();
             edge_active_cols.dedup();
+            renderer.set_lane_colors(col_colors);
 
             let edge_row = renderer.render_edge_row(
                 node,
@@ -367,10 +583,17 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
                 &app.graph_nodes,
                 not_in_current_branch,
             );
-            all_lines.push(edge_row);
+            // Shift the edge row under the graph column when text columns come before it
+            let mut edge_spans = vec![Span::raw(" ".repeat(graph_chars_offset))];
+            edge_spans.extend(edge_row.spans);
+            all_lines.push(Line::from(edge_spans));
         }
     }
 
+    if app.loading_more_commits {
+        all_lines.push(Line::from(Span::styled("  Loading…", Style::default().fg(theme.dim_fg))));
+    }
+
     // Extract visible lines based on scroll offset
     let viewport_height = inner_area.height as usize;
     let visible_lines: Vec<Line> = all_lines
@@ -385,23 +608,245 @@ fn draw_commit_graph(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_widget(paragraph, inner_area);
 }
 
-fn draw_commit_details(f: &mut Frame, app: &App, area: Rect) {
+/// "Renamed from a → b (similarity: N%)" when `lines[idx]` is the similarity line of a rename header
+fn rename_header(lines: &[&str], idx: usize) -> Option<String> {
+    let similarity = lines[idx].strip_prefix("similarity index ")?;
+    let header = lines.get(idx + 1..(idx + 3).min(lines.len()))?;
+
+    let from = header.iter().find_map(|l| l.strip_prefix("rename from "))?;
+    let to = header.iter().find_map(|l| l.strip_prefix("rename to "))?;
+
+    Some(format!("Renamed from {} → {} (similarity: {})", from, to, similarity))
+}
+
+/// "(context: 3, word diff) [no-ws]" for the details pane titles
+fn diff_settings_label(app: &App) -> String {
+    format!(
+        "(context: {}{}){}",
+        app.diff_context_lines,
+        if app.diff_word_mode { ", word diff" } else { "" },
+        if app.diff_ignore_whitespace { " [no-ws]" } else { "" },
+    )
+}
+
+/// File and hunk headers, which keep their usual colors in a word diff
+fn is_diff_header_line(line: &str) -> bool {
+    [
+        "diff --git", "index ", "--- ", "+++ ", "@@", "new file mode", "deleted file mode",
+        "old mode", "new mode", "similarity index", "rename from", "rename to", "Binary files",
+    ]
+    .iter()
+    .any(|prefix| line.starts_with(prefix))
+}
+
+/// A `--word-diff=plain` line with its `[-removed-]` and `{+added+}` runs colored and the markers dropped
+fn word_diff_spans<'a>(line: &'a str, theme: &Theme) -> Vec<Span<'a>> {
+    let removed = Style::default().fg(theme.diff_del_fg).add_modifier(Modifier::CROSSED_OUT);
+    let added = Style::default().fg(theme.diff_add_fg);
+
+    let mut spans = Vec::new();
+    let mut rest = line;
+    loop {
+        let next = [("[-", "-]", removed), ("{+", "+}", added)]
+            .into_iter()
+            .filter_map(|(open, close, style)| rest.find(open).map(|pos| (pos, open, close, style)))
+            .min_by_key(|(pos, ..)| *pos);
+        let Some((pos, open, close, style)) = next else {
+            break;
+        };
+
+        if pos > 0 {
+            spans.push(Span::raw(&rest[..pos]));
+        }
+        let run = &rest[pos + open.len()..];
+        // git closes the markers on every line, but don't lose text if one is missing
+        let end = run.find(close).unwrap_or(run.len());
+        spans.push(Span::styled(&run[..end], style));
+        rest = run.get(end + close.len()..).unwrap_or("");
+    }
+    if !rest.is_empty() || spans.is_empty() {
+        spans.push(Span::raw(rest));
+    }
+    spans
+}
+
+/// Two-cell badge for a signed commit; None for unsigned or unchecked ones
+fn signature_badge(status: Option<SignatureStatus>, theme: &Theme) -> Option<Span<'static>> {
+    match status? {
+        SignatureStatus::Good => Some(Span::styled("🔑", Style::default().fg(theme.success_fg))),
+        SignatureStatus::UnknownKey => Some(Span::styled("⚠ ", Style::default().fg(theme.warning_fg))),
+        SignatureStatus::Bad => Some(Span::styled("✗ ", Style::default().fg(theme.error_fg))),
+        SignatureStatus::Unsigned => None,
+    }
+}
+
+/// The details pane "Commit:" line, with the signature verdict spelled out
+fn signature_spans<'a>(commit_id: &'a str, status: Option<SignatureStatus>, theme: &Theme) -> Vec<Span<'a>> {
+    let mut spans = vec![
+        Span::styled("Commit: ", Style::default().fg(theme.label_fg)),
+        Span::raw(commit_id),
+    ];
+
+    let Some(badge) = signature_badge(status, theme) else {
+        return spans;
+    };
+    let verdict = match status {
+        Some(SignatureStatus::Good) => "good signature",
+        Some(SignatureStatus::UnknownKey) => "signed, key not trusted or unavailable",
+        _ => "BAD signature",
+    };
+
+    let style = badge.style;
+    spans.push(Span::raw(" "));
+    spans.push(badge);
+    spans.push(Span::styled(verdict, style));
+    spans
+}
+
+/// Text for one non-graph column of a commit graph row
+fn graph_column_text(app: &App, node: &crate::graph::GraphNode, column: GraphColumn) -> String {
+    use chrono::DateTime;
+
+    let commit = &node.commit;
+    match column {
+        GraphColumn::GraphChars => String::new(),
+        GraphColumn::ShortHash => commit.short_id.clone(),
+        GraphColumn::FullHash => commit.id.clone(),
+        GraphColumn::Subject => commit.message.lines().next().unwrap_or("").to_string(),
+        GraphColumn::Author => commit.author.clone(),
+        GraphColumn::RelativeDate => relative_time(commit.timestamp),
+        GraphColumn::AbsoluteDate => DateTime::from_timestamp(commit.timestamp, 0)
+            .unwrap_or(DateTime::UNIX_EPOCH)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        GraphColumn::BranchLabel => app.branch_labels.get(&commit.id)
+            .map(|names| format!("({})", names.join(", ")))
+            .unwrap_or_default(),
+    }
+}
+
+/// Cut `text` to at most `max` characters, ending in "..." when shortened (UTF-8 safe)
+fn truncate_with_ellipsis(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    if max <= 3 {
+        return text.chars().take(max).collect();
+    }
+    let kept: String = text.chars().take(max - 3).collect();
+    format!("{}...", kept)
+}
+
+/// Human-friendly age such as "5 minutes ago" or "3 weeks ago"
+fn relative_time(timestamp: i64) -> String {
+    let secs = (chrono::Utc::now().timestamp() - timestamp).max(0);
+    let (value, unit) = match secs {
+        s if s < 60 => return "just now".to_string(),
+        s if s < 3600 => (s / 60, "minute"),
+        s if s < 86_400 => (s / 3600, "hour"),
+        s if s < 7 * 86_400 => (s / 86_400, "day"),
+        s if s < 30 * 86_400 => (s / (7 * 86_400), "week"),
+        s if s < 365 * 86_400 => (s / (30 * 86_400), "month"),
+        s => (s / (365 * 86_400), "year"),
+    };
+    format!("{} {}{} ago", value, unit, if value == 1 { "" } else { "s" })
+}
+
+fn draw_diff_preview(f: &mut Frame, app: &App, graph_area: Rect, theme: &Theme) {
+    let preview = match app.visible_diff_preview() {
+        Some(preview) => preview,
+        None => return,
+    };
+    let selected_idx = match app.selected_commit_idx {
+        Some(idx) => idx,
+        None => return,
+    };
+
+    // Locate the selected row inside the graph pane (borders take one cell on each side)
+    let graph_inner = Block::default().borders(Borders::ALL).inner(graph_area);
+    let row = app.graph_row_of(selected_idx).saturating_sub(app.scroll_offset) as u16;
+    if row >= graph_inner.height {
+        return;
+    }
+    let anchor_y = graph_inner.y + row;
+
+    let area = f.area();
+    let popup_width = 40;
+    let popup_height = 12;
+
+    // Prefer just below the selected row, flip above it when there is no room
+    let y = if anchor_y + 1 + popup_height <= area.height {
+        anchor_y + 1
+    } else {
+        anchor_y.saturating_sub(popup_height)
+    };
+    let x = (graph_inner.x + (app.graph_width * 2) as u16 + 2)
+        .min(area.width.saturating_sub(popup_width));
+
+    let popup_area = Rect {
+        x,
+        y,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let short_id = preview.commit_id.get(..7).unwrap_or(&preview.commit_id);
+    let block = Block::default()
+        .title(format!("Preview {}", short_id))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let lines: Vec<Line> = preview.lines.iter().flatten()
+        .map(|diff_line| {
+            let style = if diff_line.starts_with("+++") || diff_line.starts_with("---") {
+                Style::default().fg(theme.muted_fg)
+            } else if diff_line.starts_with('+') {
+                Style::default().fg(theme.diff_add_fg)
+            } else if diff_line.starts_with('-') {
+                Style::default().fg(theme.diff_del_fg)
+            } else if diff_line.starts_with("@@") {
+                Style::default().fg(theme.diff_hunk_fg)
+            } else if diff_line.starts_with("diff --git") {
+                Style::default().fg(theme.diff_header_fg)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+            Line::from(Span::styled(diff_line.as_str(), style))
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines);
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_commit_details(f: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     use crate::app::FocusedPane;
     use chrono::{DateTime, Utc};
 
     let is_focused = app.focused_pane == FocusedPane::CommitDetails;
 
+    if app.status_file_diff.is_some() {
+        draw_status_file_diff(f, app, area, is_focused, theme);
+        return;
+    }
+
     let block = Block::default()
-        .title("Commit Details")
+        .title(format!("Commit Details {}", diff_settings_label(app)))
         .borders(Borders::ALL)
         .style(Style::default().fg(if is_focused {
-            Color::Yellow
+            theme.focused_pane_border
         } else {
-            Color::DarkGray
+            theme.pane_border
         }));
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
+    app.details_viewport_height = inner_area.height as usize;
 
     if let Some(idx) = app.selected_commit_idx {
         if let Some(node) = app.graph_nodes.get(idx) {
@@ -412,35 +857,124 @@ fn draw_commit_details(f: &mut Frame, app: &App, area: Rect) {
                 .unwrap_or_else(|| DateTime::UNIX_EPOCH);
             let formatted_date = datetime.format("%Y-%m-%d %H:%M:%S").to_string();
 
-            // Get branches pointing to this commit
+            // Get branches pointing to this commit, with their distance from the main branch
             let branches = app.get_all_branches_for_commit(&commit.id);
-            let (branch_label, branch_text, branch_color) = if branches.is_empty() {
-                ("Branch: ", "not branch tip".to_string(), Color::DarkGray)
+            let mut branch_spans = vec![Span::styled("Branch: ", Style::default().fg(theme.label_fg))];
+            if branches.is_empty() {
+                branch_spans.push(Span::styled("not branch tip", Style::default().fg(theme.dim_fg)));
             } else {
-                ("Branch: ", branches.join(", "), Color::Cyan)
-            };
+                for (i, branch) in branches.iter().enumerate() {
+                    if i > 0 {
+                        branch_spans.push(Span::styled(", ", Style::default().fg(theme.accent_fg)));
+                    }
+                    branch_spans.push(Span::styled(branch.clone(), Style::default().fg(theme.accent_fg)));
+
+                    if let (Some(distance), Some(main_branch)) = (app.branch_distances.get(branch), app.main_branch_name.as_ref()) {
+                        branch_spans.push(Span::styled(
+                            format!(" +{} from {}", distance, main_branch),
+                            Style::default().fg(theme.success_fg),
+                        ));
+                    }
+                }
+            }
 
+            let mut diff_start = 0;
             let mut lines = vec![
+                Line::from(signature_spans(&commit.id, app.signature_status(&commit.id), theme)),
+                Line::from(branch_spans),
+            ];
+
+            let remote_refs = app.get_remote_refs_for_commit(&commit.id);
+            if !remote_refs.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled("Remote: ", Style::default().fg(theme.label_fg)),
+                    Span::styled(remote_refs.join(", "), Style::default().fg(theme.remote_branch_fg)),
+                ]));
+            }
+
+            let tags = app.get_commit_tags(&commit.id);
+            if !tags.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled("Tags: ", Style::default().fg(theme.label_fg)),
+                    Span::styled(tags.join(", "), Style::default().fg(theme.tag_fg)),
+                ]));
+            }
+
+            // Merge commits list every parent, colored like the parent's node in the graph
+            if commit.parents.len() > 1 {
+                let mut parent_spans = vec![Span::styled("Parents:", Style::default().fg(theme.label_fg))];
+                for (i, parent_id) in commit.parents.iter().enumerate() {
+                    let color = app.graph_nodes.iter()
+                        .find(|n| &n.commit.id == parent_id)
+                        .map(|n| app.author_colors.get(&n.commit.author).copied()
+                            .unwrap_or_else(|| crate::renderer::lane_color(n.column, theme)))
+                        .unwrap_or(theme.dim_fg);
+                    let mut style = Style::default().fg(color);
+                    if app.details_parent_cursor == Some(i) {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    parent_spans.push(Span::raw(" "));
+                    parent_spans.push(Span::styled(parent_id[..7.min(parent_id.len())].to_string(), style));
+                }
+                if is_focused {
+                    parent_spans.push(Span::styled("  (p: pick, Enter: go to)", Style::default().fg(theme.dim_fg)));
+                }
+                lines.push(Line::from(parent_spans));
+            }
+
+            lines.extend([
                 Line::from(vec![
-                    Span::styled("Commit: ", Style::default().fg(Color::Yellow)),
-                    Span::raw(&commit.id),
-                ]),
-                Line::from(vec![
-                    Span::styled(branch_label, Style::default().fg(Color::Yellow)),
-                    Span::styled(branch_text, Style::default().fg(branch_color)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Date: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Date: ", Style::default().fg(theme.label_fg)),
                     Span::raw(&formatted_date),
                 ]),
                 Line::from(vec![
-                    Span::styled("Author: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Author: ", Style::default().fg(theme.label_fg)),
                     Span::raw(&commit.author),
                 ]),
-                Line::from(vec![
-                    Span::styled("Message: ", Style::default().fg(Color::Yellow)),
-                ]),
-            ];
+            ]);
+
+            if let Some(ref stat) = app.current_diff_stat {
+                lines.push(Line::from(Span::styled(
+                    stat.as_str(),
+                    Style::default().fg(theme.label_fg).add_modifier(Modifier::BOLD),
+                )));
+            }
+
+            if !app.current_diff_files.is_empty() {
+                let (marker, hint) = if app.details_files_collapsed {
+                    ("▸", "  (F: expand)")
+                } else {
+                    ("▾", "  ([ / ]: jump, F: collapse)")
+                };
+                let mut heading = vec![Span::styled(format!("{} Files:", marker), Style::default().fg(theme.label_fg))];
+                if is_focused {
+                    heading.push(Span::styled(hint, Style::default().fg(theme.dim_fg)));
+                }
+                lines.push(Line::from(heading));
+
+                if !app.details_files_collapsed {
+                    for (idx, (status, path)) in app.current_diff_files.iter().enumerate() {
+                        let status_color = match status.as_str() {
+                            "A" => theme.diff_add_fg,
+                            "D" => theme.diff_del_fg,
+                            "R" | "C" => theme.diff_header_fg,
+                            _ => theme.label_fg,
+                        };
+                        let mut path_style = Style::default();
+                        if app.details_file_cursor == Some(idx) {
+                            path_style = path_style.add_modifier(Modifier::REVERSED);
+                        }
+                        lines.push(Line::from(vec![
+                            Span::styled(format!("  {} ", status), Style::default().fg(status_color)),
+                            Span::styled(path.as_str(), path_style),
+                        ]));
+                    }
+                }
+            }
+
+            lines.push(Line::from(vec![
+                Span::styled("Message: ", Style::default().fg(theme.label_fg)),
+            ]));
 
             // Word-wrap the commit message (UTF-8 safe)
             let wrap_width = area.width.saturating_sub(2) as usize; // Account for padding
@@ -474,29 +1008,55 @@ fn draw_commit_details(f: &mut Frame, app: &App, area: Rect) {
 
             lines.push(Line::from(""));
 
+            if let Some(changed) = app.pending_large_diff {
+                lines.push(Line::from(Span::styled(
+                    format!("Diff is large ({} lines) – press Enter on the commit graph to load it.", changed),
+                    Style::default().fg(theme.label_fg),
+                )));
+            }
+
             if let Some(ref diff) = app.current_diff {
                 lines.push(Line::from(Span::styled(
                     "Diff:",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.label_fg),
                 )));
                 lines.push(Line::from(""));
+                diff_start = lines.len();
 
                 // Split diff into lines and add color coding
-                for diff_line in diff.lines() {
+                let diff_lines: Vec<&str> = diff.lines().collect();
+                for (line_idx, &diff_line) in diff_lines.iter().enumerate() {
+                    // Summarize detected renames on the similarity line (one display line per diff line,
+                    // so diff search offsets stay valid)
+                    if let Some(header) = rename_header(&diff_lines, line_idx) {
+                        lines.push(Line::from(Span::styled(header, Style::default().fg(theme.diff_header_fg))));
+                        continue;
+                    }
+                    // Search matches get a background; the current one is reversed
+                    let match_style = match app.diff_search_results.iter().position(|&idx| idx == line_idx) {
+                        Some(pos) if pos == app.diff_search_current => Style::default().add_modifier(Modifier::REVERSED),
+                        Some(_) => Style::default().bg(theme.overlay_bg),
+                        None => Style::default(),
+                    };
+                    if app.diff_word_mode && !is_diff_header_line(diff_line) {
+                        lines.push(Line::from(word_diff_spans(diff_line, theme).into_iter().map(|span| span.patch_style(match_style)).collect::<Vec<_>>()));
+                        continue;
+                    }
+
                     let style = if diff_line.starts_with('+') {
-                        Style::default().fg(Color::Green)
+                        Style::default().fg(theme.diff_add_fg)
                     } else if diff_line.starts_with('-') {
-                        Style::default().fg(Color::Red)
+                        Style::default().fg(theme.diff_del_fg)
                     } else if diff_line.starts_with("@@") {
-                        Style::default().fg(Color::Cyan)
+                        Style::default().fg(theme.diff_hunk_fg)
                     } else if diff_line.starts_with("diff --git") {
-                        Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)
+                        Style::default().fg(theme.diff_header_fg).add_modifier(Modifier::BOLD)
                     } else if diff_line.starts_with("index ") || diff_line.starts_with("---") || diff_line.starts_with("+++") {
-                        Style::default().fg(Color::Gray)
+                        Style::default().fg(theme.muted_fg)
                     } else {
                         Style::default()
                     };
-                    lines.push(Line::from(Span::styled(diff_line, style)));
+                    lines.push(Line::from(Span::styled(diff_line, style.patch(match_style))));
                 }
             }
 
@@ -509,14 +1069,12 @@ fn draw_commit_details(f: &mut Frame, app: &App, area: Rect) {
                 .skip(app.details_scroll_offset)
                 .take(viewport_height)
                 .map(|line| {
-                    // Apply horizontal scrolling by skipping characters
+                    // Apply horizontal scrolling by skipping characters across the line's spans
+                    let mut skip = h_offset;
                     let spans: Vec<Span> = line.spans.into_iter().map(|span| {
-                        let content = span.content.to_string();
-                        let trimmed = if content.len() > h_offset {
-                            content.chars().skip(h_offset).collect()
-                        } else {
-                            String::new()
-                        };
+                        let chars = span.content.chars().count();
+                        let trimmed: String = span.content.chars().skip(skip).collect();
+                        skip = skip.saturating_sub(chars);
                         Span::styled(trimmed, span.style)
                     }).collect();
                     Line::from(spans)
@@ -525,15 +1083,39 @@ fn draw_commit_details(f: &mut Frame, app: &App, area: Rect) {
 
             let paragraph = Paragraph::new(visible_lines);
             f.render_widget(paragraph, inner_area);
+            app.details_diff_start = diff_start;
+
+            // Inline find bar over the last row of the pane
+            if app.mode == crate::app::AppMode::DiffSearch && inner_area.height > 0 {
+                let bar_area = Rect {
+                    x: inner_area.x,
+                    y: inner_area.y + inner_area.height - 1,
+                    width: inner_area.width,
+                    height: 1,
+                };
+                let match_info = if app.diff_search_results.is_empty() {
+                    if app.diff_search_query.is_empty() { String::new() } else { "No matches".to_string() }
+                } else {
+                    format!("Match {} of {}", app.diff_search_current + 1, app.diff_search_results.len())
+                };
+                let bar = Paragraph::new(Line::from(vec![
+                    Span::styled("Find: ", Style::default().fg(theme.label_fg)),
+                    Span::styled(format!("{}_", app.diff_search_query), Style::default().fg(theme.text_fg)),
+                    Span::styled(format!("  {}", match_info), Style::default().fg(theme.dim_fg)),
+                ]))
+                .style(Style::default().bg(theme.overlay_bg));
+                f.render_widget(Clear, bar_area);
+                f.render_widget(bar, bar_area);
+            }
         }
     } else {
         let msg = Paragraph::new("Select a commit to view details")
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.dim_fg));
         f.render_widget(msg, inner_area);
     }
 }
 
-fn draw_git_actions(f: &mut Frame, app: &mut App, area: Rect) {
+fn draw_git_actions(f: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     use crate::app::FocusedPane;
 
     let is_focused = app.focused_pane == FocusedPane::GitActions;
@@ -542,9 +1124,9 @@ fn draw_git_actions(f: &mut Frame, app: &mut App, area: Rect) {
         .title("Git Commands")
         .borders(Borders::ALL)
         .style(Style::default().fg(if is_focused {
-            Color::Yellow
+            theme.focused_pane_border
         } else {
-            Color::DarkGray
+            theme.pane_border
         }));
 
     let inner_area = block.inner(area);
@@ -564,7 +1146,7 @@ fn draw_git_actions(f: &mut Frame, app: &mut App, area: Rect) {
         .map(|(idx, cmd)| {
             let style = if idx == app.selected_command_idx && is_focused {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.selected_fg)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
@@ -584,8 +1166,92 @@ fn draw_git_actions(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_widget(list, inner_area);
 }
 
-fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
-    let status_text = if let Some(ref msg) = app.status_message {
+fn draw_tag_list(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    use crate::app::{FocusedPane, GpgTagStatus};
+
+    let is_focused = app.focused_pane == FocusedPane::TagList;
+
+    let block = Block::default()
+        .title(format!("Tags ({}) [sort: {}]", app.tag_entries.len(), app.tag_sort.label()))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(if is_focused {
+            theme.focused_pane_border
+        } else {
+            theme.pane_border
+        }));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.tag_entries.is_empty() {
+        let paragraph = Paragraph::new("No tags").style(Style::default().fg(theme.dim_fg));
+        f.render_widget(paragraph, inner_area);
+        return;
+    }
+
+    // Keep the selection visible without tracking a separate scroll offset
+    let viewport_height = inner_area.height as usize;
+    let skip = (app.selected_tag_idx + 1).saturating_sub(viewport_height);
+
+    let items: Vec<ListItem> = app
+        .tag_entries
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(viewport_height)
+        .map(|(idx, tag)| {
+            let is_selected = idx == app.selected_tag_idx && is_focused;
+            let prefix = if is_selected { "► " } else { "  " };
+
+            let style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            let badge = match app.tag_verification_cache.get(&tag.name) {
+                Some(GpgTagStatus::Good) => Span::styled(" ✓ signed", Style::default().fg(theme.success_fg)),
+                Some(GpgTagStatus::Bad) => Span::styled(" ✗ bad signature", Style::default().fg(theme.error_fg)),
+                Some(GpgTagStatus::NoPubkey) => Span::styled(" ? unknown key", Style::default().fg(theme.label_fg)),
+                Some(GpgTagStatus::Unsigned) | None => Span::raw(""),
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(format!("{} ", tag.name), style),
+                Span::styled(format!("{} ", &tag.commit_id[..7.min(tag.commit_id.len())]), Style::default().fg(theme.dim_fg)),
+                Span::styled(
+                    if tag.annotated { "annotated" } else { "lightweight" },
+                    Style::default().fg(if tag.annotated { theme.accent_fg } else { theme.dim_fg }),
+                ),
+                badge,
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items);
+    f.render_widget(list, inner_area);
+}
+
+fn draw_status_bar(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let status_text = if app.mode == crate::app::AppMode::Search {
+        let hint = if app.search_editing {
+            "Enter to finish, Esc to cancel"
+        } else {
+            "n/N next/prev, / new search, Esc to exit"
+        };
+        if app.filtered_graph_nodes.is_some() && !app.search_query.is_empty() {
+            format!(
+                "Showing {} of {} commits matching '{}' | {}",
+                app.search_result_count(),
+                app.graph_nodes.len(),
+                app.search_query,
+                hint
+            )
+        } else {
+            format!("/{} | {}", app.search_query, hint)
+        }
+    } else if let Some(ref msg) = app.status_message {
         msg.clone()
     } else if !app.has_git_repo {
         "⚠ No git repository found - some features may be unavailable".to_string()
@@ -610,23 +1276,42 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
             String::new()
         };
 
+        let last_command = if let Some(ref last) = app.last_command {
+            format!(" | Last: {}", last)
+        } else {
+            String::new()
+        };
+
         format!(
-            "Branch: {}{} | Remote: {}",
+            "Branch: {}{}{} | Remote: {}{}",
             branch,
+            if app.first_parent_only { " (first-parent)" } else { "" },
             ahead_behind,
-            remote_host
+            remote_host,
+            last_command
         )
     };
 
+    let status_text = if app.watch_mode {
+        format!("[WATCH] {}", status_text)
+    } else {
+        status_text
+    };
+
     let paragraph = Paragraph::new(status_text)
-        .style(Style::default().fg(Color::Rgb(185, 177, 160)).bg(Color::Rgb(90, 90, 90)))
+        .style(Style::default().fg(theme.status_bar_fg).bg(theme.status_bar_bg))
         .alignment(ratatui::layout::Alignment::Left);
 
     f.render_widget(paragraph, area);
 }
 
-fn draw_git_status(f: &mut Frame, app: &App, area: Rect) {
-    use crate::app::FocusedPane;
+fn draw_git_status(f: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
+    use crate::app::{AppMode, FocusedPane};
+
+    if app.mode == AppMode::ConflictResolution {
+        draw_conflict_files(f, app, area, theme);
+        return;
+    }
 
     let is_focused = app.focused_pane == FocusedPane::GitStatus;
 
@@ -634,36 +1319,42 @@ fn draw_git_status(f: &mut Frame, app: &App, area: Rect) {
         .title("Git Status")
         .borders(Borders::ALL)
         .style(Style::default().fg(if is_focused {
-            Color::Yellow
+            theme.focused_pane_border
         } else {
-            Color::DarkGray
+            theme.pane_border
         }));
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
+    app.status_viewport_height = inner_area.height as usize;
 
     if app.git_status_files.is_empty() {
         let msg = Paragraph::new("Working tree clean")
-            .style(Style::default().fg(Color::Green));
+            .style(Style::default().fg(theme.success_fg));
         f.render_widget(msg, inner_area);
         return;
     }
 
+    // Keep the selected file on screen (PageDown can jump past the last visible row)
+    let visible = inner_area.height as usize;
+    let skip = app.selected_file_idx.unwrap_or(0).saturating_sub(visible.saturating_sub(1));
+
     let items: Vec<ListItem> = app
         .git_status_files
         .iter()
         .enumerate()
+        .skip(skip)
         .map(|(idx, file)| {
             let (status_suffix, status_color) = match file.status {
-                crate::app::FileStatus::Staged => ("(staged)", Color::Green),
-                crate::app::FileStatus::Modified => ("(modified)", Color::Yellow),
-                crate::app::FileStatus::Untracked => ("(untracked)", Color::Red),
-                crate::app::FileStatus::Deleted => ("(deleted)", Color::Red),
+                crate::app::FileStatus::Staged => ("(staged)", theme.success_fg),
+                crate::app::FileStatus::Modified => ("(modified)", theme.label_fg),
+                crate::app::FileStatus::Untracked => ("(untracked)", theme.error_fg),
+                crate::app::FileStatus::Deleted => ("(deleted)", theme.error_fg),
             };
 
             let style = if Some(idx) == app.selected_file_idx && is_focused {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.selected_fg)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(status_color)
@@ -680,6 +1371,21 @@ fn draw_git_status(f: &mut Frame, app: &App, area: Rect) {
                 _ => "",
             };
 
+            // Just (un)staged: show where the file came from and where it went
+            if let Some((anim_idx, anim)) = app.file_staging_animation {
+                if anim_idx == idx {
+                    return ListItem::new(Line::from(vec![
+                        Span::styled(format!("{}{}{} ", selection_prefix, stage_prefix, file.path), style),
+                        Span::styled(format!("[{}]", anim.from.label()), Style::default().fg(theme.dim_fg)),
+                        Span::styled(" → ", Style::default().fg(theme.text_fg)),
+                        Span::styled(
+                            format!("[{}]", anim.to.label()),
+                            Style::default().fg(status_color).add_modifier(Modifier::BOLD),
+                        ),
+                    ]));
+                }
+            }
+
             ListItem::new(format!("{}{}{} {}", selection_prefix, stage_prefix, file.path, status_suffix)).style(style)
         })
         .collect();
@@ -688,7 +1394,7 @@ fn draw_git_status(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(list, inner_area);
 }
 
-fn draw_confirmation_dialog(f: &mut Frame, app: &App) {
+fn draw_confirmation_dialog(f: &mut Frame, app: &App, theme: &Theme) {
     // Get confirmation message - use detailed message if available, otherwise fallback
     let message = if let Some(ref msg) = app.pending_command_message {
         msg.as_str()
@@ -720,7 +1426,7 @@ fn draw_confirmation_dialog(f: &mut Frame, app: &App) {
     let block = Block::default()
         .title("Confirm Action")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Red));
+        .style(Style::default().fg(theme.alert_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
@@ -731,25 +1437,41 @@ fn draw_confirmation_dialog(f: &mut Frame, app: &App) {
     for line in message.lines() {
         text.push(Line::from(Span::styled(
             line,
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.label_fg),
         )));
     }
 
+    if matches!(app.pending_command, Some(crate::app::GitCommand::CherryPick)) {
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled("[E] Edit message: ", Style::default().fg(theme.muted_fg)),
+            if app.cherry_pick_edit {
+                Span::styled("on", Style::default().fg(theme.success_fg).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled("off", Style::default().fg(theme.dim_fg))
+            },
+        ]));
+    }
+
     text.push(Line::from(""));
     text.push(Line::from(Span::styled(
-        "Press 'y' to confirm, 'n' or Esc to cancel",
-        Style::default().fg(Color::Gray),
+        format!(
+            "Press '{}' to confirm, '{}' or Esc to cancel",
+            app.config.confirm_key, app.config.cancel_key
+        ),
+        Style::default().fg(theme.muted_fg),
     )));
 
     let paragraph = Paragraph::new(text);
     f.render_widget(paragraph, inner_area);
 }
 
-fn draw_commit_message_dialog(f: &mut Frame, app: &App) {
-    // Center the commit message dialog
+fn draw_commit_message_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    // Center the commit message dialog; wide enough to show the subject limit, taller as the message grows
     let area = f.area();
-    let popup_width = 70;
-    let popup_height = 8;
+    let line_count = app.commit_message_input.split('\n').count();
+    let popup_width = (app.config.max_commit_subject_length as u16 + 8).max(70);
+    let popup_height = (line_count as u16 + 6).clamp(10, 24);
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -761,38 +1483,126 @@ fn draw_commit_message_dialog(f: &mut Frame, app: &App) {
     // Clear the area behind the popup
     f.render_widget(Clear, popup_area);
 
+    let merging = app.mode == crate::app::AppMode::MergeMessage;
+
+    // Live subject length counter; over the limit is only a warning
+    let subject_len = app.commit_message_input.lines().next().unwrap_or("").chars().count();
+    let max_len = app.config.max_commit_subject_length;
+    let counter_color = if subject_len > max_len {
+        theme.error_fg
+    } else if subject_len + 10 >= max_len {
+        theme.label_fg
+    } else {
+        theme.success_fg
+    };
+
     let block = Block::default()
-        .title("Commit Message")
+        .title(Line::from(vec![
+            Span::styled(if merging { "Merge Message " } else { "Commit Message " }, Style::default().fg(theme.success_fg)),
+            Span::styled(format!("[{}/{}]", subject_len, max_len), Style::default().fg(counter_color)),
+        ]))
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Green));
+        .style(Style::default().fg(theme.input_dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
 
-    let text = vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "Enter your commit message:",
-            Style::default().fg(Color::Yellow),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(
-            &app.commit_message_input,
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
-        )),
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),    // Prompt
+            Constraint::Min(1),       // Editor
+            Constraint::Length(2),    // Key hints
+        ])
+        .split(inner_area);
+
+    let prompt = Paragraph::new(Line::from(Span::styled(
+        "Subject line, blank line, then body:",
+        Style::default().fg(theme.label_fg),
+    )));
+    f.render_widget(prompt, chunks[0]);
+
+    let input = &app.commit_message_input;
+    let cursor = cursor_byte_offset(input, app.input_cursor);
+    let body_style = Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD);
+    let sign_off_style = Style::default().fg(theme.dim_fg);
+    let overflow_style = Style::default().fg(theme.error_fg).add_modifier(Modifier::BOLD);
+
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for (line_idx, line) in input.split('\n').enumerate() {
+        let line_end = line_start + line.len();
+        // Sign-off trailers are a footer, not part of the message body
+        let text_style = if line_idx > 0 && line.starts_with("Signed-off-by: ") { sign_off_style } else { body_style };
+        let cursor_at = (line_start..=line_end).contains(&cursor).then(|| cursor - line_start);
+
+        // Subject chars past the limit are drawn in red
+        let overflow = if line_idx == 0 {
+            line.char_indices().nth(max_len).map(|(idx, _)| idx).unwrap_or(line.len())
+        } else {
+            line.len()
+        };
+
+        let mut cuts = vec![0, overflow, line.len()];
+        cuts.extend(cursor_at);
+        cuts.sort();
+        cuts.dedup();
+
+        let mut spans = Vec::new();
+        for window in cuts.windows(2) {
+            if cursor_at == Some(window[0]) {
+                spans.push(Span::styled("█", Style::default().fg(theme.muted_fg)));
+            }
+            let style = if window[0] >= overflow { overflow_style } else { text_style };
+            spans.push(Span::styled(line[window[0]..window[1]].to_string(), style));
+        }
+        if cursor_at == Some(line.len()) {
+            spans.push(Span::styled("█", Style::default().fg(theme.muted_fg)));
+        }
+
+        lines.push(Line::from(spans));
+        line_start = line_end + 1;
+    }
+
+    // Keep the cursor's line in view
+    let cursor_line = input[..cursor].matches('\n').count();
+    let scroll = cursor_line.saturating_sub(chunks[1].height.saturating_sub(1) as usize);
+    let editor = Paragraph::new(lines).scroll((scroll as u16, 0));
+    f.render_widget(editor, chunks[1]);
+
+    let hints = Paragraph::new(vec![
         Line::from(""),
         Line::from(Span::styled(
-            "Press Enter to commit, Esc to cancel",
-            Style::default().fg(Color::Gray),
+            if merging {
+                "Enter for a new line, Ctrl+Enter (or Ctrl+S) to create the merge commit, Esc to abort the merge"
+            } else {
+                "Enter new line, Ctrl/Alt+Enter commit, Ctrl+S sign off, Esc cancel"
+            },
+            Style::default().fg(theme.muted_fg),
         )),
-    ];
+    ]);
+    f.render_widget(hints, chunks[2]);
+}
 
-    let paragraph = Paragraph::new(text);
-    f.render_widget(paragraph, inner_area);
+fn draw_branch_name_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    draw_text_input_dialog(f, app, "Create Branch", "Enter branch name:", &app.branch_name_input, "Press Enter to create, Esc to cancel", theme);
 }
 
-fn draw_branch_name_dialog(f: &mut Frame, app: &App) {
-    // Center the branch name dialog
+fn draw_rename_branch_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let prompt = format!("New name for '{}':", app.pending_rename_branch.as_deref().unwrap_or(""));
+    draw_text_input_dialog(f, app, "Rename Branch", &prompt, &app.branch_name_input, "Press Enter to rename, Esc to cancel", theme);
+}
+
+fn draw_tag_name_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    draw_text_input_dialog(f, app, "Create Tag", "Enter tag name:", &app.tag_name_input, "Press Enter to create, Esc to cancel", theme);
+}
+
+fn draw_goto_hash_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    draw_text_input_dialog(f, app, "Go to Commit", "Enter a full or abbreviated hash (4+ characters):", &app.goto_hash_input, "Press Enter to jump, Esc to cancel", theme);
+}
+
+/// Centered single-line input dialog: prompt, the input with its cursor, and a key hint
+fn draw_text_input_dialog(f: &mut Frame, app: &App, title: &str, prompt: &str, input: &str, hint: &str, theme: &Theme) {
     let area = f.area();
     let popup_width = 70;
     let popup_height = 8;
@@ -808,9 +1618,9 @@ fn draw_branch_name_dialog(f: &mut Frame, app: &App) {
     f.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title("Create Branch")
+        .title(title.to_string())
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Green));
+        .style(Style::default().fg(theme.input_dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
@@ -818,18 +1628,15 @@ fn draw_branch_name_dialog(f: &mut Frame, app: &App) {
     let text = vec![
         Line::from(""),
         Line::from(Span::styled(
-            "Enter branch name:",
-            Style::default().fg(Color::Yellow),
+            prompt.to_string(),
+            Style::default().fg(theme.label_fg),
         )),
         Line::from(""),
-        Line::from(Span::styled(
-            &app.branch_name_input,
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
-        )),
+        input_line(input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme),
         Line::from(""),
         Line::from(Span::styled(
-            "Press Enter to create, Esc to cancel",
-            Style::default().fg(Color::Gray),
+            hint.to_string(),
+            Style::default().fg(theme.muted_fg),
         )),
     ];
 
@@ -837,7 +1644,7 @@ fn draw_branch_name_dialog(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, inner_area);
 }
 
-fn draw_branch_selection_dialog(f: &mut Frame, app: &App) {
+fn draw_branch_selection_dialog(f: &mut Frame, app: &App, theme: &Theme) {
     // Center the branch selection dialog
     let area = f.area();
     let popup_width = 60;
@@ -856,7 +1663,7 @@ fn draw_branch_selection_dialog(f: &mut Frame, app: &App) {
     let block = Block::default()
         .title("Select Branch to Checkout")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Cyan));
+        .style(Style::default().fg(theme.dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
@@ -868,10 +1675,10 @@ fn draw_branch_selection_dialog(f: &mut Frame, app: &App) {
         .map(|(idx, branch)| {
             let style = if idx == app.selected_branch_idx {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.selected_fg)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text_fg)
             };
 
             let prefix = if idx == app.selected_branch_idx {
@@ -888,11 +1695,10 @@ fn draw_branch_selection_dialog(f: &mut Frame, app: &App) {
     f.render_widget(list, inner_area);
 }
 
-fn draw_delete_branch_selection_dialog(f: &mut Frame, app: &App) {
-    // Center the delete branch selection dialog
+fn draw_remote_selection_dialog(f: &mut Frame, app: &App, theme: &Theme) {
     let area = f.area();
     let popup_width = 60;
-    let popup_height = (app.available_branches.len() + 6).min(20) as u16;
+    let popup_height = (app.available_remotes.len() + 5).min(20) as u16;
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -901,37 +1707,36 @@ fn draw_delete_branch_selection_dialog(f: &mut Frame, app: &App) {
         height: popup_height.min(area.height),
     };
 
-    // Clear the area behind the popup
     f.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title("Select Branch to Force Delete")
+        .title("Select Remote to Remove")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Red));
+        .style(Style::default().fg(theme.dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
 
     let items: Vec<ListItem> = app
-        .available_branches
+        .available_remotes
         .iter()
         .enumerate()
-        .map(|(idx, branch)| {
-            let style = if idx == app.selected_branch_idx {
+        .map(|(idx, remote)| {
+            let style = if idx == app.selected_remote_idx {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.selected_fg)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text_fg)
             };
 
-            let prefix = if idx == app.selected_branch_idx {
+            let prefix = if idx == app.selected_remote_idx {
                 "► "
             } else {
                 "  "
             };
 
-            ListItem::new(format!("{}{}", prefix, branch)).style(style)
+            ListItem::new(format!("{}{}", prefix, remote)).style(style)
         })
         .collect();
 
@@ -939,11 +1744,14 @@ fn draw_delete_branch_selection_dialog(f: &mut Frame, app: &App) {
     f.render_widget(list, inner_area);
 }
 
-fn draw_config_input_dialog(f: &mut Frame, app: &App, title: &str, input: &str) {
-    // Center the config input dialog
+fn draw_working_tree_summary_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let Some(summary) = &app.working_tree_summary else {
+        return;
+    };
+
     let area = f.area();
-    let popup_width = 70;
-    let popup_height = 8;
+    let popup_width = 80;
+    let popup_height = (summary.top_files.len() + 9) as u16;
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -952,43 +1760,126 @@ fn draw_config_input_dialog(f: &mut Frame, app: &App, title: &str, input: &str)
         height: popup_height.min(area.height),
     };
 
-    // Clear the area behind the popup
     f.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title(title)
+        .title("Uncommitted Changes")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Green));
+        .style(Style::default().fg(theme.dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
 
-    let text = vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "Enter value:",
-            Style::default().fg(Color::Yellow),
-        )),
+    let mut text = vec![
         Line::from(""),
-        Line::from(Span::styled(
-            input,
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
-        )),
+        Line::from(vec![
+            Span::styled(format!("{} file(s) changed, ", summary.files_changed), Style::default().fg(theme.text_fg)),
+            Span::styled(format!("+{}", summary.insertions), Style::default().fg(theme.diff_add_fg)),
+            Span::styled(" / ", Style::default().fg(theme.text_fg)),
+            Span::styled(format!("-{}", summary.deletions), Style::default().fg(theme.diff_del_fg)),
+        ]),
         Line::from(""),
-        Line::from(Span::styled(
-            "Press Enter to save, Esc to cancel",
-            Style::default().fg(Color::Gray),
-        )),
+        Line::from(Span::styled("Most changed files:", Style::default().fg(theme.label_fg))),
     ];
 
+    let path_width = (popup_width as usize).saturating_sub(24);
+    for (path, insertions, deletions) in &summary.top_files {
+        // Keep the end of long paths, which is the part that identifies the file
+        let shown: String = if path.chars().count() > path_width {
+            let tail: String = path.chars().rev().take(path_width - 3).collect::<Vec<_>>().into_iter().rev().collect();
+            format!("...{}", tail)
+        } else {
+            path.clone()
+        };
+
+        text.push(Line::from(vec![
+            Span::styled(format!("  {:<width$}", shown, width = path_width), Style::default().fg(theme.text_fg)),
+            Span::styled(format!(" {:>7}", format!("+{}", insertions)), Style::default().fg(theme.diff_add_fg)),
+            Span::styled(format!(" {:>7}", format!("-{}", deletions)), Style::default().fg(theme.diff_del_fg)),
+        ]));
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled(
+        "Press Enter or Esc to close",
+        Style::default().fg(theme.muted_fg),
+    )));
+
     let paragraph = Paragraph::new(text);
     f.render_widget(paragraph, inner_area);
 }
 
-fn draw_squash_count_dialog(f: &mut Frame, app: &App) {
+/// Contributors listed by name in the stats panel; the rest are only counted
+const STATS_TOP_CONTRIBUTORS: usize = 10;
+
+fn draw_repo_stats_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let stats = &app.repo_stats;
+    let label_style = Style::default().fg(theme.label_fg);
+    let value_style = Style::default().fg(theme.text_fg);
+    let loading = || Line::from(Span::styled("  loading...", Style::default().fg(theme.dim_fg)));
+    let row = |label: String, value: String| Line::from(vec![
+        Span::styled(format!("  {:<22}", label), label_style),
+        Span::styled(value, value_style),
+    ]);
+
+    let mut text = vec![
+        Line::from(""),
+        row("Git version".to_string(), stats.git_version.clone().unwrap_or_else(|| "unknown".to_string())),
+        Line::from(""),
+        Line::from(Span::styled("Objects", label_style.add_modifier(Modifier::BOLD))),
+    ];
+
+    match &stats.objects {
+        None => text.push(loading()),
+        Some(objects) => {
+            // count-objects' keys, in the order worth reading
+            for (key, label) in [
+                ("in-pack", "Packed objects"),
+                ("packs", "Packs"),
+                ("size-pack", "Pack size"),
+                ("count", "Loose objects"),
+                ("size", "Loose size"),
+                ("garbage", "Garbage files"),
+            ] {
+                if let Some((_, value)) = objects.iter().find(|(k, _)| k == key) {
+                    text.push(row(label.to_string(), value.clone()));
+                }
+            }
+        }
+    }
+
+    text.push(Line::from(""));
+    match &stats.contributors {
+        None => {
+            text.push(Line::from(Span::styled("Contributors", label_style.add_modifier(Modifier::BOLD))));
+            text.push(loading());
+        }
+        Some(contributors) => {
+            text.push(Line::from(Span::styled(
+                format!("Contributors ({} unique authors)", contributors.len()),
+                label_style.add_modifier(Modifier::BOLD),
+            )));
+            for (commits, name) in contributors.iter().take(STATS_TOP_CONTRIBUTORS) {
+                text.push(Line::from(vec![
+                    Span::styled(format!("  {:>7}  ", commits), Style::default().fg(theme.accent_fg)),
+                    Span::styled(name.clone(), value_style),
+                ]));
+            }
+            if contributors.len() > STATS_TOP_CONTRIBUTORS {
+                text.push(Line::from(Span::styled(
+                    format!("  ... and {} more", contributors.len() - STATS_TOP_CONTRIBUTORS),
+                    Style::default().fg(theme.dim_fg),
+                )));
+            }
+        }
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled("Press Esc to close", Style::default().fg(theme.muted_fg))));
+
     let area = f.area();
-    let popup_width = 70;
-    let popup_height = 8;
+    let popup_width = 64;
+    let popup_height = (text.len() + 2) as u16;
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -1000,39 +1891,73 @@ fn draw_squash_count_dialog(f: &mut Frame, app: &App) {
     f.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title("Squash Commits")
+        .title("Repository Statistics")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Yellow));
+        .style(Style::default().fg(theme.dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
+    f.render_widget(Paragraph::new(text), inner_area);
+}
 
-    let text = vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "Enter number of commits to squash (from HEAD):",
-            Style::default().fg(Color::Yellow),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(
-            &app.squash_count_input,
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Press Enter to continue, Esc to cancel",
-            Style::default().fg(Color::Gray),
-        )),
-    ];
+fn draw_worktree_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    // Center the worktree dialog
+    let area = f.area();
+    let popup_width = 90;
+    let popup_height = (app.worktrees.len() + 4).clamp(6, 20) as u16;
 
-    let paragraph = Paragraph::new(text);
-    f.render_widget(paragraph, inner_area);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Worktrees (n: new, o: open, Esc: close)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let items: Vec<ListItem> = app
+        .worktrees
+        .iter()
+        .enumerate()
+        .map(|(idx, worktree)| {
+            let is_selected = idx == app.selected_worktree_idx;
+            let style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            let branch = match &worktree.branch {
+                Some(branch) => Span::styled(format!("[{}] ", branch), Style::default().fg(theme.success_fg)),
+                None => Span::styled("(detached) ", Style::default().fg(theme.dim_fg)),
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(if is_selected { "► " } else { "  " }, style),
+                Span::styled(format!("{} ", &worktree.head[..7.min(worktree.head.len())]), Style::default().fg(theme.dim_fg)),
+                branch,
+                Span::styled(worktree.path.clone(), style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items);
+    f.render_widget(list, inner_area);
 }
 
-fn draw_reword_message_dialog(f: &mut Frame, app: &App) {
+fn draw_create_worktree_dialog(f: &mut Frame, app: &App, theme: &Theme) {
     let area = f.area();
     let popup_width = 70;
-    let popup_height = 12;
+    let popup_height = 10;
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -1044,28 +1969,36 @@ fn draw_reword_message_dialog(f: &mut Frame, app: &App) {
     f.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title("Reword Commit Message")
+        .title("New Worktree")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Cyan));
+        .style(Style::default().fg(theme.input_dialog_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
 
+    // Only the focused field shows the cursor
+    let field_spans = |input: &str, active: bool| {
+        if active {
+            input_line(input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme).spans
+        } else {
+            vec![Span::styled(input.to_string(), Style::default().fg(theme.muted_fg))]
+        }
+    };
+
+    let mut branch_spans = vec![Span::styled("Branch: ", Style::default().fg(theme.label_fg))];
+    branch_spans.extend(field_spans(&app.worktree_branch_input, !app.worktree_editing_path));
+    let mut path_spans = vec![Span::styled("Path:   ", Style::default().fg(theme.label_fg))];
+    path_spans.extend(field_spans(&app.worktree_path_input, app.worktree_editing_path));
+
     let text = vec![
         Line::from(""),
-        Line::from(Span::styled(
-            "Edit commit message:",
-            Style::default().fg(Color::Yellow),
-        )),
+        Line::from(branch_spans),
         Line::from(""),
-        Line::from(Span::styled(
-            &app.reword_message_input,
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
-        )),
+        Line::from(path_spans),
         Line::from(""),
         Line::from(Span::styled(
-            "Press Enter to save, Esc to cancel",
-            Style::default().fg(Color::Gray),
+            "Tab to switch field, Enter to create, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
         )),
     ];
 
@@ -1073,12 +2006,59 @@ fn draw_reword_message_dialog(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, inner_area);
 }
 
+/// The Git Status pane while resolving conflicts: unmerged files in red, staged ones in green
+fn draw_conflict_files(f: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
+    let operation = app.conflict_operation.map(|op| op.label()).unwrap_or("Operation");
+    let block = Block::default()
+        .title(format!("{} Conflicts (e: edit, a: stage, c: continue, Esc: close)", operation))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.alert_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+    app.status_viewport_height = inner_area.height as usize;
+
+    if app.conflict_files.is_empty() {
+        let msg = Paragraph::new("No conflicted files - press c to continue")
+            .style(Style::default().fg(theme.success_fg));
+        f.render_widget(msg, inner_area);
+        return;
+    }
+
+    let visible = inner_area.height as usize;
+    let skip = app.selected_conflict_idx.saturating_sub(visible.saturating_sub(1));
+
+    let items: Vec<ListItem> = app
+        .conflict_files
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(idx, file)| {
+            let selected = idx == app.selected_conflict_idx;
+            let selection_prefix = if selected { "► " } else { "  " };
+            let (label, color) = if file.resolved {
+                ("✓ ", theme.success_fg)
+            } else {
+                ("", theme.error_fg)
+            };
+            let suffix = if file.resolved { "(resolved)" } else { "(conflict)" };
+
+            let mut style = Style::default().fg(color);
+            if selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+
+            ListItem::new(format!("{}{}{} {}", selection_prefix, label, file.path, suffix)).style(style)
+        })
+        .collect();
 
+    f.render_widget(List::new(items), inner_area);
+}
 
-fn draw_assign_branch_name_dialog(f: &mut Frame, app: &App) {
+fn draw_yanked_hash_dialog(f: &mut Frame, app: &App, theme: &Theme) {
     let area = f.area();
-    let popup_width = 70;
-    let popup_height = 10;
+    let popup_width = 60;
+    let popup_height = 8;
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -1090,129 +2070,1337 @@ fn draw_assign_branch_name_dialog(f: &mut Frame, app: &App) {
     f.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title("Assign Commits to Branch")
+        .title("Commit Hash")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Yellow));
+        .style(Style::default().fg(theme.focused_pane_border));
 
     let inner_area = block.inner(popup_area);
     f.render_widget(block, popup_area);
 
     let text = vec![
-        Line::from(""),
         Line::from(Span::styled(
-            format!("Selected {} commit(s)", app.selected_commit_ids.len()),
-            Style::default().fg(Color::Green),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Enter branch name:",
-            Style::default().fg(Color::Yellow),
+            "No clipboard tool found (pbcopy, xclip, xsel, wl-copy, clip.exe).",
+            Style::default().fg(theme.muted_fg),
         )),
         Line::from(""),
         Line::from(Span::styled(
-            &app.assign_branch_name_input,
-            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            app.yanked_hash.as_deref().unwrap_or(""),
+            Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
-            "Press Enter to create/move branch, Esc to cancel",
-            Style::default().fg(Color::Gray),
+            "Select it with Shift+drag to copy, Esc to close",
+            Style::default().fg(theme.muted_fg),
         )),
     ];
 
-    let paragraph = Paragraph::new(text);
-    f.render_widget(paragraph, inner_area);
+    f.render_widget(Paragraph::new(text), inner_area);
 }
 
-fn draw_file_diff_fullscreen(f: &mut Frame, app: &App, area: Rect) {
-    // Get the selected file name for the title
-    let file_name = if let Some(idx) = app.selected_file_idx {
-        if let Some(file) = app.git_status_files.get(idx) {
-            file.path.clone()
-        } else {
-            "File Diff".to_string()
-        }
-    } else {
-        "File Diff".to_string()
+/// Instructions for the `rebase --onto` picks, along the bottom of the graph so the commits stay visible
+fn draw_rebase_onto_banner(f: &mut Frame, app: &App, graph_area: Rect, theme: &Theme) {
+    use crate::app::AppMode;
+
+    let banner_height = 5.min(graph_area.height);
+    let banner_area = Rect {
+        x: graph_area.x,
+        y: graph_area.y + graph_area.height - banner_height,
+        width: graph_area.width,
+        height: banner_height,
     };
 
+    f.render_widget(Clear, banner_area);
+
     let block = Block::default()
-        .title(format!("File Diff: {}", file_name))
+        .title("Rebase --onto")
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Yellow));
-
-    let inner_area = block.inner(area);
-    f.render_widget(block, area);
+        .style(Style::default().fg(theme.dialog_border));
 
-    if let Some(ref diff) = app.current_diff {
-        let mut lines = Vec::new();
+    let inner_area = block.inner(banner_area);
+    f.render_widget(block, banner_area);
 
-        // Split diff into lines and add color coding
-        for diff_line in diff.lines() {
-            let style = if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
-                Style::default().fg(Color::Green)
-            } else if diff_line.starts_with('-') && !diff_line.starts_with("---") {
-                Style::default().fg(Color::Red)
-            } else if diff_line.starts_with("@@") {
-                Style::default().fg(Color::Cyan)
-            } else {
-                Style::default()
-            };
+    let new_base = app.rebase_onto_new_base.as_deref().map_or("-", |id| &id[..7]);
+    let (step, instruction) = if app.mode == AppMode::RebaseOntoNewBase {
+        ("Step 1/2", "Select the NEW BASE commit to land on")
+    } else {
+        ("Step 2/2", "Select the UPSTREAM: commits after it, up to HEAD, are replayed")
+    };
 
-            lines.push(Line::from(Span::styled(diff_line, style)));
-        }
+    let text = vec![
+        Line::from(vec![
+            Span::styled(format!("{}: ", step), Style::default().fg(theme.label_fg).add_modifier(Modifier::BOLD)),
+            Span::styled(instruction, Style::default().fg(theme.text_fg)),
+        ]),
+        Line::from(vec![
+            Span::styled("New base: ", Style::default().fg(theme.label_fg)),
+            Span::styled(new_base, Style::default().fg(theme.accent_fg)),
+            Span::styled("   ↑/↓ move, Enter pick, Esc cancel", Style::default().fg(theme.muted_fg)),
+        ]),
+    ];
 
-        // Apply both vertical and horizontal scrolling
-        let paragraph = Paragraph::new(lines)
-            .scroll((app.details_scroll_offset as u16, app.details_horizontal_offset as u16));
-        f.render_widget(paragraph, inner_area);
-    } else {
-        let msg = Paragraph::new("No diff available\n\nPress Esc or 'q' to go back")
-            .style(Style::default().fg(Color::DarkGray));
-        f.render_widget(msg, inner_area);
-    }
+    f.render_widget(Paragraph::new(text), inner_area);
 }
 
-fn draw_help_screen(f: &mut Frame, app: &App, area: Rect) {
-    // Clear the screen
-    f.render_widget(Clear, area);
+fn draw_rebase_todo_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    use crate::app::RebaseAction;
 
-    let bg_color = Color::Rgb(40, 40, 40);
-    let title_color = Color::Cyan;
-    let section_color = Color::Yellow;
-    let key_color = Color::Green;
-    let desc_color = Color::White;
+    let area = f.area();
+    let popup_width = 90;
+    let popup_height = 24;
 
-    let mut help_lines = vec![];
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
 
-    // Title
-    help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
-    help_lines.push(Line::from(vec![
-        Span::styled("                      GIT MANAGER - Keyboard Shortcuts",
-            Style::default().fg(title_color))
-    ]));
-    help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
+    f.render_widget(Clear, popup_area);
 
-    // Navigation section
+    let base = app.rebase_todo_base.as_deref().map(|id| &id[..7.min(id.len())]).unwrap_or("");
+    let block = Block::default()
+        .title(format!("Interactive Rebase onto {} (Space: change action, Enter: start, Esc: cancel)", base))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    // Keep the selection visible
+    let viewport_height = inner_area.height as usize;
+    let skip = (app.selected_rebase_todo_idx + 1).saturating_sub(viewport_height);
+
+    let items: Vec<ListItem> = app
+        .rebase_todo_entries
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(viewport_height)
+        .map(|(idx, entry)| {
+            let is_selected = idx == app.selected_rebase_todo_idx;
+            let prefix = if is_selected { "► " } else { "  " };
+            let action_color = match entry.action {
+                RebaseAction::Pick => theme.text_fg,
+                RebaseAction::Reword => theme.accent_fg,
+                RebaseAction::Edit => theme.label_fg,
+                RebaseAction::Squash | RebaseAction::Fixup => theme.tag_fg,
+                RebaseAction::Drop => theme.error_fg,
+            };
+            let mut message_style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+            if entry.action == RebaseAction::Drop {
+                message_style = message_style.fg(theme.dim_fg).add_modifier(Modifier::CROSSED_OUT);
+            }
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, message_style),
+                Span::styled(format!("{:<7} ", entry.action.keyword()), Style::default().fg(action_color).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} ", &entry.hash[..7.min(entry.hash.len())]), Style::default().fg(theme.label_fg)),
+                Span::styled(entry.message.as_str(), message_style),
+            ]))
+        })
+        .collect();
+
+    f.render_widget(List::new(items), inner_area);
+}
+
+fn draw_push_target_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 10;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Push To")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.input_dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    // Only the focused field shows the cursor
+    let field_spans = |input: &str, active: bool| {
+        if active {
+            input_line(input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme).spans
+        } else {
+            vec![Span::styled(input.to_string(), Style::default().fg(theme.muted_fg))]
+        }
+    };
+
+    let mut remote_spans = vec![Span::styled("Remote:  ", Style::default().fg(theme.label_fg))];
+    remote_spans.extend(field_spans(&app.push_remote_input, !app.push_editing_refspec));
+    let mut refspec_spans = vec![Span::styled("Refspec: ", Style::default().fg(theme.label_fg))];
+    refspec_spans.extend(field_spans(&app.push_refspec_input, app.push_editing_refspec));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(remote_spans),
+        Line::from(""),
+        Line::from(refspec_spans),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Tab to switch field, Enter to push, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+/// Add remote (name + URL) and rename remote (remote + new name) share one two-field form
+fn draw_remote_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    use crate::app::AppMode;
+
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 10;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let adding = app.mode == AppMode::AddRemote;
+    let block = Block::default()
+        .title(if adding { "Add Remote" } else { "Rename Remote" })
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.input_dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    // Only the focused field shows the cursor
+    let field_spans = |input: &str, active: bool| {
+        if active {
+            input_line(input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme).spans
+        } else {
+            vec![Span::styled(input.to_string(), Style::default().fg(theme.muted_fg))]
+        }
+    };
+
+    let (first_label, second_label, second_input, action) = if adding {
+        ("Name:     ", "URL:      ", &app.remote_url_input, "add")
+    } else {
+        ("Remote:   ", "New name: ", &app.remote_new_name_input, "rename")
+    };
+
+    let mut first_spans = vec![Span::styled(first_label, Style::default().fg(theme.label_fg))];
+    first_spans.extend(field_spans(&app.remote_name_input, !app.remote_editing_second));
+    let mut second_spans = vec![Span::styled(second_label, Style::default().fg(theme.label_fg))];
+    second_spans.extend(field_spans(second_input, app.remote_editing_second));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(first_spans),
+        Line::from(""),
+        Line::from(second_spans),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Tab to switch field, Enter to {}, Esc to cancel", action),
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_set_upstream_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 10;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let title = format!("Set Upstream for {}", app.current_branch.as_deref().unwrap_or("HEAD"));
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.input_dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    // Only the focused field shows the cursor
+    let field_spans = |input: &str, active: bool| {
+        if active {
+            input_line(input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme).spans
+        } else {
+            vec![Span::styled(input.to_string(), Style::default().fg(theme.muted_fg))]
+        }
+    };
+
+    let mut remote_spans = vec![Span::styled("Remote: ", Style::default().fg(theme.label_fg))];
+    remote_spans.extend(field_spans(&app.upstream_remote_input, !app.upstream_editing_branch));
+    let mut branch_spans = vec![Span::styled("Branch: ", Style::default().fg(theme.label_fg))];
+    branch_spans.extend(field_spans(&app.upstream_branch_input, app.upstream_editing_branch));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(remote_spans),
+        Line::from(""),
+        Line::from(branch_spans),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Tab to switch field, Enter to set, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_quick_log_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    // Center the quick log dialog
+    let area = f.area();
+    let popup_width = 80;
+    let popup_height = 24;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, popup_area);
+
+    let matches = app.quick_log_matches();
+
+    let block = Block::default()
+        .title(format!("Quick Log ({}/{}) (Enter: jump, Esc: close)", matches.len(), app.quick_log_entries.len()))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),  // Filter input
+            Constraint::Min(1),     // Matches
+        ])
+        .split(inner_area);
+
+    let filter = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(theme.label_fg)),
+        Span::styled(app.quick_log_query.as_str(), Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD)),
+    ]));
+    f.render_widget(filter, chunks[0]);
+
+    // Keep the selection visible
+    let viewport_height = chunks[1].height as usize;
+    let skip = (app.quick_log_selected + 1).saturating_sub(viewport_height);
+
+    let items: Vec<ListItem> = matches
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(viewport_height)
+        .map(|(idx, (entry, positions))| {
+            let is_selected = idx == app.quick_log_selected;
+            let style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            let mut spans = vec![
+                Span::styled(if is_selected { "► " } else { "  " }, style),
+                Span::styled(format!("{} ", entry.short_id), Style::default().fg(theme.dim_fg)),
+            ];
+            // Highlight the characters the filter matched
+            for (char_idx, c) in entry.subject.chars().enumerate() {
+                let char_style = if positions.contains(&char_idx) {
+                    style.fg(theme.match_fg).add_modifier(Modifier::BOLD)
+                } else {
+                    style
+                };
+                spans.push(Span::styled(c.to_string(), char_style));
+            }
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items);
+    f.render_widget(list, chunks[1]);
+}
+
+fn draw_hooks_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    // Center the hooks dialog
+    let area = f.area();
+    let popup_width = 80;
+    let popup_height = (app.hooks.len() + 4).clamp(6, 24) as u16;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Git Hooks (Space: enable/disable, Esc: close)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    if app.hooks.is_empty() {
+        let msg = Paragraph::new("No hooks installed")
+            .style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, inner_area);
+        return;
+    }
+
+    // Keep the selected hook on screen
+    let visible = inner_area.height as usize;
+    let skip = app.selected_hook_idx.saturating_sub(visible.saturating_sub(1));
+
+    let items: Vec<ListItem> = app
+        .hooks
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(idx, hook)| {
+            let is_selected = idx == app.selected_hook_idx;
+            let prefix = if is_selected { "► " } else { "  " };
+
+            let (status, status_color) = if hook.enabled {
+                ("[enabled] ", theme.success_fg)
+            } else if hook.is_sample {
+                ("[sample]  ", theme.dim_fg)
+            } else {
+                ("[disabled]", theme.error_fg)
+            };
+
+            let name_style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, name_style),
+                Span::styled(status, Style::default().fg(status_color)),
+                Span::styled(format!(" {:<28}", hook.name), name_style),
+                Span::styled(hook.preview.clone(), Style::default().fg(theme.dim_fg)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items);
+    f.render_widget(list, inner_area);
+}
+
+fn draw_delete_branch_selection_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    use crate::app::DeleteSelection;
+
+    // Center the delete branch selection dialog
+    let area = f.area();
+    let popup_width = 60;
+    let popup_height = (app.available_branches.len() + 6).min(20) as u16;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(match app.delete_selection {
+            DeleteSelection::Branches => "Select Branches to Force Delete (Space: mark, Enter: delete)",
+            DeleteSelection::Tags => "Select Tag to Delete (Space: mark, Enter: delete)",
+            DeleteSelection::RemoteBranches => "Select Remote Branches to Delete (Space: mark, Enter: delete)",
+            DeleteSelection::MergedBranches => "Merged Branches to Delete (Space: mark, Enter: delete)",
+        })
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.alert_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let items: Vec<ListItem> = app
+        .available_branches
+        .iter()
+        .enumerate()
+        .map(|(idx, branch)| {
+            let style = if idx == app.selected_branch_idx {
+                Style::default()
+                    .fg(theme.selected_fg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            let prefix = if idx == app.selected_branch_idx {
+                "► "
+            } else {
+                "  "
+            };
+
+            let checkbox = if app.branches_marked_for_delete.contains(branch) { "[x] " } else { "[ ] " };
+
+            ListItem::new(format!("{}{}{}", prefix, checkbox, branch)).style(style)
+        })
+        .collect();
+
+    // Stateful so long lists (e.g. many merged branches) scroll with the highlight
+    let list = List::new(items);
+    let mut state = ratatui::widgets::ListState::default().with_selected(Some(app.selected_branch_idx));
+    f.render_stateful_widget(list, inner_area, &mut state);
+}
+
+/// A single-line text input with a block cursor `chars_after` chars before the end
+fn input_line(input: &str, chars_after: usize, style: Style, theme: &Theme) -> Line<'static> {
+    let offset = cursor_byte_offset(input, chars_after);
+    Line::from(vec![
+        Span::styled(input[..offset].to_string(), style),
+        Span::styled("█", Style::default().fg(theme.muted_fg)),
+        Span::styled(input[offset..].to_string(), style),
+    ])
+}
+
+fn draw_config_input_dialog(f: &mut Frame, app: &App, title: &str, input: &str, theme: &Theme) {
+    // Center the config input dialog
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 8;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.input_dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter value:",
+            Style::default().fg(theme.label_fg),
+        )),
+        Line::from(""),
+        input_line(input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press Enter to save, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_squash_count_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 8;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Squash Commits")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter number of commits to squash (from HEAD):",
+            Style::default().fg(theme.label_fg),
+        )),
+        Line::from(""),
+        input_line(&app.squash_count_input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press Enter to continue, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_reword_message_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 12;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Reword Commit Message")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.dialog_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Edit commit message:",
+            Style::default().fg(theme.label_fg),
+        )),
+        Line::from(""),
+        input_line(&app.reword_message_input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press Enter to save, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+
+
+fn draw_assign_branch_name_dialog(f: &mut Frame, app: &App, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 70;
+    let popup_height = 10;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Assign Commits to Branch")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Selected {} commit(s)", app.selected_commit_ids.len()),
+            Style::default().fg(theme.success_fg),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter branch name:",
+            Style::default().fg(theme.label_fg),
+        )),
+        Line::from(""),
+        input_line(&app.assign_branch_name_input, app.input_cursor, Style::default().fg(theme.text_fg).add_modifier(Modifier::BOLD), theme),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press Enter to create/move branch, Esc to cancel",
+            Style::default().fg(theme.muted_fg),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text);
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_stash_list(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .title("Stashes (Space: apply, Enter: pop, D: drop, v: show, Ctrl+D: compare, Esc: close)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let items: Vec<ListItem> = app
+        .stash_entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let is_selected = idx == app.selected_stash_idx;
+            let prefix = if is_selected { "► " } else { "  " };
+            let marker = if app.stash_compare_idx == Some(idx) { "◆ " } else { "  " };
+
+            let style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, style),
+                Span::styled(marker, Style::default().fg(theme.tag_fg)),
+                Span::styled(format!("{:<11}", entry.name()), style),
+                Span::styled(format!("{} ", &entry.oid[..7.min(entry.oid.len())]), Style::default().fg(theme.dim_fg)),
+                Span::styled(format!("({}) ", entry.branch), Style::default().fg(theme.accent_fg)),
+                Span::styled(entry.message.clone(), style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items);
+    f.render_widget(list, inner_area);
+}
+
+fn draw_stash_diff(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let title = app.stash_diff_title.as_deref().unwrap_or("Stash Diff");
+
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.pane_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if let Some(ref diff) = app.stash_diff {
+        let mut lines = Vec::new();
+
+        // Split diff into lines and add color coding
+        for diff_line in diff.lines() {
+            let style = if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
+                Style::default().fg(theme.diff_add_fg)
+            } else if diff_line.starts_with('-') && !diff_line.starts_with("---") {
+                Style::default().fg(theme.diff_del_fg)
+            } else if diff_line.starts_with("@@") {
+                Style::default().fg(theme.diff_hunk_fg)
+            } else if diff_line.starts_with("diff --git") {
+                Style::default().fg(theme.diff_header_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            lines.push(Line::from(Span::styled(diff_line, style)));
+        }
+
+        if !app.stash_untracked_files.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Untracked files:",
+                Style::default().fg(theme.diff_header_fg).add_modifier(Modifier::BOLD),
+            )));
+            for path in &app.stash_untracked_files {
+                lines.push(Line::from(vec![
+                    Span::styled("  [new file] ", Style::default().fg(theme.diff_add_fg)),
+                    Span::styled(path.as_str(), Style::default().fg(theme.text_fg)),
+                ]));
+            }
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .scroll((app.details_scroll_offset as u16, 0));
+        f.render_widget(paragraph, inner_area);
+    } else {
+        let msg = Paragraph::new("Press v to show a stash, or Ctrl+D on two stashes to compare them\n\nPgUp/PgDn scrolls this pane")
+            .style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, inner_area);
+    }
+}
+
+fn draw_commit_tree(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let short_id = app.commit_tree_commit_id.as_deref().map(|id| &id[..7.min(id.len())]).unwrap_or("");
+
+    let block = Block::default()
+        .title(format!("Changed Files {} (Enter: open diff, Esc: close)", short_id))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // Keep the selected row on screen
+    let visible = inner_area.height as usize;
+    let skip = app.selected_tree_idx.saturating_sub(visible.saturating_sub(1));
+
+    let items: Vec<ListItem> = app
+        .commit_tree_entries
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(idx, entry)| {
+            let is_selected = idx == app.selected_tree_idx;
+            let prefix = if is_selected { "► " } else { "  " };
+            let indent = "  ".repeat(entry.depth);
+
+            let name_style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else if entry.path.is_none() {
+                Style::default().fg(theme.accent_fg)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            let mut spans = vec![
+                Span::styled(prefix, name_style),
+                Span::raw(indent),
+                Span::styled(entry.name.clone(), name_style),
+            ];
+
+            if entry.path.is_some() {
+                match (entry.insertions, entry.deletions) {
+                    (Some(insertions), Some(deletions)) => {
+                        spans.push(Span::styled(format!(" +{}", insertions), Style::default().fg(theme.diff_add_fg)));
+                        spans.push(Span::styled(format!(" -{}", deletions), Style::default().fg(theme.diff_del_fg)));
+                    }
+                    _ => spans.push(Span::styled(" (binary)", Style::default().fg(theme.dim_fg))),
+                }
+            }
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items);
+    f.render_widget(list, inner_area);
+}
+
+/// The details pane showing one side of a git status file's changes instead of the selected commit
+fn draw_status_file_diff(f: &mut Frame, app: &mut App, area: Rect, is_focused: bool, theme: &Theme) {
+    use crate::app::FileDiffMode;
+
+    let Some((path, diff)) = &app.status_file_diff else {
+        return;
+    };
+    let side = if app.file_diff_mode == FileDiffMode::Staged { "Staged" } else { "Unstaged" };
+
+    let block = Block::default()
+        .title(format!("{} Diff: {} {}", side, path, diff_settings_label(app)))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(if is_focused {
+            theme.focused_pane_border
+        } else {
+            theme.pane_border
+        }));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+    app.details_viewport_height = inner_area.height as usize;
+
+    if diff.is_empty() {
+        let msg = Paragraph::new(format!("No {} changes in {}", side.to_lowercase(), path))
+            .style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, inner_area);
+        return;
+    }
+
+    let lines: Vec<Line> = diff
+        .lines()
+        .map(|diff_line| {
+            if app.diff_word_mode && !is_diff_header_line(diff_line) {
+                return Line::from(word_diff_spans(diff_line, theme));
+            }
+            let style = if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
+                Style::default().fg(theme.diff_add_fg)
+            } else if diff_line.starts_with('-') && !diff_line.starts_with("---") {
+                Style::default().fg(theme.diff_del_fg)
+            } else if diff_line.starts_with("@@") {
+                Style::default().fg(theme.diff_hunk_fg)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+            Line::from(Span::styled(diff_line, style))
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines)
+        .scroll((app.details_scroll_offset as u16, app.details_horizontal_offset as u16));
+    f.render_widget(paragraph, inner_area);
+}
+
+fn draw_file_diff_fullscreen(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    // Get the selected file name for the title
+    let file_name = if let Some(idx) = app.selected_file_idx {
+        if let Some(file) = app.git_status_files.get(idx) {
+            file.path.clone()
+        } else {
+            "File Diff".to_string()
+        }
+    } else {
+        "File Diff".to_string()
+    };
+
+    let block = Block::default()
+        .title(format!("File Diff: {}", file_name))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if let Some(ref diff) = app.current_diff {
+        let mut lines = Vec::new();
+
+        // Split diff into lines and add color coding
+        for diff_line in diff.lines() {
+            let style = if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
+                Style::default().fg(theme.diff_add_fg)
+            } else if diff_line.starts_with('-') && !diff_line.starts_with("---") {
+                Style::default().fg(theme.diff_del_fg)
+            } else if diff_line.starts_with("@@") {
+                Style::default().fg(theme.diff_hunk_fg)
+            } else {
+                Style::default()
+            };
+
+            lines.push(Line::from(Span::styled(diff_line, style)));
+        }
+
+        // Apply both vertical and horizontal scrolling
+        let paragraph = Paragraph::new(lines)
+            .scroll((app.details_scroll_offset as u16, app.details_horizontal_offset as u16));
+        f.render_widget(paragraph, inner_area);
+    } else {
+        let msg = Paragraph::new("No diff available\n\nPress Esc or 'q' to go back")
+            .style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, inner_area);
+    }
+}
+
+fn draw_reflog_fullscreen(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .title(if app.reflog_orphans_only {
+            format!("Orphaned Commits ({}) (c: create branch, Enter: jump to commit, Esc: close)", app.reflog_entries.len())
+        } else {
+            format!("Reflog ({}) (Enter: jump to commit, c: create branch, Esc: close)", app.reflog_entries.len())
+        })
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.reflog_entries.is_empty() {
+        let msg = Paragraph::new("Reflog is empty\n\nPress Esc or 'q' to go back")
+            .style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, inner_area);
+        return;
+    }
+
+    // Keep the selection visible
+    let viewport_height = inner_area.height as usize;
+    let skip = (app.selected_reflog_idx + 1).saturating_sub(viewport_height);
+
+    let items: Vec<ListItem> = app
+        .reflog_entries
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(viewport_height)
+        .map(|(idx, entry)| {
+            let is_selected = idx == app.selected_reflog_idx;
+            let prefix = if is_selected { "► " } else { "  " };
+            let text_style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(prefix, text_style),
+                Span::styled(format!("{} ", &entry.hash[..7.min(entry.hash.len())]), Style::default().fg(theme.label_fg)),
+                Span::styled(format!("{:<12} ", entry.refname), Style::default().fg(theme.accent_fg)),
+                Span::styled(entry.description.as_str(), text_style),
+            ]))
+        })
+        .collect();
+
+    f.render_widget(List::new(items), inner_area);
+}
+
+/// Commits that touched one file, as a single-lane graph above the selected commit's diff of that file
+fn draw_file_history(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    let path = app.file_history_path.as_deref().unwrap_or("");
+    let block = Block::default()
+        .title(format!("History: {} ({} commits) (Enter: jump to commit, Esc: close)", path, app.file_history_nodes.len()))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(chunks[0]);
+    f.render_widget(block, chunks[0]);
+
+    let renderer = Renderer::new(*theme);
+    let no_lanes = std::collections::HashMap::new();
+    let viewport_height = inner_area.height as usize;
+    let skip = (app.selected_file_history_idx + 1).saturating_sub(viewport_height);
+
+    let items: Vec<ListItem> = app
+        .file_history_nodes
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(viewport_height)
+        .map(|(idx, node)| {
+            let is_selected = idx == app.selected_file_history_idx;
+            let text_style = if is_selected {
+                Style::default().fg(theme.selected_commit_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            // Only the commit marker: the history is linear, so there are no lanes to draw
+            let mut spans = renderer
+                .render_node_row(node, 1, &[], &no_lanes, false, crate::graph::SyncStatus::Synced, false)
+                .spans;
+            spans.push(Span::styled(format!("{} ", node.commit.short_id), Style::default().fg(theme.label_fg)));
+            spans.push(Span::styled(format!("{:<12} ", relative_time(node.commit.timestamp)), Style::default().fg(theme.muted_fg)));
+            spans.push(Span::styled(format!("{:<16} ", truncate_with_ellipsis(&node.commit.author, 16)), Style::default().fg(theme.accent_fg)));
+            spans.push(Span::styled(node.commit.message.clone(), text_style));
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    f.render_widget(List::new(items), inner_area);
+
+    let diff_block = Block::default()
+        .title("Changes to this file (PageUp/PageDown: scroll)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.pane_border));
+
+    let diff_area = diff_block.inner(chunks[1]);
+    f.render_widget(diff_block, chunks[1]);
+
+    let Some(diff) = app.file_history_diff.as_deref() else {
+        let msg = Paragraph::new("No diff available").style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, diff_area);
+        return;
+    };
+
+    let lines: Vec<Line> = diff
+        .lines()
+        .map(|diff_line| {
+            let style = if diff_line.starts_with('+') && !diff_line.starts_with("+++") {
+                Style::default().fg(theme.diff_add_fg)
+            } else if diff_line.starts_with('-') && !diff_line.starts_with("---") {
+                Style::default().fg(theme.diff_del_fg)
+            } else if diff_line.starts_with("@@") {
+                Style::default().fg(theme.diff_hunk_fg)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+            Line::from(Span::styled(diff_line, style))
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).scroll((app.file_history_scroll as u16, 0)), diff_area);
+}
+
+fn draw_blame_view(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .title(format!("Blame: {} (Enter: jump to commit, Esc: close)", app.blame_path))
+        .borders(Borders::ALL)
+        .style(Style::default().fg(theme.focused_pane_border));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.blame_lines.is_empty() {
+        let msg = Paragraph::new("File is empty\n\nPress Esc or 'q' to go back")
+            .style(Style::default().fg(theme.dim_fg));
+        f.render_widget(msg, inner_area);
+        return;
+    }
+
+    // Keep the selection visible
+    let viewport_height = inner_area.height as usize;
+    let skip = (app.selected_blame_idx + 1).saturating_sub(viewport_height);
+    let number_width = app.blame_lines.len().to_string().len();
+
+    let items: Vec<ListItem> = app
+        .blame_lines
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(viewport_height)
+        .map(|(idx, line)| {
+            let is_selected = idx == app.selected_blame_idx;
+            // Only the first line of each run of lines from the same commit gets a gutter
+            let starts_run = idx == 0 || app.blame_lines[idx - 1].commit_hash != line.commit_hash;
+            let gutter = if starts_run || is_selected {
+                format!(
+                    "{} {:<16} {:<10} ",
+                    &line.commit_hash[..7.min(line.commit_hash.len())],
+                    truncate_with_ellipsis(&line.author, 16),
+                    line.date
+                )
+            } else {
+                " ".repeat(37)
+            };
+
+            let gutter_style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.label_fg)
+            };
+            let content_style = if is_selected {
+                Style::default().fg(theme.selected_fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_fg)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(gutter, gutter_style),
+                Span::styled(format!("{:>width$} │ ", idx + 1, width = number_width), Style::default().fg(theme.dim_fg)),
+                Span::styled(line.content.replace('\t', "    "), content_style),
+            ]))
+        })
+        .collect();
+
+    f.render_widget(List::new(items), inner_area);
+}
+
+/// A help screen key column entry, padded to line up the descriptions
+fn help_key(key: impl std::fmt::Display) -> String {
+    format!("    {:<30}", key.to_string())
+}
+
+fn draw_help_screen(f: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    // Clear the screen
+    f.render_widget(Clear, area);
+
+    let bg_color = theme.overlay_bg;
+    let title_color = theme.accent_fg;
+    let section_color = theme.label_fg;
+    let key_color = theme.help_key_fg;
+    let desc_color = theme.text_fg;
+
+    let mut help_lines = vec![];
+
+    // Title
+    help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
+    help_lines.push(Line::from(vec![
+        Span::styled("                      GIT MANAGER - Keyboard Shortcuts",
+            Style::default().fg(title_color))
+    ]));
+    help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
+
+    // Navigation section
     help_lines.push(Line::from(vec![
         Span::styled("  NAVIGATION", Style::default().fg(section_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Tab                           ", Style::default().fg(key_color)),
+        Span::styled(help_key(app.config.keys.next_pane), Style::default().fg(key_color)),
         Span::styled("Switch between panes", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Arrow Keys                    ", Style::default().fg(key_color)),
+        Span::styled(help_key(format!("{} / {} / Left / Right", app.config.keys.move_up, app.config.keys.move_down)), Style::default().fg(key_color)),
         Span::styled("Navigate and scroll", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Enter                         ", Style::default().fg(key_color)),
+        Span::styled("    Ctrl+Shift+Left/Right         ", Style::default().fg(key_color)),
+        Span::styled("Move focused pane to previous/next slot", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.select), Style::default().fg(key_color)),
         Span::styled("Load diff / Execute action / Toggle details", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Space                         ", Style::default().fg(key_color)),
+        Span::styled(help_key(format!("{} / {}", app.config.keys.page_up, app.config.keys.page_down)), Style::default().fg(key_color)),
+        Span::styled("Jump half a page in the graph, a page in details/status", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.toggle_stage), Style::default().fg(key_color)),
         Span::styled("Toggle selection (for multi-commit ops)", Style::default().fg(desc_color))
     ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit details)", app.config.keys.cycle_parent)), Style::default().fg(key_color)),
+        Span::styled("Highlight next merge parent (Enter jumps to it)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit details)", app.config.keys.commit_tree)), Style::default().fg(key_color)),
+        Span::styled("Browse changed files as a tree", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} / {} (on commit details)", app.config.keys.prev_file, app.config.keys.next_file)), Style::default().fg(key_color)),
+        Span::styled("Jump to previous/next changed file in the diff", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit details)", app.config.keys.toggle_file_list)), Style::default().fg(key_color)),
+        Span::styled("Collapse/expand the changed file list", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} / {} (on commit details)", app.config.keys.staged_diff, app.config.keys.unstaged_diff)), Style::default().fg(key_color)),
+        Span::styled("Show the git status file's staged/unstaged diff (again: back)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} / {} (on commit details)", app.config.keys.more_context, app.config.keys.less_context)), Style::default().fg(key_color)),
+        Span::styled("More/fewer diff context lines (0-20)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit details)", app.config.keys.word_diff)), Style::default().fg(key_color)),
+        Span::styled("Toggle word diff ([-removed-]{+added+} within lines)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit details)", app.config.keys.ignore_whitespace)), Style::default().fg(key_color)),
+        Span::styled("Toggle ignoring whitespace changes ([no-ws] in the title)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} / {} (on commit details)", app.config.keys.search, app.config.keys.diff_search)), Style::default().fg(key_color)),
+        Span::styled("Find in diff (Enter keeps the matches, Esc clears them)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} / {} (on commit details)", app.config.keys.next_match, app.config.keys.prev_match)), Style::default().fg(key_color)),
+        Span::styled("Next/previous diff search match", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.search)), Style::default().fg(key_color)),
+        Span::styled("Filter commits by message, author or hash", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.show_all_branches), Style::default().fg(key_color)),
+        Span::styled("Toggle all branches / current branch only", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.first_parent)), Style::default().fg(key_color)),
+        Span::styled("Toggle first-parent only (main line from HEAD)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} / {} (on git status)", app.config.keys.stage_all, app.config.keys.unstage_all)), Style::default().fg(key_color)),
+        Span::styled("Stage all / unstage all changes", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on git status)", app.config.keys.file_history)), Style::default().fg(key_color)),
+        Span::styled("Show the commits that touched the file, with each one's diff", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on git status)", app.config.keys.blame_file)), Style::default().fg(key_color)),
+        Span::styled("Show git blame for the file (Enter on a line jumps to its commit)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on git status)", app.config.keys.edit_file)), Style::default().fg(key_color)),
+        Span::styled("Open the file in the editor (config, $GIT_EDITOR, $VISUAL, $EDITOR)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on git status)", app.config.keys.stage_patch)), Style::default().fg(key_color)),
+        Span::styled("Stage a modified file hunk by hunk (git add -p)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on git status)", app.config.keys.discard_file)), Style::default().fg(key_color)),
+        Span::styled("Discard a file's unstaged changes (asks first)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.cycle_sort)), Style::default().fg(key_color)),
+        Span::styled("Cycle sort order (topo / author date / committer date)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.toggle_reviewed)), Style::default().fg(key_color)),
+        Span::styled("Mark/unmark commit as reviewed", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.toggle_compact)), Style::default().fg(key_color)),
+        Span::styled("Toggle compact graph (one row per commit)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.date_grouping)), Style::default().fg(key_color)),
+        Span::styled("Cycle date separators: day / week / month / off", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.jump_to_head)), Style::default().fg(key_color)),
+        Span::styled("Jump to HEAD", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.yank_hash)), Style::default().fg(key_color)),
+        Span::styled("Copy the commit hash to the clipboard", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on commit graph)", app.config.keys.fixup)), Style::default().fg(key_color)),
+        Span::styled("Commit staged changes as a fixup for the commit", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.reflog), Style::default().fg(key_color)),
+        Span::styled("Show the reflog (Enter jumps to the commit)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.repo_stats), Style::default().fg(key_color)),
+        Span::styled("Show repository statistics", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.goto_hash), Style::default().fg(key_color)),
+        Span::styled("Go to a commit by hash", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on tag list)", app.config.keys.select)), Style::default().fg(key_color)),
+        Span::styled("Jump to the tagged commit", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(format!("{} (on tag list)", app.config.keys.tag_sort)), Style::default().fg(key_color)),
+        Span::styled("Toggle tag sort (name / date)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled("    n / N (while searching)       ", Style::default().fg(key_color)),
+        Span::styled("Jump to next/previous match", Style::default().fg(desc_color))
+    ]));
     help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
 
     // Git Actions section
@@ -1220,7 +3408,7 @@ fn draw_help_screen(f: &mut Frame, app: &App, area: Rect) {
         Span::styled("  GIT ACTIONS", Style::default().fg(section_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Enter                         ", Style::default().fg(key_color)),
+        Span::styled(help_key(app.config.keys.select), Style::default().fg(key_color)),
         Span::styled("Execute selected git command", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
@@ -1230,7 +3418,7 @@ fn draw_help_screen(f: &mut Frame, app: &App, area: Rect) {
         Span::styled("  GIT STATUS", Style::default().fg(section_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Enter (on file)               ", Style::default().fg(key_color)),
+        Span::styled(help_key(format!("{} (on file)", app.config.keys.select)), Style::default().fg(key_color)),
         Span::styled("View file diff", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
@@ -1240,22 +3428,65 @@ fn draw_help_screen(f: &mut Frame, app: &App, area: Rect) {
         Span::styled("  OTHER", Style::default().fg(section_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    r                             ", Style::default().fg(key_color)),
+        Span::styled(help_key(app.config.keys.refresh), Style::default().fg(key_color)),
         Span::styled("Refresh repository view", Style::default().fg(desc_color))
     ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.refresh_status), Style::default().fg(key_color)),
+        Span::styled("Refresh git status only (keeps graph position)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.toggle_watch), Style::default().fg(key_color)),
+        Span::styled("Toggle watch mode (auto-refresh on .git changes, on by default)", Style::default().fg(desc_color))
+    ]));
+    help_lines.push(Line::from(vec![
+        Span::styled(help_key(app.config.keys.repeat_command), Style::default().fg(key_color)),
+        Span::styled("Repeat last command on selected commit", Style::default().fg(desc_color))
+    ]));
     help_lines.push(Line::from(vec![
         Span::styled("    Esc                           ", Style::default().fg(key_color)),
         Span::styled("Cancel current dialog/input", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    Ctrl+Q                        ", Style::default().fg(key_color)),
+        Span::styled(help_key(app.config.keys.quit), Style::default().fg(key_color)),
         Span::styled("Quit application", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![
-        Span::styled("    F1                            ", Style::default().fg(key_color)),
+        Span::styled(help_key(app.config.keys.help), Style::default().fg(key_color)),
         Span::styled("Show/hide this help", Style::default().fg(desc_color))
     ]));
     help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
+
+    // Configuration section
+    help_lines.push(Line::from(vec![
+        Span::styled("  CONFIGURATION (~/.config/git_manager/config.toml)", Style::default().fg(section_color))
+    ]));
+    for line in [
+        "editor = \"code -w\"",
+        "commit_limit = 5000     # 0 loads all; diff_context, auto_refresh_ms",
+        "[keybindings]",
+        "quit = \"ctrl+q\"        # modifiers: ctrl, alt, shift",
+        "move_up = \"k\"          # names: up, down, left, right, tab, enter,",
+        "move_down = \"j\"        #   space, esc, pageup, pagedown, home, end, f1-f12",
+        "",
+        "Actions: help quit refresh refresh_status toggle_watch goto_hash",
+        "  repeat_command next_pane move_up move_down page_up page_down select",
+        "  show_all_branches first_parent reflog search cycle_sort toggle_compact",
+        "  toggle_reviewed jump_to_head yank_hash fixup diff_search commit_tree",
+        "  cycle_parent prev_file next_file toggle_file_list staged_diff repo_stats",
+        "  unstaged_diff more_context less_context word_diff ignore_whitespace",
+        "  toggle_stage stage_all unstage_all edit_file stage_patch discard_file",
+        "  blame_file file_history tag_sort next_match prev_match date_grouping",
+        "",
+        "[theme]",
+        "preset = \"light\"       # dark (default), light, high-contrast",
+        "diff_add_fg = \"#00af00\" # override any color: names, #rrggbb or 0-255",
+    ] {
+        help_lines.push(Line::from(vec![
+            Span::styled(format!("    {}", line), Style::default().fg(desc_color))
+        ]));
+    }
+    help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
     help_lines.push(Line::from(vec![Span::styled("", Style::default())]));
 
     // Apply scroll offset
@@ -1282,7 +3513,7 @@ fn draw_help_screen(f: &mut Frame, app: &App, area: Rect) {
     let footer_lines = vec![
         Line::from(vec![Span::styled("", Style::default())]),
         Line::from(vec![
-            Span::styled("           Use Up/Down arrows to scroll | Press F1, Esc, or 'q' to close",
+            Span::styled(format!("           Use Up/Down arrows to scroll | Press {}, Esc, or 'q' to close", app.config.keys.help),
                 Style::default().fg(title_color))
         ]),
     ];